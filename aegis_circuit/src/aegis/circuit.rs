@@ -0,0 +1,153 @@
+use ark_ec::CurveGroup;
+use ark_ff::Zero;
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    eq::EqGadget,
+    fields::{fp::FpVar, FieldVar},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::crypto::{
+    commitment::{
+        pedersen::{Pedersen, PedersenHornerGadget},
+        BatchCommitmentGadget, BatchCommitmentScheme,
+    },
+    dlog,
+    range::{self, RangeGadget},
+};
+
+use super::BatchUpdate;
+
+/// DBT circuit: the aggregation of the current and delta vectors under the public `tau`, the
+/// current amounts in `[0, 2^bits)`, the delta amounts in `[-2^bits, 2^bits)`, and the delta
+/// amounts summing to the public `net_issuance`.
+///
+/// The public inputs are `[tau, net_issuance]`, so `gamma_abc_g1` holds three points.
+/// The circuit is unsatisfiable unless [`AegisCircuit::check_bits`] accepts the bit width.
+/// A plain transfer batch has a zero net issuance; a mint or burn by the central bank
+/// publishes the amount it creates or destroys.
+#[derive(Clone)]
+pub struct AegisCircuit<C: CurveGroup> {
+    // public input
+    pub tau: Option<C::ScalarField>,
+    pub net_issuance: Option<C::ScalarField>,
+
+    // committed witness
+    pub aggregation: Option<Vec<C::ScalarField>>,
+    pub curr_commitments: Option<Vec<Vec<C::ScalarField>>>,
+    pub prev_commitments: Option<Vec<Vec<C::ScalarField>>>,
+
+    /// Bit width of the range checks, part of the circuit shape
+    pub bits: usize,
+}
+
+impl<C: CurveGroup> AegisCircuit<C> {
+    pub fn new(
+        tau: C::ScalarField,
+        net_issuance: C::ScalarField,
+        bits: usize,
+        update: BatchUpdate<C::ScalarField>,
+    ) -> Self {
+        let commitments = [update.curr.clone(), update.delta()].concat();
+        let slices: Vec<&[C::ScalarField]> = commitments.iter().map(|cm| &cm[..]).collect();
+        let (aggregation, _) = Pedersen::<C>::scalar_aggregate(&slices[..], tau, None);
+
+        Self {
+            tau: Some(tau),
+            net_issuance: Some(net_issuance),
+            aggregation: Some(aggregation),
+            curr_commitments: Some(update.curr),
+            prev_commitments: Some(update.prev),
+            bits,
+        }
+    }
+
+    /// Whether `bits` is at most [`dlog::MAX_BOUND_BITS`], so that the auditor can decrypt every
+    /// amount, and the deltas of `batch_size` accounts cannot wrap around when summed to the net
+    /// issuance, see [`range::check_sum_bits`].
+    pub fn check_bits(bits: usize, batch_size: usize) -> bool {
+        bits <= dlog::MAX_BOUND_BITS && range::check_sum_bits::<C::ScalarField>(bits, batch_size)
+    }
+
+    /// Number of constraints the range checks add to a batch of `batch_size` accounts.
+    pub fn range_constraints(batch_size: usize, bits: usize) -> usize {
+        batch_size
+            * (RangeGadget::<C::ScalarField>::num_constraints(bits)
+                + RangeGadget::<C::ScalarField>::num_signed_constraints(bits))
+    }
+
+    /// All-zero assignment of the given dimensions, for the setup.
+    pub fn mock(batch_size: usize, length: usize, bits: usize) -> Self {
+        Self {
+            tau: Some(C::ScalarField::zero()),
+            net_issuance: Some(C::ScalarField::zero()),
+            aggregation: Some(vec![C::ScalarField::zero(); length]),
+            curr_commitments: Some(vec![vec![C::ScalarField::zero(); length]; batch_size]),
+            prev_commitments: Some(vec![vec![C::ScalarField::zero(); length]; batch_size]),
+            bits,
+        }
+    }
+}
+
+impl<C: CurveGroup> ConstraintSynthesizer<C::ScalarField> for AegisCircuit<C> {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<C::ScalarField>,
+    ) -> ark_relations::r1cs::Result<()> {
+        let tau = FpVar::new_input(cs.clone(), || {
+            self.tau.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let net_issuance = FpVar::new_input(cs.clone(), || {
+            self.net_issuance.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        let aggregation = Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || {
+            self.aggregation.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        let current_commitments = self
+            .curr_commitments
+            .ok_or(SynthesisError::AssignmentMissing)?
+            .into_iter()
+            .map(|cm| Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || Ok(cm)))
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        let prev_commitments = self
+            .prev_commitments
+            .ok_or(SynthesisError::AssignmentMissing)?
+            .into_iter()
+            .map(|cm| Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || Ok(cm)))
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+        if !Self::check_bits(self.bits, current_commitments.len()) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut sum = FpVar::<C::ScalarField>::zero();
+        let mut delta_commitments =
+            Vec::<Vec<FpVar<C::ScalarField>>>::with_capacity(current_commitments.len());
+        for (prev, curr) in prev_commitments.iter().zip(current_commitments.iter()) {
+            let delta = prev
+                .iter()
+                .zip(curr.iter())
+                .map(|(prev, curr)| curr - prev)
+                .collect::<Vec<_>>();
+            let delta_amount = delta.first().ok_or(SynthesisError::Unsatisfiable)?;
+            RangeGadget::enforce_signed_range(delta_amount, self.bits)?;
+            sum += delta_amount;
+            delta_commitments.push(delta);
+
+            let amount = curr.first().ok_or(SynthesisError::Unsatisfiable)?;
+            RangeGadget::enforce_range(amount, self.bits)?;
+        }
+        sum.enforce_equal(&net_issuance)?;
+
+        let commitments =
+            [current_commitments, delta_commitments].concat::<Vec<FpVar<C::ScalarField>>>();
+        PedersenHornerGadget::<C, FpVar<C::ScalarField>>::enforce_equal(
+            aggregation,
+            commitments,
+            tau,
+            None,
+        )
+    }
+}
@@ -0,0 +1,109 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{
+    crypto::range,
+    gro::{self, CommittingKey, ProvingKey, VerifyingKey},
+};
+
+use super::AegisError;
+
+/// Keys of [`Aegis::setup`](super::Aegis::setup).
+pub type Keys<E> = (BatchProvingKey<E>, VerifyingKey<E>, CommittingKey<E>);
+
+/// ccGroth16 proving key of the DBT circuit, with the range-check width it was generated for.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProvingKey<E: Pairing> {
+    pub pk: ProvingKey<E>,
+    pub bits: usize,
+}
+
+/// Account commitment vectors before and after a batch, `batch_size` vectors of `length` values
+/// each, amount first.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchUpdate<F: PrimeField> {
+    pub prev: Vec<Vec<F>>,
+    pub curr: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> BatchUpdate<F> {
+    pub fn new(prev: Vec<Vec<F>>, curr: Vec<Vec<F>>) -> Self {
+        Self { prev, curr }
+    }
+
+    /// Update applying `delta_i` to every `prev_i`.
+    pub fn from_delta(prev: Vec<Vec<F>>, delta: &[Vec<F>]) -> Self {
+        let curr = prev
+            .iter()
+            .zip(delta)
+            .map(|(prev, delta)| prev.iter().zip(delta).map(|(p, d)| *p + d).collect())
+            .collect();
+        Self { prev, curr }
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.curr.len()
+    }
+
+    /// `delta_i = curr_i - prev_i`.
+    pub fn delta(&self) -> Vec<Vec<F>> {
+        self.curr
+            .iter()
+            .zip(&self.prev)
+            .map(|(curr, prev)| curr.iter().zip(prev).map(|(c, p)| *c - p).collect())
+            .collect()
+    }
+
+    /// `Σ (curr_i[0] - prev_i[0])`, the amount created by the batch, negative for a burn.
+    pub fn net_issuance(&self) -> F {
+        self.curr
+            .iter()
+            .zip(&self.prev)
+            .filter_map(|(curr, prev)| Some(*curr.first()? - prev.first()?))
+            .sum()
+    }
+
+    /// Check that every current amount is in `[0, 2^bits)` and every delta amount in
+    /// `[-2^bits, 2^bits)`, as the circuit enforces.
+    pub fn check_range(&self, bits: usize) -> Result<(), AegisError> {
+        let balances = self.curr.iter().all(|curr| {
+            curr.first()
+                .is_some_and(|amount| range::in_range(amount, bits))
+        });
+        let deltas = self.delta().iter().all(|delta| {
+            delta
+                .first()
+                .is_some_and(|amount| range::in_signed_range(amount, bits))
+        });
+        if !balances || !deltas {
+            return Err(AegisError::OutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Check that both sides hold `batch_size` vectors of `length` values.
+    pub fn check(&self, batch_size: usize, length: usize) -> Result<(), AegisError> {
+        if self.prev.len() != batch_size
+            || self.curr.len() != batch_size
+            || self
+                .prev
+                .iter()
+                .chain(&self.curr)
+                .any(|cm| cm.len() != length)
+        {
+            return Err(AegisError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+}
+
+/// Commitments of a [`BatchUpdate`] and the challenge they are aggregated under.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchCommitment<E: Pairing> {
+    /// `curr_i` then `delta_i` under `batch_g1`, published with the proof
+    pub commitments: Vec<E::G1Affine>,
+    /// Commitment `D` to `curr ‖ prev` with its opening, kept by the prover
+    pub proof_dependent_commitment: gro::Commitment<E>,
+    pub tau: E::ScalarField,
+}
@@ -0,0 +1,22 @@
+use ark_relations::r1cs::SynthesisError;
+
+#[derive(Debug, PartialEq)]
+pub enum AegisError {
+    /// The batch size or the commitment vector length is zero, or the range bit width is not
+    /// accepted by the circuit.
+    InvalidDimensions,
+    /// The update or the commitments do not match the batch size and length of the keys.
+    MismatchParameterLength,
+    /// The amounts of the update do not sum to the claimed net issuance.
+    Unbalanced,
+    /// A current amount or a delta amount is outside the range of the bit width.
+    OutOfRange,
+    /// Failure of the ccGroth16 setup, commitment, proof or verification.
+    Snark(SynthesisError),
+}
+
+impl From<SynthesisError> for AegisError {
+    fn from(e: SynthesisError) -> Self {
+        AegisError::Snark(e)
+    }
+}
@@ -0,0 +1,26 @@
+use std::marker::PhantomData;
+
+use ark_ec::pairing::Pairing;
+
+mod circuit;
+pub use circuit::AegisCircuit;
+
+mod data_structure;
+pub use data_structure::*;
+
+mod errors;
+pub use errors::AegisError;
+
+mod prover;
+mod verifier;
+
+/// Dynamic batch transaction (DBT) of Aegis over ccGroth16.
+///
+/// A batch moves `batch_size` account commitment vectors of `length` values from `prev` to `curr`.
+/// The current and delta vectors are committed under `batch_g1`, aggregated under a challenge
+/// `tau` bound to the proof-dependent commitment `D`, and the circuit checks the aggregation,
+/// the range of every current and delta amount and the conservation of value up to the public
+/// net issuance over the same committed values.
+pub struct Aegis<E: Pairing> {
+    _pairing: PhantomData<E>,
+}
@@ -0,0 +1,83 @@
+use ark_ec::pairing::Pairing;
+use ark_std::rand::{CryptoRng, RngCore};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    crypto::commitment::{pedersen::Pedersen, BatchCommitmentScheme},
+    gro::{CCGroth16, CommittingKey, Proof},
+    snark::{CircuitSpecificSetupCCSNARK, CCSNARK},
+};
+
+use super::{Aegis, AegisCircuit, AegisError, BatchCommitment, BatchProvingKey, BatchUpdate, Keys};
+
+impl<E: Pairing> Aegis<E> {
+    /// Generate the keys for batches of `batch_size` vectors of `length` values, with amounts
+    /// range-checked over `bits` bits.
+    /// The aggregation and both sides of the update are the committed witness.
+    pub fn setup<R: RngCore + CryptoRng>(
+        batch_size: usize,
+        length: usize,
+        bits: usize,
+        rng: &mut R,
+    ) -> Result<Keys<E>, AegisError> {
+        Self::check_dimensions(batch_size, length)?;
+        Self::check_bits(bits, batch_size)?;
+        let mock = AegisCircuit::<E::G1>::mock(batch_size, length, bits);
+        let (pk, vk, ck) = CCGroth16::<E>::setup(mock, length, length * (1 + 2 * batch_size), rng)?;
+        Ok((BatchProvingKey { pk, bits }, vk, ck))
+    }
+
+    /// Commit to `curr ‖ prev` in `D`, batch commit the current and delta vectors and derive
+    /// `tau`.
+    pub fn commit<R: RngCore + CryptoRng>(
+        ck: &CommittingKey<E>,
+        update: &BatchUpdate<E::ScalarField>,
+        rng: &mut R,
+    ) -> Result<BatchCommitment<E>, AegisError> {
+        let (batch_size, length) = Self::dimensions(ck)?;
+        update.check(batch_size, length)?;
+
+        let committed_witness = [&update.curr[..], &update.prev].concat().concat();
+        let proof_dependent_commitment = CCGroth16::<E>::commit(ck, &committed_witness, rng)?;
+
+        let commitments = [update.curr.clone(), update.delta()].concat();
+        let slices = cfg_iter!(commitments).map(|cm| &cm[..]).collect::<Vec<_>>();
+        let commitments = Pedersen::<E::G1>::batch_commit(&ck.batch_g1, &slices);
+        let tau = Self::challenge(&commitments, &proof_dependent_commitment.cm);
+
+        Ok(BatchCommitment {
+            commitments,
+            proof_dependent_commitment,
+            tau,
+        })
+    }
+
+    /// Prove the batch against the public `net_issuance`, zero unless the batch mints or burns,
+    /// with the amounts range-checked over the width the keys were generated for.
+    pub fn prove<R: RngCore + CryptoRng>(
+        pk: &BatchProvingKey<E>,
+        update: BatchUpdate<E::ScalarField>,
+        net_issuance: E::ScalarField,
+        commitment: &BatchCommitment<E>,
+        rng: &mut R,
+    ) -> Result<Proof<E>, AegisError> {
+        let (pk, bits) = (&pk.pk, pk.bits);
+        let (batch_size, length) = Self::dimensions(&pk.vk.ck)?;
+        update.check(batch_size, length)?;
+        Self::check_bits(bits, batch_size)?;
+        update.check_range(bits)?;
+        if update.net_issuance() != net_issuance {
+            return Err(AegisError::Unbalanced);
+        }
+
+        let prover_timer = start_timer!(|| "Aegis::Prover");
+        let circuit = AegisCircuit::<E::G1>::new(commitment.tau, net_issuance, bits, update);
+        let proof =
+            CCGroth16::<E>::prove(pk, circuit, &commitment.proof_dependent_commitment, rng)?;
+        end_timer!(prover_timer);
+
+        Ok(proof)
+    }
+}
@@ -0,0 +1,69 @@
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+
+use crate::{
+    crypto::commitment::{pedersen::Pedersen, BatchCommitmentScheme},
+    gro::{CCGroth16, CommittingKey, Proof, VerifyingKey},
+    snark::CCSNARK,
+};
+
+use super::{Aegis, AegisCircuit, AegisError};
+
+impl<E: Pairing> Aegis<E> {
+    /// Verify a DBT proof against the published current and delta commitments and the
+    /// public `net_issuance`.
+    /// `tau` is recomputed from the delta commitments and `D`, and the aggregation of the
+    /// commitments is added to `D` before the pairing check.
+    pub fn verify(
+        vk: &VerifyingKey<E>,
+        commitments: &[E::G1Affine],
+        net_issuance: E::ScalarField,
+        proof: &Proof<E>,
+    ) -> Result<bool, AegisError> {
+        let (batch_size, _) = Self::dimensions(&vk.ck)?;
+        if commitments.len() != 2 * batch_size {
+            return Err(AegisError::MismatchParameterLength);
+        }
+
+        let verifier_timer = start_timer!(|| "Aegis::Verifier");
+        let tau = Self::challenge(commitments, &proof.d);
+        let (aggregation, _) = Pedersen::<E::G1>::aggregate(commitments, tau, None);
+        let mut aggregated = proof.clone();
+        aggregated.d = (aggregation.into_group() + proof.d).into_affine();
+        let result = CCGroth16::<E>::verify(vk, &[tau, net_issuance], &aggregated)?;
+        end_timer!(verifier_timer);
+
+        Ok(result)
+    }
+
+    /// Challenge `tau` over the delta commitments, the second half of `commitments`, and `D`.
+    pub fn challenge(commitments: &[E::G1Affine], d: &E::G1Affine) -> E::ScalarField {
+        Pedersen::<E::G1>::challenge(&[], &commitments[commitments.len() / 2..], d)
+    }
+
+    pub fn check_dimensions(batch_size: usize, length: usize) -> Result<(), AegisError> {
+        if batch_size == 0 || length == 0 {
+            return Err(AegisError::InvalidDimensions);
+        }
+        Ok(())
+    }
+
+    /// Check that `bits` is a range-check width the circuit accepts for `batch_size` accounts,
+    /// see [`AegisCircuit::check_bits`].
+    pub fn check_bits(bits: usize, batch_size: usize) -> Result<(), AegisError> {
+        if !AegisCircuit::<E::G1>::check_bits(bits, batch_size) {
+            return Err(AegisError::InvalidDimensions);
+        }
+        Ok(())
+    }
+
+    /// `(batch_size, length)` of the keys: `batch_g1` holds one generator per value and
+    /// `proof_dependent_g1` one per value of `curr ‖ prev`.
+    pub fn dimensions(ck: &CommittingKey<E>) -> Result<(usize, usize), AegisError> {
+        let length = ck.batch_g1.len();
+        let committed = ck.proof_dependent_g1.len();
+        if length == 0 || committed == 0 || !committed.is_multiple_of(2 * length) {
+            return Err(AegisError::InvalidDimensions);
+        }
+        Ok((committed / (2 * length), length))
+    }
+}
@@ -0,0 +1,111 @@
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::solidity::Solidity;
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicParameters<C: CurveGroup> {
+    /// Bit width `n` of the range `[0, 2^n)`
+    pub bits: usize,
+    /// Value base of the commitments
+    pub g: C::Affine,
+    /// Blinding base of the commitments
+    pub h: C::Affine,
+    /// Base of the inner product
+    pub u: C::Affine,
+    /// `n · m` generators for up to `m` aggregated values
+    pub g_vec: Vec<C::Affine>,
+    pub h_vec: Vec<C::Affine>,
+}
+
+impl<C: CurveGroup> Solidity for PublicParameters<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        let mut v = vec![self.bits.to_string(), self.g_vec.len().to_string()];
+        v.extend(self.g.to_solidity());
+        v.extend(self.h.to_solidity());
+        v.extend(self.u.to_solidity());
+        v.extend(self.g_vec.to_solidity());
+        v.extend(self.h_vec.to_solidity());
+        v
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Instance<C: CurveGroup> {
+    /// Commitments `V_j = v_j · g + γ_j · h`
+    pub v: Vec<C::Affine>,
+}
+
+impl<C: CurveGroup> Solidity for Instance<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        self.v.to_solidity()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<C: CurveGroup> {
+    pub v: Vec<u64>,
+    pub gamma: Vec<C::ScalarField>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct InnerProductProof<C: CurveGroup> {
+    pub l_vec: Vec<C::Affine>,
+    pub r_vec: Vec<C::Affine>,
+    pub a: C::ScalarField,
+    pub b: C::ScalarField,
+}
+
+impl<C: CurveGroup> Solidity for InnerProductProof<C>
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [
+            self.l_vec.to_solidity(),
+            self.r_vec.to_solidity(),
+            self.a.to_solidity(),
+            self.b.to_solidity(),
+        ]
+        .concat()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: CurveGroup> {
+    pub a: C::Affine,
+    pub s: C::Affine,
+    pub t1: C::Affine,
+    pub t2: C::Affine,
+    pub tau_x: C::ScalarField,
+    pub mu: C::ScalarField,
+    pub t_hat: C::ScalarField,
+    pub ipp: InnerProductProof<C>,
+}
+
+impl<C: CurveGroup> Solidity for Proof<C>
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [
+            self.a.to_solidity(),
+            self.s.to_solidity(),
+            self.t1.to_solidity(),
+            self.t2.to_solidity(),
+            self.tau_x.to_solidity(),
+            self.mu.to_solidity(),
+            self.t_hat.to_solidity(),
+            self.ipp.to_solidity(),
+        ]
+        .concat()
+    }
+}
@@ -0,0 +1,13 @@
+#[derive(Debug, PartialEq)]
+pub enum RangeProofError {
+    /// The bit width is not a power of two in `[1, 64]`.
+    InvalidBitWidth,
+    /// `g_vec` and `h_vec` differ in length, or the witness does not match the instance.
+    MismatchParameterLength,
+    /// The instance is empty or needs more generators than the public parameters provide.
+    TooManyValues,
+    /// A value does not fit in the bit width.
+    ValueOutOfRange,
+    /// A point appended to the transcript is the identity.
+    IdentityPoint,
+}
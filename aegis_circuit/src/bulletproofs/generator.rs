@@ -0,0 +1,68 @@
+use ark_ec::CurveGroup;
+use ark_ff::Field;
+use ark_std::{rand::Rng, UniformRand};
+
+use super::{Instance, PublicParameters, RangeProof, RangeProofError};
+
+impl<C: CurveGroup> RangeProof<C> {
+    /// Sample public parameters for up to `max_values` aggregated values of `bits` bits.
+    pub fn generate_parameters<R: Rng>(
+        bits: usize,
+        max_values: usize,
+        rng: &mut R,
+    ) -> Result<PublicParameters<C>, RangeProofError> {
+        let len = bits * max_values.next_power_of_two();
+        let points = (0..2 * len + 3).map(|_| C::rand(rng)).collect::<Vec<_>>();
+        let points = C::normalize_batch(&points);
+
+        let pp = PublicParameters {
+            bits,
+            g: points[0],
+            h: points[1],
+            u: points[2],
+            g_vec: points[3..3 + len].to_vec(),
+            h_vec: points[3 + len..].to_vec(),
+        };
+        Self::check_public_parameters(&pp)?;
+        Ok(pp)
+    }
+
+    pub fn check_public_parameters(pp: &PublicParameters<C>) -> Result<(), RangeProofError> {
+        if !pp.bits.is_power_of_two() || pp.bits > 64 {
+            return Err(RangeProofError::InvalidBitWidth);
+        }
+        if pp.g_vec.len() != pp.h_vec.len() {
+            return Err(RangeProofError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+
+    /// Returns the number of aggregated values, padded to a power of two.
+    /// Padded values are commitments to zero with zero blinding.
+    pub fn check_instance(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+    ) -> Result<usize, RangeProofError> {
+        Self::check_public_parameters(pp)?;
+        let m = instance.v.len().next_power_of_two();
+        if instance.v.is_empty() || pp.bits * m > pp.g_vec.len() {
+            return Err(RangeProofError::TooManyValues);
+        }
+        Ok(m)
+    }
+
+    /// `[1, x, x^2, ..., x^(n-1)]`
+    pub(super) fn powers(x: C::ScalarField, n: usize) -> Vec<C::ScalarField> {
+        let mut powers = Vec::with_capacity(n);
+        let mut cur = C::ScalarField::ONE;
+        for _ in 0..n {
+            powers.push(cur);
+            cur *= x;
+        }
+        powers
+    }
+
+    pub(super) fn random_vector<R: Rng>(n: usize, rng: &mut R) -> Vec<C::ScalarField> {
+        (0..n).map(|_| C::ScalarField::rand(rng)).collect()
+    }
+}
@@ -0,0 +1,142 @@
+use ark_ec::CurveGroup;
+use ark_ff::Field;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{crypto::protocol::transcript::TranscriptProtocol, linker::points_to_bytes};
+
+use super::{InnerProductProof, RangeProofError};
+
+/// Round challenges `e_k` and folding scalars `s_i`.
+type VerificationScalars<F> = (Vec<F>, Vec<F>);
+
+impl<C: CurveGroup> InnerProductProof<C> {
+    /// Prove knowledge of `a, b` with `P = <a, g> + <b, h> + <a, b> · u`.
+    ///
+    /// Every round halves the vectors, as in CompDLEq:
+    /// `a' = a_lo · e + a_hi · e^-1`, `b' = b_lo · e^-1 + b_hi · e`,
+    /// `g' = g_lo · e^-1 + g_hi · e` and `h' = h_lo · e + h_hi · e^-1`.
+    pub fn create(
+        g: &[C::Affine],
+        h: &[C::Affine],
+        u: &C::Affine,
+        a: Vec<C::ScalarField>,
+        b: Vec<C::ScalarField>,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<Self, RangeProofError> {
+        if !a.len().is_power_of_two()
+            || a.len() != b.len()
+            || a.len() != g.len()
+            || a.len() != h.len()
+        {
+            return Err(RangeProofError::MismatchParameterLength);
+        }
+
+        let ipp_timer = start_timer!(|| "InnerProductProof::Prover");
+        let (mut g, mut h, mut a, mut b) = (g.to_vec(), h.to_vec(), a, b);
+        let (mut l_vec, mut r_vec) = (vec![], vec![]);
+        while a.len() > 1 {
+            let mid = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(mid);
+            let (b_lo, b_hi) = b.split_at(mid);
+            let (g_lo, g_hi) = g.split_at(mid);
+            let (h_lo, h_hi) = h.split_at(mid);
+
+            let c_l = inner_product(a_lo, b_hi);
+            let c_r = inner_product(a_hi, b_lo);
+            let l = (C::msm_unchecked(g_hi, a_lo) + C::msm_unchecked(h_lo, b_hi) + *u * c_l)
+                .into_affine();
+            let r = (C::msm_unchecked(g_lo, a_hi) + C::msm_unchecked(h_hi, b_lo) + *u * c_r)
+                .into_affine();
+
+            let e = Self::compute_challenge(&l, &r, transcript)?;
+            // a zero challenge has negligible probability and only yields a rejected proof
+            let e_inv = e.inverse().unwrap_or_default();
+
+            a = fold(a_lo, a_hi, e, e_inv);
+            b = fold(b_lo, b_hi, e_inv, e);
+            g = C::normalize_batch(
+                &cfg_iter!(g_lo)
+                    .zip(g_hi)
+                    .map(|(lo, hi)| *lo * e_inv + *hi * e)
+                    .collect::<Vec<_>>(),
+            );
+            h = C::normalize_batch(
+                &cfg_iter!(h_lo)
+                    .zip(h_hi)
+                    .map(|(lo, hi)| *lo * e + *hi * e_inv)
+                    .collect::<Vec<_>>(),
+            );
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+        end_timer!(ipp_timer);
+
+        Ok(InnerProductProof {
+            l_vec,
+            r_vec,
+            a: a[0],
+            b: b[0],
+        })
+    }
+
+    /// Challenges of every round and the scalars `s` such that the folded `g` is `<s, g>`
+    /// and the folded `h` is `<s^-1, h>`.
+    /// Returns `None` if the number of rounds does not match `n` or a point is the identity.
+    pub fn verification_scalars(
+        &self,
+        n: usize,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Option<VerificationScalars<C::ScalarField>> {
+        // the number of rounds comes from the proof, so the shift must not overflow
+        let rounds = self.l_vec.len();
+        let size = u32::try_from(rounds)
+            .ok()
+            .and_then(|rounds| 1usize.checked_shl(rounds));
+        if size != Some(n) || self.r_vec.len() != rounds {
+            return None;
+        }
+
+        let challenges = self
+            .l_vec
+            .iter()
+            .zip(&self.r_vec)
+            .map(|(l, r)| Self::compute_challenge(l, r, transcript).ok())
+            .collect::<Option<Vec<_>>>()?;
+        let mut inverses = challenges.clone();
+        ark_ff::batch_inversion(&mut inverses);
+
+        // s_0 = Π e_k^-1, and setting the bit of round k multiplies by e_k^2
+        let mut s = Vec::with_capacity(n);
+        s.push(inverses.iter().product::<C::ScalarField>());
+        for i in 1..n {
+            let lg_i = usize::BITS - 1 - i.leading_zeros();
+            let k = rounds - 1 - lg_i as usize;
+            s.push(s[i - (1 << lg_i)] * challenges[k].square());
+        }
+
+        Some((challenges, s))
+    }
+
+    pub fn compute_challenge(
+        l: &C::Affine,
+        r: &C::Affine,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<C::ScalarField, RangeProofError> {
+        let bytes = points_to_bytes(&[*l, *r]).ok_or(RangeProofError::IdentityPoint)?;
+        transcript.append(b"inner product", &bytes);
+        Ok(transcript.challenge_scalar(b"challenge"))
+    }
+}
+
+pub(super) fn inner_product<F: Field>(a: &[F], b: &[F]) -> F {
+    cfg_iter!(a).zip(b).map(|(a, b)| *a * b).sum()
+}
+
+fn fold<F: Field>(lo: &[F], hi: &[F], x_lo: F, x_hi: F) -> Vec<F> {
+    cfg_iter!(lo)
+        .zip(hi)
+        .map(|(lo, hi)| *lo * x_lo + *hi * x_hi)
+        .collect()
+}
@@ -0,0 +1,58 @@
+//! Aggregated Bulletproofs range proofs over Pedersen commitments `V_j = v_j · g + γ_j · h`.
+//!
+//! A single proof shows `0 <= v_j < 2^bits` for every commitment of the instance. The inner
+//! product argument folds the generators like [`CompDLEq`](crate::linker::comp_dl_eq::CompDLEq),
+//! and the verifier checks all folding rounds with a single multi-scalar multiplication.
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{CryptoRng, RngCore};
+
+mod data_structure;
+pub use data_structure::*;
+
+mod errors;
+pub use errors::RangeProofError;
+
+mod generator;
+mod inner_product;
+mod prover;
+mod verifier;
+
+use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
+
+pub struct RangeProof<C: CurveGroup> {
+    _group: PhantomData<C>,
+}
+
+impl<C: CurveGroup> SigmaProtocol for RangeProof<C> {
+    type PublicParameters = PublicParameters<C>;
+    type Instance = Instance<C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+    type Error = RangeProofError;
+
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Self::check_public_parameters(pp)?;
+        Ok(pp.clone())
+    }
+
+    fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error> {
+        Self::create_proof(pp, instance, witness, transcript, rng)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        Self::verify_proof(pp, instance, proof, transcript)
+    }
+}
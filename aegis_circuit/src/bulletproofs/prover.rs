@@ -0,0 +1,141 @@
+use ark_ec::CurveGroup;
+use ark_ff::Field;
+use ark_std::{rand::Rng, UniformRand};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::crypto::protocol::transcript::TranscriptProtocol;
+
+use super::{
+    inner_product::inner_product, InnerProductProof, Instance, Proof, PublicParameters, RangeProof,
+    RangeProofError, Witness,
+};
+
+impl<C: CurveGroup> RangeProof<C> {
+    pub fn check_witness(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+    ) -> Result<(), RangeProofError> {
+        if witness.v.len() != instance.v.len() || witness.gamma.len() != instance.v.len() {
+            return Err(RangeProofError::MismatchParameterLength);
+        }
+        if pp.bits < 64 && witness.v.iter().any(|v| v >> pp.bits != 0) {
+            return Err(RangeProofError::ValueOutOfRange);
+        }
+        Ok(())
+    }
+
+    pub fn create_proof<R: Rng>(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+        transcript: &mut impl TranscriptProtocol,
+        rng: &mut R,
+    ) -> Result<Proof<C>, RangeProofError> {
+        let m = Self::check_instance(pp, instance)?;
+        Self::check_witness(pp, instance, witness)?;
+
+        let prover_timer = start_timer!(|| "RangeProof::Prover");
+        let n = pp.bits;
+        let nm = n * m;
+        let (g_vec, h_vec) = (&pp.g_vec[..nm], &pp.h_vec[..nm]);
+        let one = C::ScalarField::ONE;
+
+        let bits_timer = start_timer!(|| "Commit bits");
+        // a_L: bits of every value, a_R = a_L - 1
+        let a_l = (0..nm)
+            .map(|i| {
+                let v = witness.v.get(i / n).copied().unwrap_or(0);
+                C::ScalarField::from((v >> (i % n)) & 1)
+            })
+            .collect::<Vec<_>>();
+        let a_r = cfg_iter!(a_l).map(|a| *a - one).collect::<Vec<_>>();
+        let alpha = C::ScalarField::rand(rng);
+        let a = (pp.h * alpha + C::msm_unchecked(g_vec, &a_l) + C::msm_unchecked(h_vec, &a_r))
+            .into_affine();
+
+        let s_l = Self::random_vector(nm, rng);
+        let s_r = Self::random_vector(nm, rng);
+        let rho = C::ScalarField::rand(rng);
+        let s = (pp.h * rho + C::msm_unchecked(g_vec, &s_l) + C::msm_unchecked(h_vec, &s_r))
+            .into_affine();
+        end_timer!(bits_timer);
+
+        Self::append_instance(pp, instance, transcript)?;
+        let (y, z) = Self::compute_yz(&a, &s, transcript)?;
+
+        let poly_timer = start_timer!(|| "Compute t(X)");
+        let y_powers = Self::powers(y, nm);
+        let two_powers = Self::powers(C::ScalarField::from(2u64), n);
+        let z_powers = Self::powers(z, m + 2);
+
+        // l(X) = (a_L - z) + s_L · X
+        // r(X) = y^nm ∘ (a_R + z + s_R · X) + z^(2+j) · 2^n on block j
+        let l0 = cfg_iter!(a_l).map(|a| *a - z).collect::<Vec<_>>();
+        let r0 = (0..nm)
+            .map(|i| y_powers[i] * (a_r[i] + z) + z_powers[2 + i / n] * two_powers[i % n])
+            .collect::<Vec<_>>();
+        let r1 = cfg_iter!(y_powers)
+            .zip(&s_r)
+            .map(|(y, s)| *y * s)
+            .collect::<Vec<_>>();
+
+        let t1 = inner_product(&l0, &r1) + inner_product(&s_l, &r0);
+        let t2 = inner_product(&s_l, &r1);
+        let tau1 = C::ScalarField::rand(rng);
+        let tau2 = C::ScalarField::rand(rng);
+        let t1_cm = (pp.g * t1 + pp.h * tau1).into_affine();
+        let t2_cm = (pp.g * t2 + pp.h * tau2).into_affine();
+        end_timer!(poly_timer);
+
+        let x = Self::compute_x(&t1_cm, &t2_cm, transcript)?;
+
+        let tau_x = tau2 * x.square()
+            + tau1 * x
+            + witness
+                .gamma
+                .iter()
+                .zip(&z_powers[2..])
+                .map(|(gamma, z)| *gamma * z)
+                .sum::<C::ScalarField>();
+        let mu = alpha + rho * x;
+        let l = cfg_iter!(l0)
+            .zip(&s_l)
+            .map(|(l, s)| *l + *s * x)
+            .collect::<Vec<_>>();
+        let r = cfg_iter!(r0)
+            .zip(&r1)
+            .map(|(r, r1)| *r + *r1 * x)
+            .collect::<Vec<_>>();
+        let t_hat = inner_product(&l, &r);
+
+        let w = Self::compute_w(tau_x, mu, t_hat, transcript);
+
+        // inner product argument over h' = y^-i · h_i
+        let y_inv = y.inverse().unwrap_or_default();
+        let y_inv_powers = Self::powers(y_inv, nm);
+        let h_prime = C::normalize_batch(
+            &cfg_iter!(h_vec)
+                .zip(&y_inv_powers)
+                .map(|(h, y)| *h * y)
+                .collect::<Vec<_>>(),
+        );
+        let u = (pp.u * w).into_affine();
+        let ipp = InnerProductProof::create(g_vec, &h_prime, &u, l, r, transcript)?;
+
+        end_timer!(prover_timer);
+
+        Ok(Proof {
+            a,
+            s,
+            t1: t1_cm,
+            t2: t2_cm,
+            tau_x,
+            mu,
+            t_hat,
+            ipp,
+        })
+    }
+}
@@ -0,0 +1,152 @@
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, Field, PrimeField};
+
+use crate::{crypto::protocol::transcript::TranscriptProtocol, linker::points_to_bytes};
+
+use super::{Instance, Proof, PublicParameters, RangeProof, RangeProofError};
+
+impl<C: CurveGroup> RangeProof<C> {
+    pub fn verify_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<bool, RangeProofError> {
+        let m = Self::check_instance(pp, instance)?;
+
+        let verifier_timer = start_timer!(|| "RangeProof::Verifier");
+        let n = pp.bits;
+        let nm = n * m;
+
+        Self::append_instance(pp, instance, transcript)?;
+        let Ok((y, z)) = Self::compute_yz(&proof.a, &proof.s, transcript) else {
+            return Ok(false);
+        };
+        let Ok(x) = Self::compute_x(&proof.t1, &proof.t2, transcript) else {
+            return Ok(false);
+        };
+        let w = Self::compute_w(proof.tau_x, proof.mu, proof.t_hat, transcript);
+        let Some((challenges, s)) = proof.ipp.verification_scalars(nm, transcript) else {
+            return Ok(false);
+        };
+
+        let y_powers = Self::powers(y, nm);
+        let two_powers = Self::powers(C::ScalarField::from(2u64), n);
+        let z_powers = Self::powers(z, m + 3);
+
+        // t_hat · g + τ_x · h = Σ z^(2+j) · V_j + δ(y, z) · g + x · T1 + x^2 · T2
+        let sum_y = y_powers.iter().sum::<C::ScalarField>();
+        let sum_two = two_powers.iter().sum::<C::ScalarField>();
+        let delta =
+            (z - z_powers[2]) * sum_y - z_powers[3..].iter().sum::<C::ScalarField>() * sum_two;
+
+        let bases = [&[pp.g, pp.h, proof.t1, proof.t2][..], &instance.v].concat();
+        let scalars = [
+            &[proof.t_hat - delta, proof.tau_x, -x, -x.square()][..],
+            &z_powers[2..2 + instance.v.len()]
+                .iter()
+                .map(|z| -*z)
+                .collect::<Vec<_>>(),
+        ]
+        .concat();
+        if !C::msm_unchecked(&bases, &scalars).is_zero() {
+            end_timer!(verifier_timer);
+            return Ok(false);
+        }
+
+        // A + x · S - z · <1, g> + <z + z^(2+j) · 2^i · y^-i, h> - μ · h + t_hat · w · u
+        //   + Σ e_k^2 · L_k + Σ e_k^-2 · R_k = a · <s, g> + b · <s^-1 · y^-i, h> + a · b · w · u
+        let ipp = &proof.ipp;
+        let mut s_inv = s.clone();
+        ark_ff::batch_inversion(&mut s_inv);
+        let y_inv = y.inverse().unwrap_or_default();
+        let y_inv_powers = Self::powers(y_inv, nm);
+
+        let g_scalars = s.iter().map(|s| -z - ipp.a * s);
+        let h_scalars = (0..nm).map(|i| {
+            z + (z_powers[2 + i / n] * two_powers[i % n] - ipp.b * s_inv[i]) * y_inv_powers[i]
+        });
+        let mut sqr = challenges.iter().map(|e| e.square()).collect::<Vec<_>>();
+        let sqr_inv = {
+            let mut inv = sqr.clone();
+            ark_ff::batch_inversion(&mut inv);
+            inv
+        };
+        sqr.extend(sqr_inv);
+
+        let bases = [
+            &[proof.a, proof.s, pp.h, pp.u][..],
+            &pp.g_vec[..nm],
+            &pp.h_vec[..nm],
+            &ipp.l_vec,
+            &ipp.r_vec,
+        ]
+        .concat();
+        let scalars = [
+            C::ScalarField::ONE,
+            x,
+            -proof.mu,
+            w * (proof.t_hat - ipp.a * ipp.b),
+        ]
+        .into_iter()
+        .chain(g_scalars)
+        .chain(h_scalars)
+        .chain(sqr)
+        .collect::<Vec<_>>();
+        let result = C::msm_unchecked(&bases, &scalars).is_zero();
+
+        end_timer!(verifier_timer);
+        Ok(result)
+    }
+
+    /// Bind the transcript to the bit width, the number of values and the commitments.
+    pub fn append_instance(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<(), RangeProofError> {
+        let mut bytes = [pp.bits as u64, instance.v.len() as u64]
+            .iter()
+            .flat_map(|x| x.to_be_bytes())
+            .collect::<Vec<_>>();
+        bytes.extend(points_to_bytes(&instance.v).ok_or(RangeProofError::IdentityPoint)?);
+        transcript.append(b"instance", &bytes);
+        Ok(())
+    }
+
+    pub fn compute_yz(
+        a: &C::Affine,
+        s: &C::Affine,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<(C::ScalarField, C::ScalarField), RangeProofError> {
+        let bytes = points_to_bytes(&[*a, *s]).ok_or(RangeProofError::IdentityPoint)?;
+        transcript.append(b"commitment", &bytes);
+        let y = transcript.challenge_scalar(b"challenge");
+        let z = transcript.challenge_scalar(b"challenge");
+        Ok((y, z))
+    }
+
+    pub fn compute_x(
+        t1: &C::Affine,
+        t2: &C::Affine,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<C::ScalarField, RangeProofError> {
+        let bytes = points_to_bytes(&[*t1, *t2]).ok_or(RangeProofError::IdentityPoint)?;
+        transcript.append(b"polynomial commitment", &bytes);
+        Ok(transcript.challenge_scalar(b"challenge"))
+    }
+
+    pub fn compute_w(
+        tau_x: C::ScalarField,
+        mu: C::ScalarField,
+        t_hat: C::ScalarField,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> C::ScalarField {
+        let bytes = [tau_x, mu, t_hat]
+            .iter()
+            .flat_map(|s| s.into_bigint().to_bytes_be())
+            .collect::<Vec<_>>();
+        transcript.append(b"evaluation", &bytes);
+        transcript.challenge_scalar(b"challenge")
+    }
+}
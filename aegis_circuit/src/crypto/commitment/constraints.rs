@@ -1,20 +1,44 @@
 use ark_ff::Field;
-use ark_r1cs_std::alloc::AllocVar;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget};
 use ark_relations::r1cs::SynthesisError;
 use ark_std::vec::Vec;
 
-use super::BatchCommitmentScheme;
+use super::{BatchCommitmentScheme, CommitmentScheme};
+
+/// Gadget computing the commitment itself inside the circuit.
+pub trait CommitmentGadget<C: CommitmentScheme, ConstraintF: Field> {
+    type ScalarVar: AllocVar<C::Scalar, ConstraintF> + Clone;
+    type CommitmentVar: AllocVar<C::Commitment, ConstraintF> + EqGadget<ConstraintF> + Clone;
+
+    /// Commit to `scalars` under the (constant) `committing_key`.
+    fn commit(
+        committing_key: &[C::Base],
+        scalars: &[Self::ScalarVar],
+    ) -> Result<Self::CommitmentVar, SynthesisError>;
+
+    /// Enforce that `commitment` opens to `scalars` under `committing_key`.
+    fn enforce_opening(
+        commitment: &Self::CommitmentVar,
+        committing_key: &[C::Base],
+        scalars: &[Self::ScalarVar],
+    ) -> Result<(), SynthesisError> {
+        Self::commit(committing_key, scalars)?.enforce_equal(commitment)
+    }
+}
 
 pub trait BatchCommitmentGadget<C: BatchCommitmentScheme, ConstraintF: Field> {
     type ScalarVar: AllocVar<C::Scalar, ConstraintF> + Clone;
     type ChallengeVar: AllocVar<C::Challenge, ConstraintF> + Clone;
 
+    /// Aggregate the committed vectors with the powers of `tau`.
+    /// Returns the aggregation and the next initial power of `tau`.
     fn aggregate(
         commitments: Vec<Vec<Self::ScalarVar>>,
         tau: Self::ChallengeVar,
         initial: Option<Self::ChallengeVar>,
-    ) -> (Vec<Self::ScalarVar>, Self::ChallengeVar);
+    ) -> Result<(Vec<Self::ScalarVar>, Self::ChallengeVar), SynthesisError>;
 
+    /// Enforce that `aggregation` is the aggregation of the committed vectors.
     fn enforce_equal(
         aggregation: Vec<Self::ScalarVar>,
         commitments: Vec<Vec<Self::ScalarVar>>,
@@ -0,0 +1,189 @@
+use std::{
+    marker::PhantomData,
+    ops::{Add, Sub},
+};
+
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::UniformRand;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{rand::Rng, vec::Vec};
+
+use super::{pedersen::Pedersen, BatchCommitmentScheme, CommitmentScheme};
+use crate::{crypto::dlog::BabyStepTable, solidity::Solidity};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Exponential ElGamal under an auditor public key `pk = sk · G`.
+///
+/// The committing key is `[g_0, ..., g_{n-1}, pk]` and the scalars are `[m_0, ..., m_{n-1}, r]`,
+/// so that `c1 = r · G` and `c2 = Σ m_i · g_i + r · pk`.
+/// `c2` is a Pedersen commitment with `pk` as the blinding base, and the auditor can strip
+/// the blinding with `sk` to recover `Σ m_i · g_i`.
+pub struct ElGamal<C: CurveGroup> {
+    _group: PhantomData<C>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ElGamalError {
+    /// No randomness `r` follows the messages.
+    MissingRandomness,
+    /// The scalars do not match the message generators and the public key.
+    MismatchParameterLength,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Ciphertext<C: CurveGroup> {
+    /// `r · G`
+    pub c1: C::Affine,
+    /// `Σ m_i · g_i + r · pk`
+    pub c2: C::Affine,
+}
+
+impl<C: CurveGroup> Default for Ciphertext<C> {
+    fn default() -> Self {
+        Self {
+            c1: C::Affine::zero(),
+            c2: C::Affine::zero(),
+        }
+    }
+}
+
+impl<C: CurveGroup> Add for Ciphertext<C> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            c1: (self.c1 + other.c1).into_affine(),
+            c2: (self.c2 + other.c2).into_affine(),
+        }
+    }
+}
+
+impl<C: CurveGroup> Sub for Ciphertext<C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            c1: (self.c1.into_group() - other.c1).into_affine(),
+            c2: (self.c2.into_group() - other.c2).into_affine(),
+        }
+    }
+}
+
+impl<C: CurveGroup> Solidity for Ciphertext<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [self.c1.to_solidity(), self.c2.to_solidity()].concat()
+    }
+}
+
+impl<C: CurveGroup> ElGamal<C> {
+    /// Generate the auditor key pair `(sk, pk)`.
+    pub fn keygen(rng: &mut impl Rng) -> (C::ScalarField, C::Affine) {
+        let sk = C::ScalarField::rand(rng);
+        let pk = (C::generator() * sk).into_affine();
+        (sk, pk)
+    }
+
+    /// Append the auditor public key to the message generators.
+    pub fn committing_key(generators: &[C::Affine], pk: &C::Affine) -> Vec<C::Affine> {
+        [generators, &[*pk]].concat()
+    }
+
+    /// Encrypt `[m_0, ..., m_{n-1}, r]` under `committing_key = [g_0, ..., g_{n-1}, pk]`.
+    pub fn encrypt(
+        committing_key: &[C::Affine],
+        scalars: &[C::ScalarField],
+    ) -> Result<Ciphertext<C>, ElGamalError> {
+        let (r, _) = scalars
+            .split_last()
+            .ok_or(ElGamalError::MissingRandomness)?;
+        if scalars.len() != committing_key.len() {
+            return Err(ElGamalError::MismatchParameterLength);
+        }
+
+        Ok(Ciphertext {
+            c1: (C::generator() * r).into_affine(),
+            c2: Pedersen::<C>::commit(committing_key, scalars),
+        })
+    }
+
+    /// Strip the blinding of `ciphertext` and return `Σ m_i · g_i`.
+    pub fn decrypt(sk: &C::ScalarField, ciphertext: &Ciphertext<C>) -> C::Affine {
+        (ciphertext.c2.into_group() - ciphertext.c1 * sk).into_affine()
+    }
+
+    /// Recover the amount of a single-amount ciphertext, where `table.base()` is the amount generator.
+    pub fn decrypt_amount(
+        sk: &C::ScalarField,
+        ciphertext: &Ciphertext<C>,
+        table: &BabyStepTable<C>,
+    ) -> Option<u64> {
+        table.solve(&Self::decrypt(sk, ciphertext))
+    }
+}
+
+impl<C: CurveGroup> CommitmentScheme for ElGamal<C> {
+    type Scalar = C::ScalarField;
+    type Base = C::Affine;
+    type Commitment = Ciphertext<C>;
+
+    /// [`ElGamal::encrypt`].
+    ///
+    /// # Panics
+    ///
+    /// If the scalars do not match the committing key; use [`ElGamal::encrypt`] to handle
+    /// the mismatch instead.
+    fn commit(committing_key: &[Self::Base], commitments: &[Self::Scalar]) -> Self::Commitment {
+        Self::encrypt(committing_key, commitments)
+            .expect("one scalar per committing key generator, the last one being the randomness")
+    }
+}
+
+impl<C: CurveGroup> BatchCommitmentScheme for ElGamal<C> {
+    type Challenge = C::ScalarField;
+
+    fn batch_commit(
+        batch_key: &[Self::Base],
+        commitments: &[&[Self::Scalar]],
+    ) -> Vec<Self::Commitment> {
+        cfg_iter!(commitments)
+            .map(|cm| ElGamal::<C>::commit(batch_key, cm))
+            .collect()
+    }
+
+    fn challenge(
+        public_inputs: &[Self::Scalar],
+        commitments: &[Self::Commitment],
+        proof_dependent_commitment: &Self::Base,
+    ) -> Self::Challenge {
+        let points = commitments
+            .iter()
+            .flat_map(|ct| [ct.c1, ct.c2])
+            .collect::<Vec<_>>();
+        Pedersen::<C>::challenge(public_inputs, &points, proof_dependent_commitment)
+    }
+
+    fn aggregate(
+        commitments: &[Self::Commitment],
+        tau: Self::Challenge,
+        initial: Option<Self::Challenge>,
+    ) -> (Self::Commitment, Self::Challenge) {
+        let (c1, c2): (Vec<_>, Vec<_>) = commitments.iter().map(|ct| (ct.c1, ct.c2)).unzip();
+        let (c1, _) = Pedersen::<C>::aggregate(&c1, tau, initial);
+        let (c2, next) = Pedersen::<C>::aggregate(&c2, tau, initial);
+
+        (Ciphertext { c1, c2 }, next)
+    }
+
+    fn scalar_aggregate(
+        commitments: &[&[Self::Scalar]],
+        tau: Self::Challenge,
+        initial: Option<Self::Challenge>,
+    ) -> (Vec<Self::Scalar>, Self::Challenge) {
+        Pedersen::<C>::scalar_aggregate(commitments, tau, initial)
+    }
+}
@@ -1,6 +1,7 @@
 pub mod constraints;
-pub use constraints::BatchCommitmentGadget;
+pub use constraints::{BatchCommitmentGadget, CommitmentGadget};
 
+pub mod elgamal;
 pub mod pedersen;
 
 use ark_std::vec::Vec;
@@ -25,7 +26,7 @@ pub trait BatchCommitmentScheme: CommitmentScheme {
 
     fn challenge(
         public_inputs: &[Self::Scalar],
-        commitments: &[Self::Base],
+        commitments: &[Self::Commitment],
         proof_dependent_commitment: &Self::Base,
     ) -> Self::Challenge;
 
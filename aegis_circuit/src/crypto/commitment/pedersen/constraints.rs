@@ -1,13 +1,18 @@
 use std::{fmt::Debug, marker::PhantomData};
 
-use ark_ec::CurveGroup;
-use ark_r1cs_std::{boolean::Boolean, fields::FieldVar};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ed_on_bn254::{constraints::EdwardsVar, EdwardsProjective};
+use ark_ff::{Field, PrimeField};
+use ark_r1cs_std::{
+    fields::{nonnative::NonNativeFieldVar, FieldVar},
+    groups::CurveVar,
+};
 use ark_relations::r1cs::SynthesisError;
 use ark_std::ops::Add;
 
-use crate::crypto::commitment::constraints::BatchCommitmentGadget;
+use crate::crypto::commitment::constraints::{BatchCommitmentGadget, CommitmentGadget};
 
-use super::Pedersen;
+use super::{BasePrimeField, Pedersen};
 
 pub struct PedersenGadget<C, FV>
 where
@@ -30,7 +35,9 @@ where
         commitments: Vec<Vec<Self::ScalarVar>>,
         tau: Self::ChallengeVar,
         initial: Option<Self::ChallengeVar>,
-    ) -> (Vec<Self::ScalarVar>, Self::ChallengeVar) {
+    ) -> Result<(Vec<Self::ScalarVar>, Self::ChallengeVar), SynthesisError> {
+        let len = commitment_length(&commitments)?;
+
         let mut powers_of_tau = vec![];
         let mut cur = initial.unwrap_or(tau.clone());
         for _ in 0..commitments.len() {
@@ -38,10 +45,7 @@ where
             cur *= &tau;
         }
 
-        let len = commitments[0].len();
-        let indicies = 0..len;
-
-        let aggregation = indicies
+        let aggregation = (0..len)
             .map(|c| {
                 commitments
                     .iter()
@@ -52,7 +56,7 @@ where
             })
             .collect::<Vec<Self::ScalarVar>>();
 
-        (aggregation, cur)
+        Ok((aggregation, cur))
     }
 
     fn enforce_equal(
@@ -61,36 +65,211 @@ where
         tau: Self::ChallengeVar,
         initial: Option<Self::ChallengeVar>,
     ) -> Result<(), SynthesisError> {
-        let mut powers_of_tau = vec![];
-        let mut cur = initial.unwrap_or(tau.clone());
-        for _ in 0..commitments.len() {
-            powers_of_tau.push(cur.clone());
-            cur *= &tau;
+        if aggregation.len() != commitment_length(&commitments)? {
+            return Err(SynthesisError::Unsatisfiable);
         }
 
-        let len = commitments[0].len();
-        let indicies = 0..len;
+        let (_aggregation, _) = Self::aggregate(commitments, tau, initial)?;
+        _aggregation
+            .iter()
+            .zip(aggregation.iter())
+            .try_for_each(|(_aggr, aggr)| _aggr.enforce_equal(aggr))
+    }
+}
 
-        let _aggregation = indicies
-            .map(|c| {
-                commitments
-                    .iter()
-                    .zip(powers_of_tau.iter())
-                    .fold(Self::ScalarVar::zero(), |acc, (cm, tau)| {
-                        acc + cm[c].clone() * tau
+/// Pedersen aggregation gadget evaluating `Σ cm_i · initial · τ^i` in Horner form.
+///
+/// Each column costs `b` multiplications for `b` commitments, and no powers of `τ`
+/// are allocated. In `enforce_equal` the final multiplication by the initial power
+/// is merged with the equality check.
+pub struct PedersenHornerGadget<C, FV>
+where
+    C: CurveGroup,
+    FV: FieldVar<C::ScalarField, C::ScalarField>,
+{
+    _group: PhantomData<C>,
+    _field: PhantomData<FV>,
+}
+
+impl<C, FV> BatchCommitmentGadget<Pedersen<C>, C::ScalarField> for PedersenHornerGadget<C, FV>
+where
+    C: CurveGroup,
+    FV: FieldVar<C::ScalarField, C::ScalarField> + Clone + Debug,
+{
+    type ScalarVar = FV;
+    type ChallengeVar = FV;
+
+    fn aggregate(
+        commitments: Vec<Vec<Self::ScalarVar>>,
+        tau: Self::ChallengeVar,
+        initial: Option<Self::ChallengeVar>,
+    ) -> Result<(Vec<Self::ScalarVar>, Self::ChallengeVar), SynthesisError> {
+        horner_aggregate(&commitments, &tau, initial)
+    }
+
+    fn enforce_equal(
+        aggregation: Vec<Self::ScalarVar>,
+        commitments: Vec<Vec<Self::ScalarVar>>,
+        tau: Self::ChallengeVar,
+        initial: Option<Self::ChallengeVar>,
+    ) -> Result<(), SynthesisError> {
+        horner_enforce_equal(&aggregation, &commitments, &tau, initial)
+    }
+}
+
+/// Pedersen commitment gadget over a curve `C` embedded in the constraint field,
+/// e.g. Baby Jubjub over BN254 (see [`BabyJubJubGadget`]).
+///
+/// Scalars of `C` are emulated with [`NonNativeFieldVar`] and the committing key is
+/// constant, so commitments use fixed-base scalar multiplication.
+pub struct PedersenCurveGadget<C, GG>
+where
+    C: CurveGroup,
+    GG: CurveVar<C, BasePrimeField<C>>,
+{
+    _group: PhantomData<C>,
+    _group_var: PhantomData<GG>,
+}
+
+/// Pedersen commitment gadget over Baby Jubjub, the twisted Edwards curve embedded in BN254.
+pub type BabyJubJubGadget = PedersenCurveGadget<EdwardsProjective, EdwardsVar>;
+
+impl<C, GG> CommitmentGadget<Pedersen<C>, BasePrimeField<C>> for PedersenCurveGadget<C, GG>
+where
+    C: CurveGroup,
+    BasePrimeField<C>: PrimeField,
+    GG: CurveVar<C, BasePrimeField<C>>,
+{
+    type ScalarVar = NonNativeFieldVar<C::ScalarField, BasePrimeField<C>>;
+    type CommitmentVar = GG;
+
+    fn commit(
+        committing_key: &[C::Affine],
+        scalars: &[Self::ScalarVar],
+    ) -> Result<Self::CommitmentVar, SynthesisError> {
+        if committing_key.len() < scalars.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // [g, 2g, 4g, ...] for every generator of the committing key
+        let num_bits = C::ScalarField::MODULUS_BIT_SIZE as usize;
+        let bases = committing_key[..scalars.len()]
+            .iter()
+            .map(|g| {
+                let mut base = g.into_group();
+                (0..num_bits)
+                    .map(|_| {
+                        let cur = base;
+                        base.double_in_place();
+                        cur
                     })
+                    .collect::<Vec<_>>()
             })
-            .collect::<Vec<Self::ScalarVar>>();
+            .collect::<Vec<_>>();
 
-        _aggregation
-            .iter()
-            .zip(aggregation.iter())
-            .for_each(|(_aggr, aggr)| {
-                _aggr
-                    .conditional_enforce_equal(aggr, &Boolean::constant(true))
-                    .unwrap()
-            });
+        GG::precomputed_base_multiscalar_mul_le(&bases, scalars.iter())
+    }
+}
+
+impl<C, GG> BatchCommitmentGadget<Pedersen<C>, BasePrimeField<C>> for PedersenCurveGadget<C, GG>
+where
+    C: CurveGroup,
+    BasePrimeField<C>: PrimeField,
+    GG: CurveVar<C, BasePrimeField<C>>,
+{
+    type ScalarVar = NonNativeFieldVar<C::ScalarField, BasePrimeField<C>>;
+    type ChallengeVar = NonNativeFieldVar<C::ScalarField, BasePrimeField<C>>;
+
+    fn aggregate(
+        commitments: Vec<Vec<Self::ScalarVar>>,
+        tau: Self::ChallengeVar,
+        initial: Option<Self::ChallengeVar>,
+    ) -> Result<(Vec<Self::ScalarVar>, Self::ChallengeVar), SynthesisError> {
+        horner_aggregate(&commitments, &tau, initial)
+    }
+
+    fn enforce_equal(
+        aggregation: Vec<Self::ScalarVar>,
+        commitments: Vec<Vec<Self::ScalarVar>>,
+        tau: Self::ChallengeVar,
+        initial: Option<Self::ChallengeVar>,
+    ) -> Result<(), SynthesisError> {
+        horner_enforce_equal(&aggregation, &commitments, &tau, initial)
+    }
+}
+
+/// Compute `Σ cm_i[c] · τ^i` for every column `c`.
+fn horner<F, CF, FV>(commitments: &[Vec<FV>], tau: &FV, len: usize) -> Vec<FV>
+where
+    F: Field,
+    CF: PrimeField,
+    FV: FieldVar<F, CF>,
+{
+    (0..len)
+        .map(|c| {
+            let mut rev = commitments.iter().rev();
+            // `commitment_length` guarantees at least one commitment
+            let last = rev.next().map(|cm| cm[c].clone()).unwrap_or_else(FV::zero);
+            rev.fold(last, |acc, cm| acc * tau + &cm[c])
+        })
+        .collect()
+}
+
+fn horner_aggregate<F, CF, FV>(
+    commitments: &[Vec<FV>],
+    tau: &FV,
+    initial: Option<FV>,
+) -> Result<(Vec<FV>, FV), SynthesisError>
+where
+    F: Field,
+    CF: PrimeField,
+    FV: FieldVar<F, CF>,
+{
+    let len = commitment_length(commitments)?;
+    let initial = initial.unwrap_or(tau.clone());
+
+    let aggregation = horner(commitments, tau, len)
+        .into_iter()
+        .map(|h| h * &initial)
+        .collect();
+    // next initial: initial * τ^b, by square-and-multiply
+    let next = tau.pow_by_constant([commitments.len() as u64])? * &initial;
+
+    Ok((aggregation, next))
+}
+
+fn horner_enforce_equal<F, CF, FV>(
+    aggregation: &[FV],
+    commitments: &[Vec<FV>],
+    tau: &FV,
+    initial: Option<FV>,
+) -> Result<(), SynthesisError>
+where
+    F: Field,
+    CF: PrimeField,
+    FV: FieldVar<F, CF>,
+{
+    let len = commitment_length(commitments)?;
+    if aggregation.len() != len {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    let initial = initial.unwrap_or(tau.clone());
+
+    horner(commitments, tau, len)
+        .iter()
+        .zip(aggregation.iter())
+        .try_for_each(|(h, aggr)| h.mul_equals(&initial, aggr))
+}
 
-        Ok(())
+/// Returns the common length of the committed vectors.
+/// Fails if there is no commitment or the lengths differ.
+fn commitment_length<FV>(commitments: &[Vec<FV>]) -> Result<usize, SynthesisError> {
+    let len = commitments
+        .first()
+        .map(|cm| cm.len())
+        .ok_or(SynthesisError::Unsatisfiable)?;
+    if commitments.iter().any(|cm| cm.len() != len) {
+        return Err(SynthesisError::Unsatisfiable);
     }
+    Ok(len)
 }
@@ -1,5 +1,7 @@
 pub mod constraints;
-pub use constraints::PedersenGadget;
+pub use constraints::{
+    BabyJubJubGadget, PedersenCurveGadget, PedersenGadget, PedersenHornerGadget,
+};
 
 use std::marker::PhantomData;
 
@@ -66,13 +68,16 @@ impl<C: CurveGroup> BatchCommitmentScheme for Pedersen<C> {
             .iter()
             .for_each(|x| strings.push(x.to_string()));
 
-        commitments.iter().for_each(|cm| {
-            strings.push(cm.x().unwrap().to_string());
-            strings.push(cm.y().unwrap().to_string());
-        });
-
-        strings.push(proof_dependent_commitment.x().unwrap().to_string());
-        strings.push(proof_dependent_commitment.y().unwrap().to_string());
+        // the identity has no affine coordinates and is hashed as (0, 0), as on the EVM
+        let mut push_point = |point: &C::Affine| match point.xy() {
+            Some((x, y)) => {
+                strings.push(x.to_string());
+                strings.push(y.to_string());
+            }
+            None => strings.extend(["0".to_string(), "0".to_string()]),
+        };
+        commitments.iter().for_each(&mut push_point);
+        push_point(proof_dependent_commitment);
 
         strings.iter().for_each(|s| update(s));
         drop(strings);
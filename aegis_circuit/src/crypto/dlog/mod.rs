@@ -0,0 +1,207 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+};
+use ark_std::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Amounts are range-checked to at most 64 bits in the DBT circuit, see
+/// [`AegisCircuit::check_bits`](crate::aegis::AegisCircuit::check_bits), so larger bounds are
+/// never needed.
+pub const MAX_BOUND_BITS: usize = 64;
+
+/// Upper limit on the baby-step table size, `2^MAX_BABY_BITS` entries.
+pub const MAX_BABY_BITS: usize = 32;
+
+/// Number of giant steps normalized and looked up together.
+const GIANT_CHUNK: u64 = 1 << 10;
+
+#[derive(Debug, PartialEq)]
+pub enum DLogError {
+    /// The bound exceeds [`MAX_BOUND_BITS`].
+    BoundTooLarge,
+    /// The table size exceeds [`MAX_BABY_BITS`] or the bound, or leaves `2^64` giant steps.
+    InvalidTableSize,
+}
+
+impl From<DLogError> for SerializationError {
+    fn from(_: DLogError) -> Self {
+        SerializationError::InvalidData
+    }
+}
+
+/// Precomputed baby steps for solving `m · base = target` with `0 <= m < 2^bound_bits`.
+///
+/// `m` is written as `i · 2^baby_bits + j`. The table stores a fingerprint of every `j · base`,
+/// and the solver walks `target - i · 2^baby_bits · base` for `i < 2^(bound_bits - baby_bits)`.
+/// A larger table trades memory and precomputation for fewer giant steps.
+/// The fields are checked on construction and on deserialization, where the table is
+/// recomputed from the base, so a table from untrusted bytes cannot make the solver panic or
+/// miss a baby step.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize)]
+pub struct BabyStepTable<C: CurveGroup> {
+    base: C::Affine,
+    bound_bits: u32,
+    baby_bits: u32,
+    /// `(fingerprint(j · base), j)` sorted by fingerprint
+    table: Vec<(u64, u32)>,
+}
+
+impl<C: CurveGroup> BabyStepTable<C> {
+    /// Precompute the baby steps of `base` for amounts below `2^bound_bits`.
+    pub fn new(base: C::Affine, bound_bits: usize, baby_bits: usize) -> Result<Self, DLogError> {
+        check_dimensions(bound_bits, baby_bits)?;
+        Ok(Self {
+            base,
+            bound_bits: bound_bits as u32,
+            baby_bits: baby_bits as u32,
+            table: Self::baby_steps(&base, baby_bits),
+        })
+    }
+
+    /// Table with `2^(bound_bits / 2)` baby steps, balancing baby and giant steps.
+    pub fn with_bound(base: C::Affine, bound_bits: usize) -> Result<Self, DLogError> {
+        Self::new(base, bound_bits, (bound_bits / 2).min(MAX_BABY_BITS))
+    }
+
+    /// Amount generator of the table.
+    pub fn base(&self) -> &C::Affine {
+        &self.base
+    }
+
+    pub fn bound_bits(&self) -> usize {
+        self.bound_bits as usize
+    }
+
+    pub fn baby_bits(&self) -> usize {
+        self.baby_bits as usize
+    }
+
+    /// Find `m < 2^bound_bits` with `m · base = target`.
+    pub fn solve(&self, target: &C::Affine) -> Option<u64> {
+        // checked again for tables deserialized without validation
+        check_dimensions(self.bound_bits(), self.baby_bits()).ok()?;
+
+        let solve_timer = start_timer!(|| "BSGS::Giant steps");
+        let giant_steps = 1u64 << (self.bound_bits - self.baby_bits);
+        let stride = self.base * C::ScalarField::from(1u64 << self.baby_bits);
+        let num_chunks = giant_steps.div_ceil(GIANT_CHUNK);
+
+        let search = |chunk: u64| {
+            let start = chunk * GIANT_CHUNK;
+            let end = (start + GIANT_CHUNK).min(giant_steps);
+
+            let mut cur = target.into_group() - stride * C::ScalarField::from(start);
+            let mut points = Vec::with_capacity((end - start) as usize);
+            for _ in start..end {
+                points.push(cur);
+                cur -= stride;
+            }
+            C::normalize_batch(&points)
+                .iter()
+                .zip(start..end)
+                .find_map(|(p, i)| self.lookup(p, i))
+        };
+
+        #[cfg(feature = "parallel")]
+        let m = (0..num_chunks).into_par_iter().find_map_any(search);
+        #[cfg(not(feature = "parallel"))]
+        let m = (0..num_chunks).find_map(search);
+
+        end_timer!(solve_timer);
+        m
+    }
+
+    /// `(fingerprint(j · base), j)` for `j < 2^baby_bits`, sorted.
+    fn baby_steps(base: &C::Affine, baby_bits: usize) -> Vec<(u64, u32)> {
+        let table_timer = start_timer!(|| "BSGS::Baby steps");
+        let mut steps = Vec::with_capacity(1 << baby_bits);
+        let mut cur = C::zero();
+        for _ in 0..(1u64 << baby_bits) {
+            steps.push(cur);
+            cur += base;
+        }
+        let steps = C::normalize_batch(&steps);
+
+        let mut table = cfg_iter!(steps)
+            .enumerate()
+            .map(|(j, p)| (fingerprint(p), j as u32))
+            .collect::<Vec<_>>();
+        table.sort_unstable();
+        end_timer!(table_timer);
+        table
+    }
+
+    /// Candidate `i · 2^baby_bits + j` for the baby step `j` matching `point`.
+    fn lookup(&self, point: &C::Affine, i: u64) -> Option<u64> {
+        let key = fingerprint(point);
+        let from = self.table.partition_point(|(f, _)| *f < key);
+        self.table[from..]
+            .iter()
+            .take_while(|(f, _)| *f == key)
+            // fingerprints may collide, so confirm the baby step
+            .find(|(_, j)| (self.base * C::ScalarField::from(*j as u64)).into_affine() == *point)
+            .and_then(|(_, j)| (i << self.baby_bits).checked_add(*j as u64))
+    }
+}
+
+impl<C: CurveGroup> Valid for BabyStepTable<C> {
+    /// The dimensions are in range, and the table is the one [`BabyStepTable::new`] computes
+    /// from the base, at the same cost.
+    fn check(&self) -> Result<(), SerializationError> {
+        self.base.check()?;
+        check_dimensions(self.bound_bits(), self.baby_bits())?;
+
+        if self.table.len() != 1 << self.baby_bits
+            || self.table != Self::baby_steps(&self.base, self.baby_bits())
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl<C: CurveGroup> CanonicalDeserialize for BabyStepTable<C> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let table = Self {
+            base: C::Affine::deserialize_with_mode(&mut reader, compress, validate)?,
+            bound_bits: u32::deserialize_with_mode(&mut reader, compress, validate)?,
+            baby_bits: u32::deserialize_with_mode(&mut reader, compress, validate)?,
+            table: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if let Validate::Yes = validate {
+            table.check()?;
+        }
+        Ok(table)
+    }
+}
+
+/// `baby_bits <= bound_bits <= MAX_BOUND_BITS`, `baby_bits <= MAX_BABY_BITS`, and fewer than
+/// `2^64` giant steps, so the shifts of the solver cannot overflow.
+fn check_dimensions(bound_bits: usize, baby_bits: usize) -> Result<(), DLogError> {
+    if bound_bits > MAX_BOUND_BITS {
+        return Err(DLogError::BoundTooLarge);
+    }
+    if baby_bits > MAX_BABY_BITS || baby_bits > bound_bits || bound_bits - baby_bits >= 64 {
+        return Err(DLogError::InvalidTableSize);
+    }
+    Ok(())
+}
+
+/// Low 64 bits of the compressed encoding of `point`.
+fn fingerprint<G: AffineRepr>(point: &G) -> u64 {
+    if point.is_zero() {
+        return 0;
+    }
+    let mut bytes = vec![];
+    point.serialize_compressed(&mut bytes).unwrap();
+    let mut fingerprint = [0u8; 8];
+    fingerprint.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(fingerprint)
+}
@@ -1,2 +1,4 @@
 pub mod commitment;
+pub mod dlog;
 pub mod protocol;
+pub mod range;
@@ -0,0 +1,115 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{
+    fmt::Debug,
+    rand::{CryptoRng, RngCore},
+};
+
+/// Prover of an interactive protocol, answering every verifier challenge with a serialized
+/// round message.
+///
+/// A prover keeps its secret randomness until it answers, and cannot be copied: answering two
+/// challenges from the same state would reveal the witness. Tests rewind the AmComEq prover
+/// through its test-only `snapshot`.
+pub trait InteractiveProver {
+    type Error: Debug + 'static;
+
+    /// Next message in reply to the verifier's last challenge.
+    /// Fails with [`InteractiveError::OutOfOrder`] once the last message has been sent.
+    fn next_message<R: RngCore + CryptoRng>(
+        &mut self,
+        challenge: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>, InteractiveError<Self::Error>>;
+}
+
+/// Verifier of an interactive protocol, sampling its challenges from `rng` instead of a
+/// transcript.
+pub trait InteractiveVerifier {
+    type Error: Debug + 'static;
+
+    /// Next move after the prover's last message, `None` at the start.
+    /// The verifier always moves first; protocols opened by the prover start with an empty
+    /// challenge. A malformed message is rejected, not an error.
+    fn next_move<R: RngCore + CryptoRng>(
+        &mut self,
+        message: Option<&[u8]>,
+        rng: &mut R,
+    ) -> Result<Move, InteractiveError<Self::Error>>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Move {
+    /// Serialized challenge for the prover.
+    Challenge(Vec<u8>),
+    /// Final decision of the verifier.
+    Decision(bool),
+}
+
+/// Challenge of the verifier and the prover's reply, as exchanged over the channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Round {
+    pub challenge: Vec<u8>,
+    pub message: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InteractiveError<E> {
+    /// Error of the underlying protocol.
+    Protocol(E),
+    /// The prover cannot decode a challenge.
+    MalformedChallenge,
+    /// The party was called out of turn, e.g. after its last message.
+    OutOfOrder,
+}
+
+impl<E> InteractiveError<E> {
+    /// Convert the protocol error, e.g. of a sub-protocol into the composed one.
+    pub fn map<F>(self, f: impl FnOnce(E) -> F) -> InteractiveError<F> {
+        match self {
+            InteractiveError::Protocol(e) => InteractiveError::Protocol(f(e)),
+            InteractiveError::MalformedChallenge => InteractiveError::MalformedChallenge,
+            InteractiveError::OutOfOrder => InteractiveError::OutOfOrder,
+        }
+    }
+}
+
+/// Compressed encoding of a round message or challenge.
+pub fn encode<T: CanonicalSerialize>(item: &T) -> Vec<u8> {
+    let mut bytes = vec![];
+    item.serialize_compressed(&mut bytes)
+        .expect("serialization into a vector cannot fail");
+    bytes
+}
+
+/// Decode a round message or challenge, `None` if it is malformed or has trailing bytes.
+pub fn decode<T: CanonicalDeserialize>(bytes: &[u8]) -> Option<T> {
+    let mut reader = bytes;
+    let item = T::deserialize_compressed(&mut reader).ok()?;
+    reader.is_empty().then_some(item)
+}
+
+/// Run the prover against the verifier over a local channel.
+/// Returns the decision and every round exchanged, for auditing.
+pub fn run<P, V, R>(
+    prover: &mut P,
+    verifier: &mut V,
+    rng: &mut R,
+) -> Result<(bool, Vec<Round>), InteractiveError<P::Error>>
+where
+    P: InteractiveProver,
+    V: InteractiveVerifier<Error = P::Error>,
+    R: RngCore + CryptoRng,
+{
+    let mut rounds = vec![];
+    let mut step = verifier.next_move(None, rng)?;
+    loop {
+        match step {
+            Move::Challenge(challenge) => {
+                let message = prover.next_message(&challenge, rng)?;
+                step = verifier.next_move(Some(&message), rng)?;
+                rounds.push(Round { challenge, message });
+            }
+            Move::Decision(accepted) => return Ok((accepted, rounds)),
+        }
+    }
+}
@@ -1,2 +1,3 @@
+pub mod interactive;
 pub mod sigma;
 pub mod transcript;
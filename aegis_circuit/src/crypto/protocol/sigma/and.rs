@@ -0,0 +1,142 @@
+use std::marker::PhantomData;
+
+use ark_std::rand::{CryptoRng, RngCore};
+
+use crate::crypto::protocol::transcript::TranscriptProtocol;
+
+use super::{ComposableSigma, CompositionError, MovesProof, SigmaProtocol};
+
+/// Conjunction of two sigma protocols answering one shared challenge.
+///
+/// Both statements are appended, then both commitments, before the challenge is sampled.
+pub struct And<P1, P2> {
+    _protocols: PhantomData<(P1, P2)>,
+}
+
+type AndError<P1, P2> =
+    CompositionError<<P1 as SigmaProtocol>::Error, <P2 as SigmaProtocol>::Error>;
+
+impl<P1, P2> SigmaProtocol for And<P1, P2>
+where
+    P1: ComposableSigma,
+    P2: ComposableSigma<Challenge = P1::Challenge>,
+{
+    type PublicParameters = (P1::PublicParameters, P2::PublicParameters);
+    type Instance = (P1::Instance, P2::Instance);
+    type Witness = (P1::Witness, P2::Witness);
+    type Proof =
+        MovesProof<<Self as ComposableSigma>::Commitment, <Self as ComposableSigma>::Response>;
+    type Error = AndError<P1, P2>;
+
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Ok((
+            P1::setup(&pp.0).map_err(CompositionError::Left)?,
+            P2::setup(&pp.1).map_err(CompositionError::Right)?,
+        ))
+    }
+
+    fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error> {
+        Self::prove_moves(pp, instance, witness, transcript, rng)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        Self::verify_moves(pp, instance, proof, transcript)
+    }
+}
+
+impl<P1, P2> ComposableSigma for And<P1, P2>
+where
+    P1: ComposableSigma,
+    P2: ComposableSigma<Challenge = P1::Challenge>,
+{
+    type Challenge = P1::Challenge;
+    type Context = (P1::Context, P2::Context);
+    type Commitment = (P1::Commitment, P2::Commitment);
+    type Response = (P1::Response, P2::Response);
+    type State = (P1::State, P2::State);
+
+    fn append_instance<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        transcript: &mut T,
+    ) -> Result<Self::Context, Self::Error> {
+        Ok((
+            P1::append_instance(&pp.0, &instance.0, transcript).map_err(CompositionError::Left)?,
+            P2::append_instance(&pp.1, &instance.1, transcript).map_err(CompositionError::Right)?,
+        ))
+    }
+
+    fn commit<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> Result<(Self::State, Self::Commitment), Self::Error> {
+        let (s1, c1) =
+            P1::commit(&pp.0, &context.0, &witness.0, rng).map_err(CompositionError::Left)?;
+        let (s2, c2) =
+            P2::commit(&pp.1, &context.1, &witness.1, rng).map_err(CompositionError::Right)?;
+        Ok(((s1, s2), (c1, c2)))
+    }
+
+    fn append_commitment<T: TranscriptProtocol>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), Self::Error> {
+        P1::append_commitment(&commitment.0, transcript).map_err(CompositionError::Left)?;
+        P2::append_commitment(&commitment.1, transcript).map_err(CompositionError::Right)
+    }
+
+    fn respond(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: Self::Challenge,
+    ) -> Result<Self::Response, Self::Error> {
+        Ok((
+            P1::respond(&pp.0, &context.0, &witness.0, state.0, challenge)
+                .map_err(CompositionError::Left)?,
+            P2::respond(&pp.1, &context.1, &witness.1, state.1, challenge)
+                .map_err(CompositionError::Right)?,
+        ))
+    }
+
+    fn check(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        commitment: &Self::Commitment,
+        challenge: Self::Challenge,
+        response: &Self::Response,
+    ) -> Result<bool, Self::Error> {
+        let left = P1::check(&pp.0, &context.0, &commitment.0, challenge, &response.0)
+            .map_err(CompositionError::Left)?;
+        let right = P2::check(&pp.1, &context.1, &commitment.1, challenge, &response.1)
+            .map_err(CompositionError::Right)?;
+        Ok(left && right)
+    }
+
+    fn simulate<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        challenge: Self::Challenge,
+        rng: &mut R,
+    ) -> Result<(Self::Commitment, Self::Response), Self::Error> {
+        let (c1, r1) =
+            P1::simulate(&pp.0, &context.0, challenge, rng).map_err(CompositionError::Left)?;
+        let (c2, r2) =
+            P2::simulate(&pp.1, &context.1, challenge, rng).map_err(CompositionError::Right)?;
+        Ok(((c1, c2), (r1, r2)))
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq)]
+pub enum CompositionError<E1, E2> {
+    /// Error of the left protocol.
+    Left(E1),
+    /// Error of the right protocol.
+    Right(E2),
+    /// The witness answering the challenge is not the branch the commitment was made for.
+    WitnessMismatch,
+}
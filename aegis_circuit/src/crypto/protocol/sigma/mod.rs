@@ -1,5 +1,15 @@
 use super::transcript::TranscriptProtocol;
-use ark_std::rand::{CryptoRng, RngCore};
+use ark_ff::PrimeField;
+use ark_std::{
+    fmt::Debug,
+    rand::{CryptoRng, RngCore},
+};
+
+pub mod and;
+pub mod or;
+
+mod errors;
+pub use errors::CompositionError;
 
 /// Sigma protocol trait for zero-knowledge proof systems.
 pub trait SigmaProtocol {
@@ -8,9 +18,11 @@ pub trait SigmaProtocol {
     type Witness;
     type Proof;
 
-    type Error: 'static;
+    /// Returned for malformed public parameters, instances or witnesses.
+    /// An invalid proof is not an error: `verify` returns `Ok(false)`.
+    type Error: Debug + 'static;
 
-    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, ()>;
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error>;
 
     fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
         pp: &Self::PublicParameters,
@@ -18,12 +30,111 @@ pub trait SigmaProtocol {
         witness: &Self::Witness,
         transcript: &mut T,
         rng: &mut R,
-    ) -> Result<Self::Proof, ()>;
+    ) -> Result<Self::Proof, Self::Error>;
 
     fn verify<T: TranscriptProtocol>(
         pp: &Self::PublicParameters,
         instance: &Self::Instance,
         proof: &Self::Proof,
         transcript: &mut T,
-    ) -> Result<bool, ()>;
+    ) -> Result<bool, Self::Error>;
+}
+
+/// Sigma protocol exposing its three moves (commitment, challenge, response) and a simulator,
+/// so that it can be composed with [`And`](and::And) and [`Or`](or::Or).
+///
+/// The instance is appended first, then the commitment, and the challenge is sampled under the
+/// label `challenge`, so a protocol whose `prove` runs the same moves keeps its proofs.
+pub trait ComposableSigma: SigmaProtocol {
+    type Challenge: PrimeField;
+    /// Statement data derived from the instance, e.g. coefficients sampled from the transcript.
+    type Context;
+    type Commitment;
+    type Response;
+    /// Secrets kept by the prover between the commitment and the response.
+    type State;
+
+    /// Append the instance to the transcript and derive the context of both parties.
+    fn append_instance<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        transcript: &mut T,
+    ) -> Result<Self::Context, Self::Error>;
+
+    fn commit<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> Result<(Self::State, Self::Commitment), Self::Error>;
+
+    /// Fails if the commitment cannot be encoded, e.g. it contains the identity.
+    fn append_commitment<T: TranscriptProtocol>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), Self::Error>;
+
+    fn respond(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: Self::Challenge,
+    ) -> Result<Self::Response, Self::Error>;
+
+    fn check(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        commitment: &Self::Commitment,
+        challenge: Self::Challenge,
+        response: &Self::Response,
+    ) -> Result<bool, Self::Error>;
+
+    /// Accepting commitment and response for a challenge chosen in advance.
+    fn simulate<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        challenge: Self::Challenge,
+        rng: &mut R,
+    ) -> Result<(Self::Commitment, Self::Response), Self::Error>;
+
+    /// Run the three moves with the challenge sampled from the transcript.
+    fn prove_moves<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<MovesProof<Self::Commitment, Self::Response>, Self::Error> {
+        let context = Self::append_instance(pp, instance, transcript)?;
+        let (state, commitment) = Self::commit(pp, &context, witness, rng)?;
+        Self::append_commitment(&commitment, transcript)?;
+        let challenge = transcript.challenge_scalar(b"challenge");
+        let response = Self::respond(pp, &context, witness, state, challenge)?;
+        Ok(MovesProof {
+            commitment,
+            response,
+        })
+    }
+
+    fn verify_moves<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &MovesProof<Self::Commitment, Self::Response>,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        let context = Self::append_instance(pp, instance, transcript)?;
+        let Ok(()) = Self::append_commitment(&proof.commitment, transcript) else {
+            return Ok(false);
+        };
+        let challenge = transcript.challenge_scalar(b"challenge");
+        Self::check(pp, &context, &proof.commitment, challenge, &proof.response)
+    }
+}
+
+/// Non-interactive proof made of the first and the last move.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MovesProof<Cm, Rs> {
+    pub commitment: Cm,
+    pub response: Rs,
 }
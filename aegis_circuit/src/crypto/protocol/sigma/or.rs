@@ -0,0 +1,195 @@
+use std::marker::PhantomData;
+
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    UniformRand,
+};
+
+use crate::crypto::protocol::transcript::TranscriptProtocol;
+
+use super::{ComposableSigma, CompositionError, MovesProof, SigmaProtocol};
+
+/// Disjunction of two sigma protocols (Cramer–Damgård–Schoenmakers).
+///
+/// The prover simulates the branch it has no witness for with a challenge `e_2` of its choice
+/// and answers the real branch with `e_1 = e - e_2`. The response carries `e_1`, and the
+/// verifier derives `e_2 = e - e_1`, so it cannot tell which branch is real.
+pub struct Or<P1, P2> {
+    _protocols: PhantomData<(P1, P2)>,
+}
+
+/// Witness of a single branch.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrWitness<W1, W2> {
+    Left(W1),
+    Right(W2),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrResponse<F, R1, R2> {
+    /// Challenge of the left branch, the right one is `e - e_1`
+    pub e1: F,
+    pub left: R1,
+    pub right: R2,
+}
+
+/// State of the real branch, with the simulated challenge and response of the other one.
+pub enum OrState<P1: ComposableSigma, P2: ComposableSigma> {
+    Left(P1::State, P1::Challenge, P2::Response),
+    Right(P2::State, P1::Challenge, P1::Response),
+}
+
+type OrError<P1, P2> = CompositionError<<P1 as SigmaProtocol>::Error, <P2 as SigmaProtocol>::Error>;
+
+impl<P1, P2> SigmaProtocol for Or<P1, P2>
+where
+    P1: ComposableSigma,
+    P2: ComposableSigma<Challenge = P1::Challenge>,
+{
+    type PublicParameters = (P1::PublicParameters, P2::PublicParameters);
+    type Instance = (P1::Instance, P2::Instance);
+    type Witness = OrWitness<P1::Witness, P2::Witness>;
+    type Proof =
+        MovesProof<<Self as ComposableSigma>::Commitment, <Self as ComposableSigma>::Response>;
+    type Error = OrError<P1, P2>;
+
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Ok((
+            P1::setup(&pp.0).map_err(CompositionError::Left)?,
+            P2::setup(&pp.1).map_err(CompositionError::Right)?,
+        ))
+    }
+
+    fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error> {
+        Self::prove_moves(pp, instance, witness, transcript, rng)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        Self::verify_moves(pp, instance, proof, transcript)
+    }
+}
+
+impl<P1, P2> ComposableSigma for Or<P1, P2>
+where
+    P1: ComposableSigma,
+    P2: ComposableSigma<Challenge = P1::Challenge>,
+{
+    type Challenge = P1::Challenge;
+    type Context = (P1::Context, P2::Context);
+    type Commitment = (P1::Commitment, P2::Commitment);
+    type Response = OrResponse<P1::Challenge, P1::Response, P2::Response>;
+    type State = OrState<P1, P2>;
+
+    fn append_instance<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        transcript: &mut T,
+    ) -> Result<Self::Context, Self::Error> {
+        Ok((
+            P1::append_instance(&pp.0, &instance.0, transcript).map_err(CompositionError::Left)?,
+            P2::append_instance(&pp.1, &instance.1, transcript).map_err(CompositionError::Right)?,
+        ))
+    }
+
+    fn commit<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> Result<(Self::State, Self::Commitment), Self::Error> {
+        let e_simulated = P1::Challenge::rand(rng);
+        match witness {
+            OrWitness::Left(w1) => {
+                let (s1, c1) =
+                    P1::commit(&pp.0, &context.0, w1, rng).map_err(CompositionError::Left)?;
+                let (c2, r2) = P2::simulate(&pp.1, &context.1, e_simulated, rng)
+                    .map_err(CompositionError::Right)?;
+                Ok((OrState::Left(s1, e_simulated, r2), (c1, c2)))
+            }
+            OrWitness::Right(w2) => {
+                let (s2, c2) =
+                    P2::commit(&pp.1, &context.1, w2, rng).map_err(CompositionError::Right)?;
+                let (c1, r1) = P1::simulate(&pp.0, &context.0, e_simulated, rng)
+                    .map_err(CompositionError::Left)?;
+                Ok((OrState::Right(s2, e_simulated, r1), (c1, c2)))
+            }
+        }
+    }
+
+    fn append_commitment<T: TranscriptProtocol>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), Self::Error> {
+        P1::append_commitment(&commitment.0, transcript).map_err(CompositionError::Left)?;
+        P2::append_commitment(&commitment.1, transcript).map_err(CompositionError::Right)
+    }
+
+    fn respond(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: Self::Challenge,
+    ) -> Result<Self::Response, Self::Error> {
+        match (witness, state) {
+            (OrWitness::Left(w1), OrState::Left(s1, e2, right)) => {
+                let e1 = challenge - e2;
+                let left =
+                    P1::respond(&pp.0, &context.0, w1, s1, e1).map_err(CompositionError::Left)?;
+                Ok(OrResponse { e1, left, right })
+            }
+            (OrWitness::Right(w2), OrState::Right(s2, e1, left)) => {
+                let right = P2::respond(&pp.1, &context.1, w2, s2, challenge - e1)
+                    .map_err(CompositionError::Right)?;
+                Ok(OrResponse { e1, left, right })
+            }
+            _ => Err(CompositionError::WitnessMismatch),
+        }
+    }
+
+    fn check(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        commitment: &Self::Commitment,
+        challenge: Self::Challenge,
+        response: &Self::Response,
+    ) -> Result<bool, Self::Error> {
+        let e2 = challenge - response.e1;
+        let left = P1::check(
+            &pp.0,
+            &context.0,
+            &commitment.0,
+            response.e1,
+            &response.left,
+        )
+        .map_err(CompositionError::Left)?;
+        let right = P2::check(&pp.1, &context.1, &commitment.1, e2, &response.right)
+            .map_err(CompositionError::Right)?;
+        Ok(left && right)
+    }
+
+    fn simulate<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        challenge: Self::Challenge,
+        rng: &mut R,
+    ) -> Result<(Self::Commitment, Self::Response), Self::Error> {
+        let e1 = P1::Challenge::rand(rng);
+        let (c1, left) =
+            P1::simulate(&pp.0, &context.0, e1, rng).map_err(CompositionError::Left)?;
+        let (c2, right) = P2::simulate(&pp.1, &context.1, challenge - e1, rng)
+            .map_err(CompositionError::Right)?;
+        Ok(((c1, c2), OrResponse { e1, left, right }))
+    }
+}
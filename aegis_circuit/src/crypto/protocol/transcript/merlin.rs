@@ -0,0 +1,42 @@
+use super::TranscriptProtocol;
+use ark_ff::PrimeField;
+use merlin::Transcript;
+
+/// STROBE-based transcript (Merlin).
+///
+/// Every append is framed with its label and length, so distinct message sequences never
+/// absorb the same bytes, and the transcript is bound to a protocol name from the start.
+#[derive(Clone)]
+pub struct MerlinBase {
+    transcript: Transcript,
+}
+
+impl MerlinBase {
+    /// Protocol name used by [`TranscriptProtocol::new`].
+    pub const PROTOCOL_NAME: &'static [u8] = b"Aegis";
+
+    /// Create a new transcript domain-separated by the protocol `name`.
+    pub fn with_protocol(name: &'static [u8]) -> Self {
+        MerlinBase {
+            transcript: Transcript::new(name),
+        }
+    }
+}
+
+impl TranscriptProtocol for MerlinBase {
+    /// Labels are always absorbed, so `use_label` is ignored.
+    fn new(_use_label: bool) -> Self {
+        Self::with_protocol(Self::PROTOCOL_NAME)
+    }
+
+    fn append(&mut self, label: &'static [u8], item: &[u8]) {
+        self.transcript.append_message(label, item);
+    }
+
+    /// Squeezes 128 bits more than the modulus size, so the reduction is statistically uniform.
+    fn challenge_scalar<F: PrimeField>(&mut self, label: &'static [u8]) -> F {
+        let mut bytes = vec![0u8; (F::MODULUS_BIT_SIZE as usize).div_ceil(8) + 16];
+        self.transcript.challenge_bytes(label, &mut bytes);
+        F::from_le_bytes_mod_order(&bytes)
+    }
+}
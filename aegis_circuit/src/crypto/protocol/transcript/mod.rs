@@ -1,5 +1,7 @@
 use ark_ff::PrimeField;
 
+pub mod merlin;
+pub mod poseidon;
 pub mod sha3;
 
 pub trait TranscriptProtocol: Clone {
@@ -11,4 +13,17 @@ pub trait TranscriptProtocol: Clone {
 
     /// Compute a `label`ed challenge variable.
     fn challenge_scalar<F: PrimeField>(&mut self, label: &'static [u8]) -> F;
+
+    /// Compute `n` `label`ed challenge variables.
+    fn challenge_scalars<F: PrimeField>(&mut self, label: &'static [u8], n: usize) -> Vec<F> {
+        (0..n).map(|_| self.challenge_scalar(label)).collect()
+    }
+
+    /// Derive a child transcript domain-separated by `label`.
+    /// The parent is left untouched, so several sub-protocols can branch from the same state.
+    fn fork(&self, label: &'static [u8]) -> Self {
+        let mut child = self.clone();
+        child.append(b"fork", label);
+        child
+    }
 }
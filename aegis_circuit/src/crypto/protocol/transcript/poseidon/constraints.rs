@@ -0,0 +1,100 @@
+use ark_crypto_primitives::sponge::{
+    constraints::CryptographicSpongeVar,
+    poseidon::{constraints::PoseidonSpongeVar, PoseidonConfig},
+};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::{fp::FpVar, nonnative::NonNativeFieldVar},
+    uint8::UInt8,
+};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+use super::poseidon_config;
+
+/// In-circuit counterpart of [`PoseidonBase`](super::PoseidonBase).
+///
+/// The same sequence of appends yields the same challenges as the native transcript.
+#[derive(Clone)]
+pub struct PoseidonBaseVar<CF: PrimeField> {
+    sponge: PoseidonSpongeVar<CF>,
+    use_label: bool,
+}
+
+impl<CF: PrimeField> PoseidonBaseVar<CF> {
+    /// Create a new transcript with the default Poseidon parameters.
+    pub fn new(cs: ConstraintSystemRef<CF>, use_label: bool) -> Self {
+        Self::with_config(cs, &poseidon_config(), use_label)
+    }
+
+    /// Create a new transcript with the given Poseidon `config`.
+    pub fn with_config(
+        cs: ConstraintSystemRef<CF>,
+        config: &PoseidonConfig<CF>,
+        use_label: bool,
+    ) -> Self {
+        PoseidonBaseVar {
+            sponge: PoseidonSpongeVar::new(cs, config),
+            use_label,
+        }
+    }
+
+    /// Append an `item` with the given `label`.
+    pub fn append(
+        &mut self,
+        label: &'static [u8],
+        item: &[UInt8<CF>],
+    ) -> Result<(), SynthesisError> {
+        self.append_label(label)?;
+        self.sponge.absorb(&item)
+    }
+
+    /// Append field elements of `CF` without converting them to bytes.
+    pub fn append_field_elements(
+        &mut self,
+        label: &'static [u8],
+        items: &[FpVar<CF>],
+    ) -> Result<(), SynthesisError> {
+        self.append_label(label)?;
+        self.sponge.absorb(&items)
+    }
+
+    /// Compute a `label`ed challenge in the constraint field.
+    pub fn challenge_scalar(&mut self, label: &'static [u8]) -> Result<FpVar<CF>, SynthesisError> {
+        self.append_label(label)?;
+        Ok(self.sponge.squeeze_field_elements(1)?.remove(0))
+    }
+
+    /// Compute a `label`ed challenge in a field `F` other than the constraint field.
+    pub fn challenge_nonnative<F: PrimeField>(
+        &mut self,
+        label: &'static [u8],
+    ) -> Result<NonNativeFieldVar<F, CF>, SynthesisError> {
+        self.append_label(label)?;
+        let (challenges, _) = self.sponge.squeeze_nonnative_field_elements::<F>(1)?;
+        Ok(challenges[0].clone())
+    }
+
+    /// Compute `n` `label`ed challenges in the constraint field.
+    pub fn challenge_scalars(
+        &mut self,
+        label: &'static [u8],
+        n: usize,
+    ) -> Result<Vec<FpVar<CF>>, SynthesisError> {
+        (0..n).map(|_| self.challenge_scalar(label)).collect()
+    }
+
+    /// Derive a child transcript domain-separated by `label`, as in
+    /// [`TranscriptProtocol::fork`](super::super::TranscriptProtocol::fork).
+    pub fn fork(&self, label: &'static [u8]) -> Result<Self, SynthesisError> {
+        let mut child = self.clone();
+        child.append(b"fork", &UInt8::constant_vec(label))?;
+        Ok(child)
+    }
+
+    fn append_label(&mut self, label: &'static [u8]) -> Result<(), SynthesisError> {
+        match self.use_label {
+            true => self.sponge.absorb(&UInt8::constant_vec(label).as_slice()),
+            false => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use ark_crypto_primitives::sponge::{
+    poseidon::{find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge},
+    Absorb, CryptographicSponge,
+};
+use ark_ff::PrimeField;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use super::TranscriptProtocol;
+
+pub mod constraints;
+pub use constraints::PoseidonBaseVar;
+
+/// Width of the sponge is `RATE + 1`.
+pub const RATE: usize = 2;
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 57;
+pub const ALPHA: u64 = 5;
+
+/// Poseidon parameters over `F` (x^5 S-box, 8 full and 57 partial rounds for width 3),
+/// with round constants and MDS matrix generated by the Grain LFSR.
+///
+/// The S-box requires `gcd(5, |F| - 1) = 1`, which holds for the BN254 scalar field.
+/// The constants are generated once per field and cached, as every transcript needs them.
+pub fn poseidon_config<F: PrimeField>() -> PoseidonConfig<F> {
+    static CONFIGS: OnceLock<Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> = OnceLock::new();
+
+    let mut configs = CONFIGS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    configs
+        .entry(TypeId::of::<F>())
+        .or_insert_with(|| Box::new(generate_config::<F>()))
+        .downcast_ref::<PoseidonConfig<F>>()
+        .expect("configs are keyed by their field")
+        .clone()
+}
+
+fn generate_config<F: PrimeField>() -> PoseidonConfig<F> {
+    let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+        F::MODULUS_BIT_SIZE as u64,
+        RATE,
+        FULL_ROUNDS as u64,
+        PARTIAL_ROUNDS as u64,
+        0,
+    );
+    PoseidonConfig::new(FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, mds, ark, RATE, 1)
+}
+
+/// Algebraic transcript over the Poseidon sponge on `CF`.
+///
+/// Byte items are absorbed with their length, packed into field elements, and field
+/// elements can be absorbed directly with [`PoseidonBase::append_field_elements`].
+/// [`PoseidonBaseVar`] replays the same transcript inside a circuit over `CF`.
+#[derive(Clone)]
+pub struct PoseidonBase<CF: PrimeField> {
+    sponge: PoseidonSponge<CF>,
+    use_label: bool,
+}
+
+impl<CF: PrimeField> PoseidonBase<CF> {
+    /// Create a new transcript with the given Poseidon `config`.
+    pub fn with_config(config: &PoseidonConfig<CF>, use_label: bool) -> Self {
+        PoseidonBase {
+            sponge: PoseidonSponge::new(config),
+            use_label,
+        }
+    }
+
+    /// Append field elements of `CF` without converting them to bytes.
+    pub fn append_field_elements(&mut self, label: &'static [u8], items: &[CF])
+    where
+        CF: Absorb,
+    {
+        self.append_label(label);
+        self.sponge.absorb(&items);
+    }
+
+    fn append_label(&mut self, label: &'static [u8]) {
+        if self.use_label {
+            self.sponge.absorb(&label);
+        }
+    }
+}
+
+impl<CF: PrimeField> TranscriptProtocol for PoseidonBase<CF> {
+    fn new(use_label: bool) -> Self {
+        Self::with_config(&poseidon_config(), use_label)
+    }
+
+    fn append(&mut self, label: &'static [u8], item: &[u8]) {
+        self.append_label(label);
+        self.sponge.absorb(&item);
+    }
+
+    /// Native challenges (`F = CF`) are squeezed as a single sponge element,
+    /// other fields are sampled from `MODULUS_BIT_SIZE - 1` squeezed bits.
+    fn challenge_scalar<F: PrimeField>(&mut self, label: &'static [u8]) -> F {
+        self.append_label(label);
+        self.sponge.squeeze_field_elements::<F>(1)[0]
+    }
+}
@@ -27,10 +27,18 @@ impl TranscriptProtocol for SHA3Base {
         if self.use_label {
             self.bytes.extend_from_slice(label);
         }
-        let mut hasher = Keccak256::new();
-        hasher.update(&self.bytes);
-        self.bytes.clear();
-        let bytes = hasher.finalize().to_vec();
+        // expand to 64 bytes, keccak(bytes || 0) || keccak(bytes || 1),
+        // so the reduction mod r is statistically uniform
+        let seed = std::mem::take(&mut self.bytes);
+        let bytes = [0u8, 1]
+            .iter()
+            .flat_map(|i| {
+                Keccak256::new()
+                    .chain_update(&seed)
+                    .chain_update([*i])
+                    .finalize()
+            })
+            .collect::<Vec<_>>();
         let challenge = F::from_be_bytes_mod_order(&bytes);
         self.bytes.extend(challenge.into_bigint().to_bytes_be());
         challenge
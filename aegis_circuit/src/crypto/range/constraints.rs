@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar, prelude::Boolean, R1CSVar};
+use ark_relations::r1cs::SynthesisError;
+use ark_std::vec::Vec;
+
+use super::{check_bits, in_range, offset};
+
+/// Range checks over a canonical bit decomposition.
+///
+/// The value is decomposed into fresh boolean witnesses whose weighted sum must equal it.
+/// As `2^bits` stays below the modulus the sum cannot wrap around, so the decomposition is
+/// unique and a value outside the range has none.
+/// Every decomposition over `n` bits adds `n + 1` constraints.
+pub struct RangeGadget<F: PrimeField> {
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> RangeGadget<F> {
+    /// Enforce `0 <= value < 2^bits`, and return the little-endian bits of `value`.
+    pub fn enforce_range(value: &FpVar<F>, bits: usize) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        if !check_bits::<F>(bits) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        Self::decompose(value, bits)
+    }
+
+    /// Enforce `-2^bits <= value < 2^bits` by checking `value + 2^bits` over `bits + 1` bits.
+    pub fn enforce_signed_range(
+        value: &FpVar<F>,
+        bits: usize,
+    ) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        if !check_bits::<F>(bits) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        Self::decompose(&(value + offset::<F>(bits)), bits + 1)
+    }
+
+    /// Number of constraints added by [`Self::enforce_range`] over `bits` bits.
+    pub fn num_constraints(bits: usize) -> usize {
+        bits + 1
+    }
+
+    /// Number of constraints added by [`Self::enforce_signed_range`] over `bits` bits.
+    pub fn num_signed_constraints(bits: usize) -> usize {
+        Self::num_constraints(bits + 1)
+    }
+
+    /// Decompose `value` over `bits` boolean witnesses, with `2^bits` below the modulus.
+    fn decompose(value: &FpVar<F>, bits: usize) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        let cs = value.cs();
+        let decomposition = value
+            .value()
+            .map(|value| value.into_bigint().to_bits_le())
+            .ok();
+
+        // a constant is checked natively, as the equality of constants adds no constraint
+        if cs.is_none() {
+            let value = value.value()?;
+            if !in_range(&value, bits) {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+            let decomposition = decomposition.ok_or(SynthesisError::AssignmentMissing)?;
+            return Ok(decomposition[..bits]
+                .iter()
+                .map(|bit| Boolean::constant(*bit))
+                .collect());
+        }
+
+        let bit_vars = (0..bits)
+            .map(|i| {
+                Boolean::new_witness(cs.clone(), || {
+                    decomposition
+                        .as_ref()
+                        .map(|bits| bits[i])
+                        .ok_or(SynthesisError::AssignmentMissing)
+                })
+            })
+            .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+        Boolean::le_bits_to_fp_var(&bit_vars)?.enforce_equal(value)?;
+        Ok(bit_vars)
+    }
+}
@@ -0,0 +1,41 @@
+pub mod constraints;
+pub use constraints::RangeGadget;
+
+use ark_ff::{BigInteger, PrimeField};
+
+/// Largest bit width the range checks accept over `F`.
+///
+/// A signed check decomposes `value + 2^bits` over `bits + 1` bits, and the decomposition is
+/// canonical only while `2^(bits + 1)` stays below the modulus.
+pub fn max_bits<F: PrimeField>() -> usize {
+    F::MODULUS_BIT_SIZE as usize - 2
+}
+
+/// Whether `bits` is a valid width for the range checks over `F`.
+pub fn check_bits<F: PrimeField>(bits: usize) -> bool {
+    bits > 0 && bits <= max_bits::<F>()
+}
+
+/// Whether the sum of `terms` signed values over `bits` bits cannot wrap around over `F`.
+///
+/// The sum is at most `terms · 2^bits <= 2^(bits + ⌈log2 terms⌉)` in absolute value, so it keeps
+/// its sign below the modulus once `bits + ⌈log2 terms⌉ + 1 <= max_bits`.
+pub fn check_sum_bits<F: PrimeField>(bits: usize, terms: usize) -> bool {
+    let log_terms = terms.next_power_of_two().trailing_zeros() as usize;
+    check_bits::<F>(bits) && bits + log_terms < max_bits::<F>()
+}
+
+/// `0 <= value < 2^bits`.
+pub fn in_range<F: PrimeField>(value: &F, bits: usize) -> bool {
+    value.into_bigint().num_bits() as usize <= bits
+}
+
+/// `-2^bits <= value < 2^bits`, with negative values taken modulo the field.
+pub fn in_signed_range<F: PrimeField>(value: &F, bits: usize) -> bool {
+    in_range(&(*value + offset::<F>(bits)), bits + 1)
+}
+
+/// `2^bits`, the offset moving the signed range onto `[0, 2^(bits + 1))`.
+fn offset<F: PrimeField>(bits: usize) -> F {
+    F::from(2u64).pow([bits as u64])
+}
@@ -1,6 +1,9 @@
+pub mod aegis;
+pub mod bulletproofs;
 pub mod crypto;
 pub mod gro;
 pub mod linker;
+pub mod pol;
 pub mod snark;
 pub mod solidity;
 
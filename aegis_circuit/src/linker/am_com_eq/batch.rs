@@ -0,0 +1,189 @@
+use ark_ec::CurveGroup;
+use ark_ff::{UniformRand, Zero};
+use ark_std::rand::Rng;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::crypto::protocol::transcript::TranscriptProtocol;
+
+use super::{AmComEq, AmComEqError, Instance, Proof, PublicParameters};
+
+/// Random linear combination of AmComEq-shaped verification equations under the same
+/// public parameters. Every equation adds its scalars to the shared bases `poly_ck` and
+/// `coeff_ck`, and its own points are appended, so a single MSM checks them all.
+pub struct BatchVerifier<C: CurveGroup> {
+    g: Vec<C::ScalarField>,
+    h: Vec<C::ScalarField>,
+    g_hat: Vec<C::ScalarField>,
+    h_hat: Vec<C::ScalarField>,
+    points: Vec<C::Affine>,
+    scalars: Vec<C::ScalarField>,
+}
+
+impl<C: CurveGroup> BatchVerifier<C> {
+    pub fn new(pp: &PublicParameters<C>) -> Self {
+        Self {
+            g: vec![C::ScalarField::zero(); pp.poly_ck.g.len()],
+            h: vec![C::ScalarField::zero(); pp.poly_ck.h.len()],
+            g_hat: vec![C::ScalarField::zero(); pp.coeff_ck.g.len()],
+            h_hat: vec![C::ScalarField::zero(); pp.coeff_ck.h.len()],
+            points: vec![],
+            scalars: vec![],
+        }
+    }
+
+    /// Add `weight · (<poly_ck.g, z> + <poly_ck.h, omega>)`.
+    pub fn add_poly(
+        &mut self,
+        weight: C::ScalarField,
+        z: &[C::ScalarField],
+        omega: &[C::ScalarField],
+    ) {
+        cfg_iter_mut!(self.g)
+            .zip(z)
+            .for_each(|(acc, z)| *acc += weight * z);
+        cfg_iter_mut!(self.h)
+            .zip(omega)
+            .for_each(|(acc, omega)| *acc += weight * omega);
+    }
+
+    /// Add `weight · (<coeff_ck.g, Σ ρ_i · z_i> + <coeff_ck.h, omega_hat>)`,
+    /// where `z_i` is the `i`-th chunk of `z` of length `d0`.
+    pub fn add_coeff(
+        &mut self,
+        weight: C::ScalarField,
+        z: &[C::ScalarField],
+        coefficients: &[C::ScalarField],
+        omega_hat: &[C::ScalarField],
+    ) {
+        let d0 = self.g_hat.len();
+        cfg_iter_mut!(self.g_hat).enumerate().for_each(|(j, acc)| {
+            *acc += weight
+                * coefficients
+                    .iter()
+                    .enumerate()
+                    .map(|(i, rho)| z[d0 * i + j] * rho)
+                    .sum::<C::ScalarField>()
+        });
+        cfg_iter_mut!(self.h_hat)
+            .zip(omega_hat)
+            .for_each(|(acc, omega_hat)| *acc += weight * omega_hat);
+    }
+
+    /// Add `scalar · point`.
+    pub fn add_point(&mut self, point: C::Affine, scalar: C::ScalarField) {
+        self.points.push(point);
+        self.scalars.push(scalar);
+    }
+
+    /// Check that the combination is the identity with one MSM.
+    pub fn verify(self, pp: &PublicParameters<C>) -> bool {
+        let bases = [
+            &pp.poly_ck.g[..],
+            &pp.poly_ck.h,
+            &pp.coeff_ck.g,
+            &pp.coeff_ck.h,
+            &self.points,
+        ]
+        .concat();
+        let scalars = [self.g, self.h, self.g_hat, self.h_hat, self.scalars].concat();
+        C::msm_unchecked(&bases, &scalars).is_zero()
+    }
+}
+
+impl<C: CurveGroup> AmComEq<C> {
+    /// Verify many proofs under the same public parameters with a random linear combination
+    /// of their equations, so the committing keys enter a single MSM.
+    /// Returns the indices of the rejected proofs, empty if every proof is accepted. A malformed
+    /// instance or proof rejects its own index only.
+    pub fn batch_verify<T: TranscriptProtocol>(
+        pp: &PublicParameters<C>,
+        batch: &mut [(&Instance<C>, &Proof<C>, T)],
+        rng: &mut impl Rng,
+    ) -> Result<Vec<usize>, AmComEqError> {
+        let batch_timer = start_timer!(|| format!("AmComEq::BatchVerifier ({})", batch.len()));
+
+        let mut rejected = vec![];
+        let mut accepted = vec![];
+        for (index, (instance, proof, transcript)) in batch.iter_mut().enumerate() {
+            if Self::check_instance(pp, instance).is_err() || Self::check_proof(pp, proof).is_err()
+            {
+                rejected.push(index);
+                continue;
+            }
+            let Ok(coefficients) = Self::compute_coefficients(instance, transcript) else {
+                rejected.push(index);
+                continue;
+            };
+            let Ok(challenge) = Self::compute_e(&proof.commitment, transcript) else {
+                rejected.push(index);
+                continue;
+            };
+            accepted.push((index, coefficients, challenge));
+        }
+
+        let mut verifier = BatchVerifier::new(pp);
+        for (index, coefficients, challenge) in accepted.iter() {
+            let (instance, proof, _) = &batch[*index];
+            Self::accumulate(
+                &mut verifier,
+                instance,
+                proof,
+                coefficients,
+                *challenge,
+                rng,
+            );
+        }
+
+        // identify the offending proofs one by one
+        if !verifier.verify(pp) {
+            for (index, coefficients, challenge) in accepted.iter() {
+                let (instance, proof, _) = &batch[*index];
+                let mut verifier = BatchVerifier::new(pp);
+                Self::accumulate(
+                    &mut verifier,
+                    instance,
+                    proof,
+                    coefficients,
+                    *challenge,
+                    rng,
+                );
+                if !verifier.verify(pp) {
+                    rejected.push(*index);
+                }
+            }
+            rejected.sort_unstable();
+        }
+
+        end_timer!(batch_timer);
+        Ok(rejected)
+    }
+
+    /// Add both equations of a proof with independent random weights.
+    fn accumulate(
+        verifier: &mut BatchVerifier<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        coefficients: &[C::ScalarField],
+        challenge: C::ScalarField,
+        rng: &mut impl Rng,
+    ) {
+        let alpha = C::ScalarField::rand(rng);
+        let beta = C::ScalarField::rand(rng);
+
+        // <g, z> + <h, omega> = a + e · c
+        verifier.add_poly(alpha, &proof.z, &proof.omega);
+        verifier.add_point(proof.commitment.a, -alpha);
+        verifier.add_point(instance.c, -alpha * challenge);
+
+        // <g_hat, Σ ρ_i · z_i> + <h_hat, omega_hat> = a_hat + e · Σ ρ_i · c_hat_i
+        verifier.add_coeff(beta, &proof.z, coefficients, &proof.omega_hat);
+        verifier.add_point(proof.commitment.a_hat, -beta);
+        instance
+            .c_hat
+            .iter()
+            .zip(coefficients)
+            .for_each(|(c_hat, rho)| verifier.add_point(*c_hat, -beta * challenge * rho));
+    }
+}
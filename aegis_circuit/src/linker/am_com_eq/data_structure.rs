@@ -22,6 +22,9 @@ where
 pub struct PublicParameters<C: CurveGroup> {
     pub poly_ck: CommittingKey<C>,
     pub coeff_ck: CommittingKey<C>,
+    /// Fold threshold of the CompDLEq step of
+    /// [`CompAmComEq`](crate::linker::comp_am_com_eq::CompAmComEq), unused by [`AmComEq`](super::AmComEq)
+    pub threshold: usize,
 }
 
 impl<C: CurveGroup> Solidity for PublicParameters<C>
@@ -52,6 +55,15 @@ pub struct Instance<C: CurveGroup> {
     pub c_hat: Vec<C::Affine>,
 }
 
+impl<C: CurveGroup> Solidity for Instance<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [self.c.to_solidity(), self.c_hat.to_solidity()].concat()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Witness<C: CurveGroup> {
     pub w: Vec<Vec<C::ScalarField>>,
@@ -1,5 +1,9 @@
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub enum AmComEqError {
+    /// The committing keys, instance and witness do not agree on `l`, `d0`, `d1` or `d2`.
     MismatchParameterLength,
-    NonPowerOfTwo,
+    /// A point appended to the transcript is the identity.
+    IdentityPoint,
+    /// The proof is malformed.
+    InvalidProof,
 }
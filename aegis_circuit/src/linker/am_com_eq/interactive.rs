@@ -0,0 +1,225 @@
+use ark_ec::CurveGroup;
+use ark_ff::UniformRand;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::{CryptoRng, RngCore};
+
+use crate::crypto::protocol::interactive::{
+    decode, encode, InteractiveError, InteractiveProver, InteractiveVerifier, Move,
+};
+
+use super::{
+    AmComEq, AmComEqError, Commitment, Instance, Proof, PublicParameters, Randomness, Witness,
+};
+
+/// Last prover message of interactive AmComEq.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Response<C: CurveGroup> {
+    pub z: Vec<C::ScalarField>,
+    pub omega: Vec<C::ScalarField>,
+    pub omega_hat: Vec<C::ScalarField>,
+}
+
+enum ProverRound<C: CurveGroup> {
+    Coefficients,
+    Challenge {
+        coefficients: Vec<C::ScalarField>,
+        randomness: Randomness<C>,
+        commitment: Commitment<C>,
+    },
+    Finished,
+}
+
+/// Interactive AmComEq prover: it receives the coefficients `ρ_i`, sends the [`Commitment`],
+/// receives the challenge `e` and sends the [`Response`].
+pub struct Prover<'a, C: CurveGroup> {
+    pp: &'a PublicParameters<C>,
+    instance: &'a Instance<C>,
+    witness: &'a Witness<C>,
+    round: ProverRound<C>,
+}
+
+impl<'a, C: CurveGroup> Prover<'a, C> {
+    pub fn new(
+        pp: &'a PublicParameters<C>,
+        instance: &'a Instance<C>,
+        witness: &'a Witness<C>,
+    ) -> Result<Self, AmComEqError> {
+        AmComEq::check_instance(pp, instance)?;
+        AmComEq::check_witness(pp, instance, witness)?;
+        Ok(Self {
+            pp,
+            instance,
+            witness,
+            round: ProverRound::Coefficients,
+        })
+    }
+
+    /// Copy of the prover in its current round, so that tests can rewind it to answer a second
+    /// challenge on the same commitment.
+    #[cfg(test)]
+    pub(crate) fn snapshot(&self) -> Self {
+        let round = match &self.round {
+            ProverRound::Coefficients => ProverRound::Coefficients,
+            ProverRound::Challenge {
+                coefficients,
+                randomness,
+                commitment,
+            } => ProverRound::Challenge {
+                coefficients: coefficients.clone(),
+                randomness: randomness.clone(),
+                commitment: commitment.clone(),
+            },
+            ProverRound::Finished => ProverRound::Finished,
+        };
+        Self { round, ..*self }
+    }
+}
+
+impl<'a, C: CurveGroup> InteractiveProver for Prover<'a, C> {
+    type Error = AmComEqError;
+
+    fn next_message<R: RngCore + CryptoRng>(
+        &mut self,
+        challenge: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>, InteractiveError<Self::Error>> {
+        match &self.round {
+            ProverRound::Coefficients => {
+                let coefficients = decode::<Vec<C::ScalarField>>(challenge)
+                    .filter(|rho| rho.len() == self.instance.c_hat.len())
+                    .ok_or(InteractiveError::MalformedChallenge)?;
+                let (randomness, commitment) =
+                    AmComEq::create_random_commitment(self.pp, &coefficients, rng)
+                        .map_err(InteractiveError::Protocol)?;
+                let message = encode(&commitment);
+                self.round = ProverRound::Challenge {
+                    coefficients,
+                    randomness,
+                    commitment,
+                };
+                Ok(message)
+            }
+            ProverRound::Challenge {
+                coefficients,
+                randomness,
+                commitment,
+            } => {
+                let challenge = decode::<C::ScalarField>(challenge)
+                    .ok_or(InteractiveError::MalformedChallenge)?;
+                let proof = AmComEq::create_proof_with_assignment(
+                    self.pp,
+                    self.witness,
+                    randomness,
+                    commitment,
+                    coefficients,
+                    challenge,
+                )
+                .map_err(InteractiveError::Protocol)?;
+                self.round = ProverRound::Finished;
+                Ok(encode(&Response::<C> {
+                    z: proof.z,
+                    omega: proof.omega,
+                    omega_hat: proof.omega_hat,
+                }))
+            }
+            ProverRound::Finished => Err(InteractiveError::OutOfOrder),
+        }
+    }
+}
+
+enum VerifierRound<C: CurveGroup> {
+    Start,
+    Commitment {
+        coefficients: Vec<C::ScalarField>,
+    },
+    Response {
+        coefficients: Vec<C::ScalarField>,
+        commitment: Commitment<C>,
+        challenge: C::ScalarField,
+    },
+    Finished,
+}
+
+/// Interactive AmComEq verifier, sampling the coefficients `ρ_i` and the challenge `e`.
+pub struct Verifier<'a, C: CurveGroup> {
+    pp: &'a PublicParameters<C>,
+    instance: &'a Instance<C>,
+    round: VerifierRound<C>,
+}
+
+impl<'a, C: CurveGroup> Verifier<'a, C> {
+    pub fn new(
+        pp: &'a PublicParameters<C>,
+        instance: &'a Instance<C>,
+    ) -> Result<Self, AmComEqError> {
+        AmComEq::check_instance(pp, instance)?;
+        Ok(Self {
+            pp,
+            instance,
+            round: VerifierRound::Start,
+        })
+    }
+}
+
+impl<'a, C: CurveGroup> InteractiveVerifier for Verifier<'a, C> {
+    type Error = AmComEqError;
+
+    fn next_move<R: RngCore + CryptoRng>(
+        &mut self,
+        message: Option<&[u8]>,
+        rng: &mut R,
+    ) -> Result<Move, InteractiveError<Self::Error>> {
+        let round = std::mem::replace(&mut self.round, VerifierRound::Finished);
+        match (round, message) {
+            (VerifierRound::Start, None) => {
+                let coefficients = (0..self.instance.c_hat.len())
+                    .map(|_| C::ScalarField::rand(rng))
+                    .collect::<Vec<_>>();
+                let challenge = encode(&coefficients);
+                self.round = VerifierRound::Commitment { coefficients };
+                Ok(Move::Challenge(challenge))
+            }
+            (VerifierRound::Commitment { coefficients }, Some(message)) => {
+                let Some(commitment) = decode::<Commitment<C>>(message) else {
+                    return Ok(Move::Decision(false));
+                };
+                let challenge = C::ScalarField::rand(rng);
+                self.round = VerifierRound::Response {
+                    coefficients,
+                    commitment,
+                    challenge,
+                };
+                Ok(Move::Challenge(encode(&challenge)))
+            }
+            (
+                VerifierRound::Response {
+                    coefficients,
+                    commitment,
+                    challenge,
+                },
+                Some(message),
+            ) => {
+                let Some(response) = decode::<Response<C>>(message) else {
+                    return Ok(Move::Decision(false));
+                };
+                let proof = Proof {
+                    commitment,
+                    z: response.z,
+                    omega: response.omega,
+                    omega_hat: response.omega_hat,
+                };
+                Ok(Move::Decision(
+                    AmComEq::check_proof(self.pp, &proof).is_ok()
+                        && AmComEq::check_responses(
+                            self.pp,
+                            self.instance,
+                            &proof,
+                            &coefficients,
+                            challenge,
+                        ),
+                ))
+            }
+            _ => Err(InteractiveError::OutOfOrder),
+        }
+    }
+}
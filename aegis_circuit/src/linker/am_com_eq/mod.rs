@@ -1,14 +1,25 @@
 use std::marker::PhantomData;
 
-use ark_ec::CurveGroup;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::UniformRand;
 use ark_std::rand::{CryptoRng, RngCore};
 
 pub mod data_structure;
-use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
+use crate::{
+    crypto::protocol::{
+        sigma::{ComposableSigma, SigmaProtocol},
+        transcript::TranscriptProtocol,
+    },
+    linker::points_to_bytes,
+};
 
 pub use self::data_structure::*;
 
+mod batch;
+pub use batch::BatchVerifier;
 mod errors;
+pub use errors::AmComEqError;
+pub mod interactive;
 mod prover;
 mod verifier;
 
@@ -21,9 +32,9 @@ impl<C: CurveGroup> SigmaProtocol for AmComEq<C> {
     type Instance = Instance<C>;
     type Witness = Witness<C>;
     type Proof = Proof<C>;
-    type Error = ();
+    type Error = AmComEqError;
 
-    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, ()> {
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
         Ok(pp.clone())
     }
 
@@ -33,7 +44,7 @@ impl<C: CurveGroup> SigmaProtocol for AmComEq<C> {
         witness: &Self::Witness,
         transcript: &mut T,
         rng: &mut R,
-    ) -> Result<Self::Proof, ()> {
+    ) -> Result<Self::Proof, Self::Error> {
         Self::create_proof(pp, instance, witness, transcript, rng)
     }
 
@@ -42,7 +53,112 @@ impl<C: CurveGroup> SigmaProtocol for AmComEq<C> {
         instance: &Self::Instance,
         proof: &Self::Proof,
         transcript: &mut T,
-    ) -> Result<bool, ()> {
+    ) -> Result<bool, Self::Error> {
         Self::verify_proof(pp, instance, proof, transcript)
     }
 }
+
+impl<C: CurveGroup> ComposableSigma for AmComEq<C> {
+    type Challenge = C::ScalarField;
+    /// The instance with its coefficients `ρ_i`
+    type Context = (Instance<C>, Vec<C::ScalarField>);
+    type Commitment = Commitment<C>;
+    type Response = interactive::Response<C>;
+    type State = Randomness<C>;
+
+    fn append_instance<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        transcript: &mut T,
+    ) -> Result<Self::Context, Self::Error> {
+        Self::check_instance(pp, instance)?;
+        let coefficients = Self::compute_coefficients(instance, transcript)?;
+        Ok((instance.clone(), coefficients))
+    }
+
+    fn commit<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> Result<(Self::State, Self::Commitment), Self::Error> {
+        Self::check_witness(pp, &context.0, witness)?;
+        Self::create_random_commitment(pp, &context.1, rng)
+    }
+
+    fn append_commitment<T: TranscriptProtocol>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), Self::Error> {
+        let bytes = points_to_bytes(&[commitment.a, commitment.a_hat])
+            .ok_or(AmComEqError::IdentityPoint)?;
+        transcript.append(b"commitment", &bytes);
+        Ok(())
+    }
+
+    fn respond(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: Self::Challenge,
+    ) -> Result<Self::Response, Self::Error> {
+        Ok(Self::compute_response(
+            pp, witness, &state, &context.1, challenge,
+        ))
+    }
+
+    fn check(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        commitment: &Self::Commitment,
+        challenge: Self::Challenge,
+        response: &Self::Response,
+    ) -> Result<bool, Self::Error> {
+        let proof = Proof {
+            commitment: commitment.clone(),
+            z: response.z.clone(),
+            omega: response.omega.clone(),
+            omega_hat: response.omega_hat.clone(),
+        };
+        Ok(Self::check_proof(pp, &proof).is_ok()
+            && Self::check_responses(pp, &context.0, &proof, &context.1, challenge))
+    }
+
+    /// `a = <g, z> + <h, ω> - e · c` and `a_hat = <coeff_g, Σ ρ_i · z_i> + <coeff_h, Ω> -
+    /// e · Σ ρ_i · c_hat_i` for random responses.
+    fn simulate<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        challenge: Self::Challenge,
+        rng: &mut R,
+    ) -> Result<(Self::Commitment, Self::Response), Self::Error> {
+        let mut sample = |n: usize| {
+            (0..n)
+                .map(|_| C::ScalarField::rand(rng))
+                .collect::<Vec<_>>()
+        };
+        let response = interactive::Response {
+            z: sample(pp.poly_ck.g.len()),
+            omega: sample(pp.poly_ck.h.len()),
+            omega_hat: sample(pp.coeff_ck.h.len()),
+        };
+
+        let (s_real, m_real) = Self::compute_real(
+            pp,
+            &response.z,
+            &response.omega,
+            &response.omega_hat,
+            &context.1,
+        );
+        let (s_statement, m_statement) = Self::compute_statement(&context.0, &context.1, challenge);
+        let commitment = Commitment::<C> {
+            a: (s_real - s_statement).into_affine(),
+            a_hat: (m_real - m_statement).into_affine(),
+        };
+        if commitment.a.is_zero() || commitment.a_hat.is_zero() {
+            return Err(AmComEqError::IdentityPoint);
+        }
+        Ok((commitment, response))
+    }
+}
@@ -7,16 +7,19 @@ use rayon::prelude::*;
 
 use crate::crypto::protocol::transcript::TranscriptProtocol;
 
-use super::{AmComEq, Commitment, Instance, Proof, PublicParameters, Randomness, Witness};
+use super::{
+    interactive::Response, AmComEq, AmComEqError, Commitment, Instance, Proof, PublicParameters,
+    Randomness, Witness,
+};
 
 impl<C: CurveGroup> AmComEq<C> {
     /// Generate a random and compute the commitment
     /// Returns the randomness and the commitment
     pub fn create_random_commitment(
         pp: &PublicParameters<C>,
-        powers_of_x: &[C::ScalarField],
+        coefficients: &[C::ScalarField],
         rng: &mut impl Rng,
-    ) -> Result<(Randomness<C>, Commitment<C>), ()> {
+    ) -> Result<(Randomness<C>, Commitment<C>), AmComEqError> {
         let commit_timer = start_timer!(|| "AmComEq::Commit");
         let ld = pp.poly_ck.g.len();
         let d1 = pp.poly_ck.h.len();
@@ -31,7 +34,7 @@ impl<C: CurveGroup> AmComEq<C> {
 
         end_timer!(random_timer);
 
-        let commitment = Self::create_commitment_from_random(pp, powers_of_x, &random)?;
+        let commitment = Self::create_commitment_from_random(pp, coefficients, &random)?;
         end_timer!(commit_timer);
 
         Ok((random, commitment))
@@ -40,9 +43,9 @@ impl<C: CurveGroup> AmComEq<C> {
     /// Compute the commitment from the randomness
     pub fn create_commitment_from_random(
         pp: &PublicParameters<C>,
-        powers_of_x: &[C::ScalarField],
+        coefficients: &[C::ScalarField],
         random: &Randomness<C>,
-    ) -> Result<Commitment<C>, ()> {
+    ) -> Result<Commitment<C>, AmComEqError> {
         let a_timer = start_timer!(|| "Compute A");
         let r = cfg_iter!(random.r)
             .map(|s| s.into_bigint())
@@ -58,7 +61,7 @@ impl<C: CurveGroup> AmComEq<C> {
 
         let a_hat_timer = start_timer!(|| "Compute A hat");
         let d0 = pp.coeff_ck.g.len();
-        let l = powers_of_x.len();
+        let l = coefficients.len();
 
         let d0_indicies = (0..d0).collect::<Vec<_>>();
         let l_indicies = (0..l).collect::<Vec<_>>();
@@ -66,7 +69,7 @@ impl<C: CurveGroup> AmComEq<C> {
         let aggregated_r = cfg_iter!(d0_indicies)
             .map(|&j| {
                 cfg_iter!(l_indicies)
-                    .map(|&i| random.r[d0 * i + j] * powers_of_x[i])
+                    .map(|&i| random.r[d0 * i + j] * coefficients[i])
                     .sum::<C::ScalarField>()
                     .into_bigint()
             })
@@ -90,9 +93,26 @@ impl<C: CurveGroup> AmComEq<C> {
         witness: &Witness<C>,
         randomness: &Randomness<C>,
         commitment: &Commitment<C>,
-        powers_of_x: &[C::ScalarField],
+        coefficients: &[C::ScalarField],
         challenge: C::ScalarField,
-    ) -> Result<Proof<C>, ()> {
+    ) -> Result<Proof<C>, AmComEqError> {
+        let response = Self::compute_response(pp, witness, randomness, coefficients, challenge);
+        Ok(Proof {
+            commitment: commitment.clone(),
+            z: response.z,
+            omega: response.omega,
+            omega_hat: response.omega_hat,
+        })
+    }
+
+    /// Compute the responses `z = r + e · w`, `ω = δ + e · α` and `Ω = γ + e · Σ ρ_i · β_i`.
+    pub fn compute_response(
+        pp: &PublicParameters<C>,
+        witness: &Witness<C>,
+        randomness: &Randomness<C>,
+        coefficients: &[C::ScalarField],
+        challenge: C::ScalarField,
+    ) -> Response<C> {
         let z_timer = start_timer!(|| "Compute Z");
         let z = cfg_iter!(randomness.r)
             .zip(witness.w.concat())
@@ -113,7 +133,7 @@ impl<C: CurveGroup> AmComEq<C> {
         let aggregated_beta = cfg_iter!(d2_indicies)
             .map(|&j| {
                 cfg_iter!(witness.beta)
-                    .zip(powers_of_x)
+                    .zip(coefficients)
                     .map(|(beta, x)| beta[j] * x)
                     .sum::<C::ScalarField>()
             })
@@ -126,12 +146,34 @@ impl<C: CurveGroup> AmComEq<C> {
 
         end_timer!(omega_hat_timer);
 
-        Ok(Proof {
-            commitment: commitment.clone(),
+        Response {
             z,
             omega,
             omega_hat,
-        })
+        }
+    }
+
+    /// Check that the witness matches the dimensions of the public parameters and instance.
+    pub fn check_witness(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+    ) -> Result<(), AmComEqError> {
+        let (l, d0, d1, d2) = (
+            instance.c_hat.len(),
+            pp.coeff_ck.g.len(),
+            pp.poly_ck.h.len(),
+            pp.coeff_ck.h.len(),
+        );
+        if witness.w.len() != l
+            || witness.beta.len() != l
+            || witness.alpha.len() != d1
+            || witness.w.iter().any(|w_i| w_i.len() != d0)
+            || witness.beta.iter().any(|beta_i| beta_i.len() != d2)
+        {
+            return Err(AmComEqError::MismatchParameterLength);
+        }
+        Ok(())
     }
 
     /// Create a proof
@@ -141,19 +183,22 @@ impl<C: CurveGroup> AmComEq<C> {
         witness: &Witness<C>,
         transcript: &mut impl TranscriptProtocol,
         rng: &mut impl Rng,
-    ) -> Result<Proof<C>, ()> {
+    ) -> Result<Proof<C>, AmComEqError> {
+        Self::check_instance(pp, instance)?;
+        Self::check_witness(pp, instance, witness)?;
+
         let proof_timer = start_timer!(|| "AmComEq::Prover");
 
-        let powers_of_x = Self::compute_powers_of_x(instance, transcript);
-        let (randomness, commitment) = Self::create_random_commitment(pp, &powers_of_x, rng)?;
-        let challenge = Self::compute_e(&commitment, transcript);
+        let coefficients = Self::compute_coefficients(instance, transcript)?;
+        let (randomness, commitment) = Self::create_random_commitment(pp, &coefficients, rng)?;
+        let challenge = Self::compute_e(&commitment, transcript)?;
 
         let proof = Self::create_proof_with_assignment(
             pp,
             witness,
             &randomness,
             &commitment,
-            &powers_of_x,
+            &coefficients,
             challenge,
         )?;
 
@@ -1,14 +1,15 @@
-use ark_ec::{AffineRepr, CurveGroup};
+use ark_ec::CurveGroup;
 use ark_ff::PrimeField;
-use ark_serialize::CanonicalSerialize;
-use ark_std::One;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use crate::crypto::protocol::transcript::TranscriptProtocol;
+use crate::{
+    crypto::protocol::{sigma::ComposableSigma, transcript::TranscriptProtocol},
+    linker::points_to_bytes,
+};
 
-use super::{AmComEq, Commitment, Instance, Proof, PublicParameters};
+use super::{AmComEq, AmComEqError, Commitment, Instance, Proof, PublicParameters};
 
 impl<C: CurveGroup> AmComEq<C> {
     pub fn verify_proof(
@@ -16,31 +17,66 @@ impl<C: CurveGroup> AmComEq<C> {
         instance: &Instance<C>,
         proof: &Proof<C>,
         transcript: &mut impl TranscriptProtocol,
-    ) -> Result<bool, ()> {
+    ) -> Result<bool, AmComEqError> {
+        Self::check_instance(pp, instance)?;
+        if Self::check_proof(pp, proof).is_err() {
+            return Ok(false);
+        }
+
         let verifier_timer = start_timer!(|| "AmComEq::Verifier");
 
-        let powers_of_x = Self::compute_powers_of_x(instance, transcript);
+        let coefficients = Self::compute_coefficients(instance, transcript)?;
+
+        let Ok(challenge) = Self::compute_e(&proof.commitment, transcript) else {
+            return Ok(false);
+        };
 
-        let challenge = Self::compute_e(&proof.commitment, transcript);
+        let result = Self::check_responses(pp, instance, proof, &coefficients, challenge);
+
+        end_timer!(verifier_timer);
 
+        Ok(result)
+    }
+
+    /// Check both equations of a well-formed proof under the given coefficients and challenge.
+    pub fn check_responses(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        coefficients: &[C::ScalarField],
+        challenge: C::ScalarField,
+    ) -> bool {
+        let (s_real, m_real) =
+            Self::compute_real(pp, &proof.z, &proof.omega, &proof.omega_hat, coefficients);
+        let (s_statement, m_statement) = Self::compute_statement(instance, coefficients, challenge);
+
+        s_real == s_statement + proof.commitment.a && m_real == m_statement + proof.commitment.a_hat
+    }
+
+    /// Left-hand sides of both equations, `<g, z> + <h, ω>` and
+    /// `<coeff_g, Σ ρ_i · z_i> + <coeff_h, Ω>`.
+    pub fn compute_real(
+        pp: &PublicParameters<C>,
+        z: &[C::ScalarField],
+        omega: &[C::ScalarField],
+        omega_hat: &[C::ScalarField],
+        coefficients: &[C::ScalarField],
+    ) -> (C, C) {
         let single_timer = start_timer!(|| "Single Commitment");
-        let z = cfg_iter!(proof.z)
-            .map(|s| s.into_bigint())
-            .collect::<Vec<_>>();
-        let omega = cfg_iter!(proof.omega)
+        let z_bigint = cfg_iter!(z).map(|s| s.into_bigint()).collect::<Vec<_>>();
+        let omega = cfg_iter!(omega)
             .map(|s| s.into_bigint())
             .collect::<Vec<_>>();
         let s_real =
-            C::msm_bigint(&pp.poly_ck.g, &z[..]) + C::msm_bigint(&pp.poly_ck.h, &omega[..]);
+            C::msm_bigint(&pp.poly_ck.g, &z_bigint[..]) + C::msm_bigint(&pp.poly_ck.h, &omega[..]);
+        drop(z_bigint);
         drop(omega);
-
-        let s_expected = proof.commitment.a + instance.c * challenge;
         end_timer!(single_timer);
 
         let multiple_timer = start_timer!(|| "Multiple Commitment");
 
         let d0 = pp.coeff_ck.g.len();
-        let l = powers_of_x.len();
+        let l = coefficients.len();
 
         let d0_indicies = (0..d0).collect::<Vec<_>>();
         let l_indicies = (0..l).collect::<Vec<_>>();
@@ -48,88 +84,80 @@ impl<C: CurveGroup> AmComEq<C> {
         let aggregated_z = cfg_iter!(d0_indicies)
             .map(|&j| {
                 cfg_iter!(l_indicies)
-                    .map(|&i| proof.z[d0 * i + j] * powers_of_x[i])
+                    .map(|&i| z[d0 * i + j] * coefficients[i])
                     .sum::<C::ScalarField>()
                     .into_bigint()
             })
             .collect::<Vec<_>>();
-        drop(z);
 
-        let omega_hat = cfg_iter!(proof.omega_hat)
+        let omega_hat = cfg_iter!(omega_hat)
             .map(|s| s.into_bigint())
             .collect::<Vec<_>>();
         let m_real = C::msm_bigint(&pp.coeff_ck.g, &aggregated_z)
             + C::msm_bigint(&pp.coeff_ck.h, &omega_hat[..]);
 
-        drop(aggregated_z);
-        drop(omega_hat);
+        end_timer!(multiple_timer);
 
-        let powers_of_x = cfg_iter!(powers_of_x)
+        (s_real, m_real)
+    }
+
+    /// Right-hand sides of both equations without the commitment, `e · c` and
+    /// `e · Σ ρ_i · c_hat_i`.
+    pub fn compute_statement(
+        instance: &Instance<C>,
+        coefficients: &[C::ScalarField],
+        challenge: C::ScalarField,
+    ) -> (C, C) {
+        let coefficients = cfg_iter!(coefficients)
             .map(|s| s.into_bigint())
             .collect::<Vec<_>>();
-        let m_expected =
-            proof.commitment.a_hat + C::msm_bigint(&instance.c_hat, &powers_of_x[..]) * challenge;
-
-        end_timer!(multiple_timer);
+        (
+            instance.c * challenge,
+            C::msm_bigint(&instance.c_hat, &coefficients[..]) * challenge,
+        )
+    }
 
-        end_timer!(verifier_timer);
+    /// Check that the instance matches the dimensions of the public parameters,
+    /// i.e. `|g| = l · d0` for `l` commitments `c_hat`.
+    pub fn check_instance(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+    ) -> Result<(), AmComEqError> {
+        if pp.poly_ck.g.len() != instance.c_hat.len() * pp.coeff_ck.g.len() {
+            return Err(AmComEqError::MismatchParameterLength);
+        }
+        Ok(())
+    }
 
-        assert_eq!(
-            s_real.into_affine(),
-            s_expected.into_affine(),
-            "single commit different"
-        );
-        assert_eq!(
-            m_real.into_affine(),
-            m_expected.into_affine(),
-            "multiple commit different"
-        );
-
-        Ok(s_real.into_affine() == s_expected.into() && m_real.into_affine() == m_expected.into())
+    /// Check that the responses of the proof match the dimensions of the public parameters.
+    pub fn check_proof(pp: &PublicParameters<C>, proof: &Proof<C>) -> Result<(), AmComEqError> {
+        if proof.z.len() != pp.poly_ck.g.len()
+            || proof.omega.len() != pp.poly_ck.h.len()
+            || proof.omega_hat.len() != pp.coeff_ck.h.len()
+        {
+            return Err(AmComEqError::InvalidProof);
+        }
+        Ok(())
     }
 
-    pub fn compute_powers_of_x<T: TranscriptProtocol>(
+    /// Sample independent coefficients for the random linear combination of `c_hat`.
+    /// Compared to powers of a single challenge, the soundness error drops from `l / |F|` to `1 / |F|`.
+    pub fn compute_coefficients<T: TranscriptProtocol>(
         instance: &Instance<C>,
         transcript: &mut T,
-    ) -> Vec<C::ScalarField> {
+    ) -> Result<Vec<C::ScalarField>, AmComEqError> {
         let l = instance.c_hat.len();
         let instance = vec![&[instance.c][..], &instance.c_hat].concat();
-        let bytes = cfg_iter!(instance)
-            .map(|p| {
-                let mut _bytes = vec![];
-                let (x, y) = p.xy().unwrap();
-                y.serialize_uncompressed(&mut _bytes).unwrap();
-                x.serialize_uncompressed(&mut _bytes).unwrap();
-                _bytes.reverse();
-                _bytes
-            })
-            .flatten()
-            .collect::<Vec<_>>();
+        let bytes = points_to_bytes(&instance).ok_or(AmComEqError::IdentityPoint)?;
         transcript.append(b"instance", &bytes);
-        let x = transcript.challenge_scalar::<C::ScalarField>(b"challenge");
-
-        let mut powers_of_x = vec![];
-        let mut curr = C::ScalarField::one();
-        for _ in 0..l {
-            powers_of_x.push(curr);
-            curr *= x;
-        }
-        powers_of_x
+        Ok(transcript.challenge_scalars::<C::ScalarField>(b"challenge", l))
     }
 
     pub fn compute_e<T: TranscriptProtocol>(
         commitment: &Commitment<C>,
         transcript: &mut T,
-    ) -> C::ScalarField {
-        let mut bytes = vec![];
-        let (x, y) = commitment.a.xy().unwrap();
-        let (x_hat, y_hat) = commitment.a_hat.xy().unwrap();
-        y_hat.serialize_uncompressed(&mut bytes).unwrap();
-        x_hat.serialize_uncompressed(&mut bytes).unwrap();
-        y.serialize_uncompressed(&mut bytes).unwrap();
-        x.serialize_uncompressed(&mut bytes).unwrap();
-        bytes.reverse();
-        transcript.append(b"commitment", &bytes);
-        transcript.challenge_scalar::<C::ScalarField>(b"challenge")
+    ) -> Result<C::ScalarField, AmComEqError> {
+        <Self as ComposableSigma>::append_commitment(commitment, transcript)?;
+        Ok(transcript.challenge_scalar::<C::ScalarField>(b"challenge"))
     }
 }
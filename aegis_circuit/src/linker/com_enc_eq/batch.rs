@@ -0,0 +1,109 @@
+use ark_ec::CurveGroup;
+use ark_ff::{UniformRand, Zero};
+use ark_std::rand::Rng;
+
+use crate::crypto::protocol::transcript::TranscriptProtocol;
+
+use super::{ComEncEq, ComEncEqError, Instance, Proof, PublicParameters};
+
+/// Index of a proof in the batch, with its coefficients and challenge.
+type Entry<F> = (usize, Vec<F>, F);
+
+impl<C: CurveGroup> ComEncEq<C> {
+    /// Verify many proofs under the same public parameters with a random linear combination
+    /// of their equations, so `g`, `h`, `pk` and `G` enter a single MSM with every instance point.
+    /// Returns the indices of the rejected proofs, empty if every proof is accepted. A malformed
+    /// instance rejects its own index only.
+    pub fn batch_verify<T: TranscriptProtocol>(
+        pp: &PublicParameters<C>,
+        batch: &mut [(&Instance<C>, &Proof<C>, T)],
+        rng: &mut impl Rng,
+    ) -> Result<Vec<usize>, ComEncEqError> {
+        let batch_timer = start_timer!(|| format!("ComEncEq::BatchVerifier ({})", batch.len()));
+
+        let mut rejected = vec![];
+        let mut accepted = vec![];
+        for (index, (instance, proof, transcript)) in batch.iter_mut().enumerate() {
+            if Self::check_instance(instance).is_err() {
+                rejected.push(index);
+                continue;
+            }
+            let Ok(coefficients) = Self::compute_coefficients(instance, transcript) else {
+                rejected.push(index);
+                continue;
+            };
+            let Ok(challenge) = Self::compute_e(&proof.commitment, transcript) else {
+                rejected.push(index);
+                continue;
+            };
+            accepted.push((index, coefficients, challenge));
+        }
+
+        let check = |entries: &[Entry<C::ScalarField>], rng: &mut _| {
+            let mut bases = vec![pp.g, pp.h, pp.pk, C::generator().into_affine()];
+            let mut scalars = vec![C::ScalarField::zero(); 4];
+            for (index, coefficients, challenge) in entries {
+                let (instance, proof, _) = &batch[*index];
+                Self::accumulate(
+                    &mut bases,
+                    &mut scalars,
+                    instance,
+                    proof,
+                    coefficients,
+                    *challenge,
+                    rng,
+                );
+            }
+            C::msm_unchecked(&bases, &scalars).is_zero()
+        };
+
+        // identify the offending proofs one by one
+        if !check(&accepted, rng) {
+            for entry in accepted.iter() {
+                if !check(std::slice::from_ref(entry), rng) {
+                    rejected.push(entry.0);
+                }
+            }
+            rejected.sort_unstable();
+        }
+
+        end_timer!(batch_timer);
+        Ok(rejected)
+    }
+
+    /// Add the three equations of a proof with independent random weights, the scalars of
+    /// `g`, `h`, `pk` and `G` to the first four entries and the proof and instance points after.
+    fn accumulate(
+        bases: &mut Vec<C::Affine>,
+        scalars: &mut Vec<C::ScalarField>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        coefficients: &[C::ScalarField],
+        challenge: C::ScalarField,
+        rng: &mut impl Rng,
+    ) {
+        let [alpha, beta, gamma] = [(); 3].map(|_| C::ScalarField::rand(rng));
+
+        // z_m · g + z_r · h = a + e · Σ ρ_i · c_i
+        // z_k · G = a1 + e · Σ ρ_i · c1_i
+        // z_m · g + z_k · pk = a2 + e · Σ ρ_i · c2_i
+        scalars[0] += (alpha + gamma) * proof.z_m;
+        scalars[1] += alpha * proof.z_r;
+        scalars[2] += gamma * proof.z_k;
+        scalars[3] += beta * proof.z_k;
+
+        let commitment = &proof.commitment;
+        bases.extend([commitment.a, commitment.a1, commitment.a2]);
+        scalars.extend([-alpha, -beta, -gamma]);
+        for ((c, ct), rho) in instance
+            .c
+            .iter()
+            .zip(&instance.ciphertexts)
+            .zip(coefficients)
+        {
+            let e_rho = challenge * rho;
+            bases.extend([*c, ct.c1, ct.c2]);
+            scalars.extend([-alpha * e_rho, -beta * e_rho, -gamma * e_rho]);
+        }
+    }
+}
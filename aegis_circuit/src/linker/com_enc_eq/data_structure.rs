@@ -0,0 +1,112 @@
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+pub use crate::crypto::commitment::elgamal::Ciphertext;
+use crate::solidity::Solidity;
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicParameters<C: CurveGroup> {
+    pub g: C::Affine,
+    /// Blinding generator of the commitments
+    pub h: C::Affine,
+    /// Auditor key `pk = sk · G`, e.g. from [`ElGamal::keygen`](crate::crypto::commitment::elgamal::ElGamal::keygen)
+    pub pk: C::Affine,
+}
+
+impl<C: CurveGroup> Solidity for PublicParameters<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [
+            self.g.to_solidity(),
+            self.h.to_solidity(),
+            self.pk.to_solidity(),
+        ]
+        .concat()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Instance<C: CurveGroup> {
+    /// Commitments `c_i = m_i · g + r_i · h`
+    pub c: Vec<C::Affine>,
+    /// Encryptions of the same `m_i` under the auditor key
+    pub ciphertexts: Vec<Ciphertext<C>>,
+}
+
+impl<C: CurveGroup> Solidity for Instance<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        let mut v = vec![self.c.len().to_string()];
+        v.extend(self.c.to_solidity());
+        v.extend(self.ciphertexts.to_solidity());
+        v
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<C: CurveGroup> {
+    pub m: Vec<C::ScalarField>,
+    /// Blinding factors of the commitments
+    pub r: Vec<C::ScalarField>,
+    /// Encryption randomness of the ciphertexts
+    pub k: Vec<C::ScalarField>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Randomness<C: CurveGroup> {
+    pub m: C::ScalarField,
+    pub r: C::ScalarField,
+    pub k: C::ScalarField,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<C: CurveGroup> {
+    /// `s_m · g + s_r · h`
+    pub a: C::Affine,
+    /// `s_k · G`
+    pub a1: C::Affine,
+    /// `s_m · g + s_k · pk`
+    pub a2: C::Affine,
+}
+
+impl<C: CurveGroup> Solidity for Commitment<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [
+            self.a.to_solidity(),
+            self.a1.to_solidity(),
+            self.a2.to_solidity(),
+        ]
+        .concat()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: CurveGroup> {
+    pub commitment: Commitment<C>,
+    pub z_m: C::ScalarField,
+    pub z_r: C::ScalarField,
+    pub z_k: C::ScalarField,
+}
+
+impl<C: CurveGroup> Solidity for Proof<C>
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [
+            self.z_m.to_solidity(),
+            self.z_r.to_solidity(),
+            self.z_k.to_solidity(),
+            self.commitment.to_solidity(),
+        ]
+        .concat()
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq)]
+pub enum ComEncEqError {
+    /// The commitments, ciphertexts and witness do not have the same length.
+    MismatchParameterLength,
+    /// A point appended to the transcript is the identity.
+    IdentityPoint,
+}
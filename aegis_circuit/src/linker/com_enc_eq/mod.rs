@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{CryptoRng, RngCore};
+
+mod data_structure;
+pub use data_structure::*;
+
+mod batch;
+mod errors;
+pub use errors::ComEncEqError;
+
+mod prover;
+mod verifier;
+
+use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
+
+/// Amortized proof that [`ElGamal`](crate::crypto::commitment::elgamal::ElGamal) ciphertexts
+/// `(k_i · G, m_i · g + k_i · pk)` under an auditor key `pk` encrypt the values of Pedersen
+/// commitments `c_i = m_i · g + r_i · h`.
+///
+/// As in AmComEq, the statements are combined with coefficients `ρ_i` from the transcript, so a
+/// single sigma step on `(Σ ρ_i · m_i, Σ ρ_i · r_i, Σ ρ_i · k_i)` covers the batch and the proof
+/// has constant size. [`ComEncEqVerifier`](crate::solidity::ComEncEqVerifier) generates the
+/// contract verifying these proofs on chain.
+pub struct ComEncEq<C: CurveGroup> {
+    _group: PhantomData<C>,
+}
+
+impl<C: CurveGroup> SigmaProtocol for ComEncEq<C> {
+    type PublicParameters = PublicParameters<C>;
+    type Instance = Instance<C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+    type Error = ComEncEqError;
+
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Ok(pp.clone())
+    }
+
+    fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error> {
+        Self::create_proof(pp, instance, witness, transcript, rng)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        Self::verify_proof(pp, instance, proof, transcript)
+    }
+}
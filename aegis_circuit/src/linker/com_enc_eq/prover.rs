@@ -0,0 +1,92 @@
+use ark_ec::CurveGroup;
+use ark_ff::UniformRand;
+use ark_std::rand::Rng;
+
+use crate::crypto::{
+    commitment::{elgamal::ElGamal, pedersen::Pedersen, CommitmentScheme},
+    protocol::transcript::TranscriptProtocol,
+};
+
+use super::{
+    Ciphertext, ComEncEq, ComEncEqError, Commitment, Instance, Proof, PublicParameters, Randomness,
+    Witness,
+};
+
+impl<C: CurveGroup> ComEncEq<C> {
+    /// Pedersen commitment `m · g + r · h`.
+    pub fn commit(pp: &PublicParameters<C>, m: C::ScalarField, r: C::ScalarField) -> C::Affine {
+        Pedersen::<C>::commit(&[pp.g, pp.h], &[m, r])
+    }
+
+    /// ElGamal encryption `(k · G, m · g + k · pk)` of the value `m`.
+    pub fn encrypt(
+        pp: &PublicParameters<C>,
+        m: C::ScalarField,
+        k: C::ScalarField,
+    ) -> Ciphertext<C> {
+        ElGamal::<C>::commit(&ElGamal::<C>::committing_key(&[pp.g], &pp.pk), &[m, k])
+    }
+
+    /// Check that the witness has one value per commitment and ciphertext.
+    pub fn check_witness(
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+    ) -> Result<(), ComEncEqError> {
+        let l = instance.c.len();
+        if witness.m.len() != l || witness.r.len() != l || witness.k.len() != l {
+            return Err(ComEncEqError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+
+    /// Sample the randomness and compute the commitment.
+    pub fn create_random_commitment(
+        pp: &PublicParameters<C>,
+        rng: &mut impl Rng,
+    ) -> (Randomness<C>, Commitment<C>) {
+        let commit_timer = start_timer!(|| "ComEncEq::Commit");
+        let randomness = Randomness {
+            m: C::ScalarField::rand(rng),
+            r: C::ScalarField::rand(rng),
+            k: C::ScalarField::rand(rng),
+        };
+        let a = Self::commit(pp, randomness.m, randomness.r);
+        let Ciphertext { c1: a1, c2: a2 } = Self::encrypt(pp, randomness.m, randomness.k);
+        end_timer!(commit_timer);
+
+        (randomness, Commitment { a, a1, a2 })
+    }
+
+    pub fn create_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+        transcript: &mut impl TranscriptProtocol,
+        rng: &mut impl Rng,
+    ) -> Result<Proof<C>, ComEncEqError> {
+        Self::check_instance(instance)?;
+        Self::check_witness(instance, witness)?;
+
+        let prover_timer = start_timer!(|| "ComEncEq::Prover");
+
+        let coefficients = Self::compute_coefficients(instance, transcript)?;
+        let (randomness, commitment) = Self::create_random_commitment(pp, rng);
+        let challenge = Self::compute_e(&commitment, transcript)?;
+
+        let combine = |x: &[C::ScalarField]| {
+            x.iter()
+                .zip(&coefficients)
+                .map(|(x, rho)| *x * rho)
+                .sum::<C::ScalarField>()
+        };
+        let proof = Proof {
+            commitment,
+            z_m: randomness.m + challenge * combine(&witness.m),
+            z_r: randomness.r + challenge * combine(&witness.r),
+            z_k: randomness.k + challenge * combine(&witness.k),
+        };
+
+        end_timer!(prover_timer);
+        Ok(proof)
+    }
+}
@@ -0,0 +1,90 @@
+use ark_ec::CurveGroup;
+
+use crate::{crypto::protocol::transcript::TranscriptProtocol, linker::points_to_bytes};
+
+use super::{ComEncEq, ComEncEqError, Commitment, Instance, Proof, PublicParameters};
+
+impl<C: CurveGroup> ComEncEq<C> {
+    pub fn verify_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<bool, ComEncEqError> {
+        Self::check_instance(instance)?;
+
+        let verifier_timer = start_timer!(|| "ComEncEq::Verifier");
+
+        let coefficients = Self::compute_coefficients(instance, transcript)?;
+        let Ok(challenge) = Self::compute_e(&proof.commitment, transcript) else {
+            return Ok(false);
+        };
+
+        let (c, c1, c2) = Self::combine_instance(instance, &coefficients);
+        let Commitment { a, a1, a2 } = &proof.commitment;
+
+        // z_m · g + z_r · h = a + e · Σ ρ_i · c_i
+        let commitment_check = pp.g * proof.z_m + pp.h * proof.z_r == c * challenge + a;
+        // z_k · G = a1 + e · Σ ρ_i · c1_i
+        let randomness_check = C::generator() * proof.z_k == c1 * challenge + a1;
+        // z_m · g + z_k · pk = a2 + e · Σ ρ_i · c2_i
+        let value_check = pp.g * proof.z_m + pp.pk * proof.z_k == c2 * challenge + a2;
+
+        end_timer!(verifier_timer);
+
+        Ok(commitment_check && randomness_check && value_check)
+    }
+
+    /// Check that there is one ciphertext per commitment.
+    pub fn check_instance(instance: &Instance<C>) -> Result<(), ComEncEqError> {
+        if instance.c.len() != instance.ciphertexts.len() {
+            return Err(ComEncEqError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+
+    /// `(Σ ρ_i · c_i, Σ ρ_i · c1_i, Σ ρ_i · c2_i)`.
+    pub fn combine_instance(instance: &Instance<C>, coefficients: &[C::ScalarField]) -> (C, C, C) {
+        let c1 = instance
+            .ciphertexts
+            .iter()
+            .map(|ct| ct.c1)
+            .collect::<Vec<_>>();
+        let c2 = instance
+            .ciphertexts
+            .iter()
+            .map(|ct| ct.c2)
+            .collect::<Vec<_>>();
+        (
+            C::msm_unchecked(&instance.c, coefficients),
+            C::msm_unchecked(&c1, coefficients),
+            C::msm_unchecked(&c2, coefficients),
+        )
+    }
+
+    /// Sample the coefficients `ρ_i` after appending the commitments and ciphertexts.
+    pub fn compute_coefficients<T: TranscriptProtocol>(
+        instance: &Instance<C>,
+        transcript: &mut T,
+    ) -> Result<Vec<C::ScalarField>, ComEncEqError> {
+        let points = instance
+            .c
+            .iter()
+            .zip(&instance.ciphertexts)
+            .flat_map(|(c, ct)| [*c, ct.c1, ct.c2])
+            .collect::<Vec<_>>();
+        let bytes = points_to_bytes(&points).ok_or(ComEncEqError::IdentityPoint)?;
+        transcript.append(b"instance", &bytes);
+        Ok(transcript.challenge_scalars(b"coefficients", instance.c.len()))
+    }
+
+    pub fn compute_e<T: TranscriptProtocol>(
+        commitment: &Commitment<C>,
+        transcript: &mut T,
+    ) -> Result<C::ScalarField, ComEncEqError> {
+        let bytes = points_to_bytes(&[commitment.a, commitment.a1, commitment.a2])
+            .ok_or(ComEncEqError::IdentityPoint)?;
+        transcript.append(b"commitment", &bytes);
+        Ok(transcript.challenge_scalar(b"challenge"))
+    }
+}
@@ -0,0 +1,60 @@
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::solidity::Solidity;
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicParameters<C: CurveGroup> {
+    pub g: Vec<C::Affine>,
+    /// Fold threshold of the CompDLEq step of
+    /// [`CompComPoK`](crate::linker::comp_com_pok::CompComPoK), unused by [`ComPoK`](super::ComPoK)
+    pub threshold: usize,
+}
+
+impl<C: CurveGroup> Solidity for PublicParameters<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        let mut v = vec![self.g.len().to_string()];
+        v.extend(self.g.to_solidity());
+        v
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Instance<C: CurveGroup> {
+    /// Commitments of the accounts, `c_i = <g, m_i>`
+    pub c: Vec<C::Affine>,
+}
+
+impl<C: CurveGroup> Solidity for Instance<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        self.c.to_solidity()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<C: CurveGroup> {
+    /// Openings `m_i`, at most as long as `g`
+    pub m: Vec<Vec<C::ScalarField>>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: CurveGroup> {
+    pub a: C::Affine,
+    pub z: Vec<C::ScalarField>,
+}
+
+impl<C: CurveGroup> Solidity for Proof<C>
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [self.z.to_solidity(), self.a.to_solidity()].concat()
+    }
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, PartialEq)]
+pub enum ComPoKError {
+    /// An opening is longer than the committing key, or the witness and instance differ in size.
+    MismatchParameterLength,
+    /// A point appended to the transcript is the identity.
+    IdentityPoint,
+}
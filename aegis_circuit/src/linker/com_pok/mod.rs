@@ -0,0 +1,55 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{CryptoRng, RngCore};
+
+mod data_structure;
+pub use data_structure::*;
+
+mod errors;
+pub use errors::ComPoKError;
+
+mod prover;
+mod verifier;
+
+use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
+
+/// Amortized proof of knowledge of the openings of vector Pedersen commitments
+/// `c_i = <g, m_i>` under a common key `g`.
+///
+/// A single commitment `a = <g, r>` and response `z = r + Σ ρ_i · m_i` cover every commitment,
+/// so the proof size does not grow with the number of accounts.
+pub struct ComPoK<C: CurveGroup> {
+    _group: PhantomData<C>,
+}
+
+impl<C: CurveGroup> SigmaProtocol for ComPoK<C> {
+    type PublicParameters = PublicParameters<C>;
+    type Instance = Instance<C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+    type Error = ComPoKError;
+
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Ok(pp.clone())
+    }
+
+    fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error> {
+        Self::create_proof(pp, instance, witness, transcript, rng)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        Self::verify_proof(pp, instance, proof, transcript)
+    }
+}
@@ -0,0 +1,86 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{PrimeField, UniformRand};
+use ark_std::rand::Rng;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::crypto::protocol::transcript::TranscriptProtocol;
+
+use super::{ComPoK, ComPoKError, Instance, Proof, PublicParameters, Witness};
+
+impl<C: CurveGroup> ComPoK<C> {
+    /// Check that every opening fits the committing key and matches a commitment.
+    pub fn check_witness(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+    ) -> Result<(), ComPoKError> {
+        if witness.m.len() != instance.c.len() || witness.m.iter().any(|m| m.len() > pp.g.len()) {
+            return Err(ComPoKError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+
+    /// Compute the response `z = r + Σ ρ_i · m_i`.
+    pub fn compute_response(
+        r: &[C::ScalarField],
+        witness: &Witness<C>,
+        coefficients: &[C::ScalarField],
+    ) -> Vec<C::ScalarField> {
+        let indicies = (0..r.len()).collect::<Vec<_>>();
+        cfg_iter!(indicies)
+            .map(|&j| {
+                witness
+                    .m
+                    .iter()
+                    .zip(coefficients)
+                    .filter_map(|(m, rho)| m.get(j).map(|m| *m * rho))
+                    .fold(r[j], |acc, x| acc + x)
+            })
+            .collect()
+    }
+
+    /// Sample the randomness `r` and compute `a = <g, r>`.
+    pub fn create_random_commitment(
+        pp: &PublicParameters<C>,
+        rng: &mut impl Rng,
+    ) -> Result<(Vec<C::ScalarField>, C::Affine), ComPoKError> {
+        let commit_timer = start_timer!(|| "ComPoK::Commit");
+        let r = (0..pp.g.len())
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let r_bigint = cfg_iter!(r).map(|s| s.into_bigint()).collect::<Vec<_>>();
+        let a = C::msm_bigint(&pp.g, &r_bigint).into_affine();
+        if a.is_zero() {
+            return Err(ComPoKError::IdentityPoint);
+        }
+        end_timer!(commit_timer);
+
+        Ok((r, a))
+    }
+
+    /// Create a proof for every commitment of the instance at once.
+    pub fn create_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+        transcript: &mut impl TranscriptProtocol,
+        rng: &mut impl Rng,
+    ) -> Result<Proof<C>, ComPoKError> {
+        Self::check_witness(pp, instance, witness)?;
+
+        let prover_timer = start_timer!(|| "ComPoK::Prover");
+
+        let (r, a) = Self::create_random_commitment(pp, rng)?;
+        let coefficients = Self::compute_coefficients(instance, &a, transcript)?;
+
+        let z_timer = start_timer!(|| "Compute Z");
+        let z = Self::compute_response(&r, witness, &coefficients);
+        end_timer!(z_timer);
+
+        end_timer!(prover_timer);
+
+        Ok(Proof { a, z })
+    }
+}
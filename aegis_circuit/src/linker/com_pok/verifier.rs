@@ -0,0 +1,62 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::PrimeField;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{crypto::protocol::transcript::TranscriptProtocol, linker::points_to_bytes};
+
+use super::{ComPoK, ComPoKError, Instance, Proof, PublicParameters};
+
+impl<C: CurveGroup> ComPoK<C> {
+    pub fn verify_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<bool, ComPoKError> {
+        if proof.z.len() != pp.g.len() || proof.a.is_zero() {
+            return Ok(false);
+        }
+
+        let verifier_timer = start_timer!(|| "ComPoK::Verifier");
+
+        let coefficients = Self::compute_coefficients(instance, &proof.a, transcript)?;
+
+        let expected = Self::compute_expected(instance, &proof.a, &coefficients);
+
+        let z = cfg_iter!(proof.z)
+            .map(|s| s.into_bigint())
+            .collect::<Vec<_>>();
+        let real = C::msm_bigint(&pp.g, &z);
+
+        end_timer!(verifier_timer);
+
+        Ok(real == expected)
+    }
+
+    /// Expected value of `<g, z>`, i.e. `a + Σ ρ_i · c_i`.
+    pub fn compute_expected(
+        instance: &Instance<C>,
+        a: &C::Affine,
+        coefficients: &[C::ScalarField],
+    ) -> C {
+        let coefficients = cfg_iter!(coefficients)
+            .map(|s| s.into_bigint())
+            .collect::<Vec<_>>();
+        C::msm_bigint(&instance.c, &coefficients) + a
+    }
+
+    /// Sample the coefficients `ρ_i` after appending the commitments and `a`.
+    pub fn compute_coefficients<T: TranscriptProtocol>(
+        instance: &Instance<C>,
+        a: &C::Affine,
+        transcript: &mut T,
+    ) -> Result<Vec<C::ScalarField>, ComPoKError> {
+        let bytes = points_to_bytes(&instance.c).ok_or(ComPoKError::IdentityPoint)?;
+        transcript.append(b"instance", &bytes);
+        let bytes = points_to_bytes(&[*a]).ok_or(ComPoKError::IdentityPoint)?;
+        transcript.append(b"commitment", &bytes);
+        Ok(transcript.challenge_scalars(b"challenge", instance.c.len()))
+    }
+}
@@ -0,0 +1,161 @@
+use ark_ec::CurveGroup;
+use ark_ff::UniformRand;
+use ark_std::rand::Rng;
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol,
+    linker::{
+        am_com_eq::{AmComEq, BatchVerifier},
+        comp_dl_eq::CompDLEq,
+    },
+};
+
+use super::{CompAmComEq, CompAmComEqError, Instance, Proof, PublicParameters};
+
+/// Transcript-derived values of one proof.
+struct Challenges<F> {
+    coefficients: Vec<F>,
+    e: F,
+    rounds: Vec<F>,
+}
+
+impl<C: CurveGroup> CompAmComEq<C> {
+    /// Verify many proofs under the same public parameters with a random linear combination.
+    /// The CompDLEq folding of each proof is expanded into scalars on the original generators,
+    /// so `poly_ck` and `coeff_ck` enter a single MSM.
+    /// Returns the indices of the rejected proofs, empty if every proof is accepted. A malformed
+    /// instance or proof rejects its own index only.
+    pub fn batch_verify<T: TranscriptProtocol>(
+        pp: &PublicParameters<C>,
+        batch: &mut [(&Instance<C>, &Proof<C>, T)],
+        rng: &mut impl Rng,
+    ) -> Result<Vec<usize>, CompAmComEqError> {
+        let batch_timer = start_timer!(|| format!("CompAmComEq::BatchVerifier ({})", batch.len()));
+
+        CompDLEq::<C>::check_threshold(pp.threshold)?;
+        let (n, threshold) = (pp.poly_ck.g.len(), pp.threshold);
+
+        let mut rejected = vec![];
+        let mut accepted = vec![];
+        for (index, (instance, proof, transcript)) in batch.iter_mut().enumerate() {
+            if AmComEq::check_instance(pp, instance).is_err()
+                || proof.ace.z.len() != CompDLEq::<C>::final_len(n, threshold)
+                || proof.ace.omega.len() != pp.poly_ck.h.len()
+                || proof.ace.omega_hat.len() != pp.coeff_ck.h.len()
+                || proof.commitments.len() != CompDLEq::<C>::num_rounds(n, threshold)
+            {
+                rejected.push(index);
+                continue;
+            }
+            let Ok(coefficients) = AmComEq::compute_coefficients(instance, transcript) else {
+                rejected.push(index);
+                continue;
+            };
+            let Ok(e) = AmComEq::compute_e(&proof.ace.commitment, transcript) else {
+                rejected.push(index);
+                continue;
+            };
+            let Ok(rounds) = proof
+                .commitments
+                .iter()
+                .map(|commitment| CompDLEq::compute_challenge(commitment, transcript))
+                .collect::<Result<Vec<_>, _>>()
+            else {
+                rejected.push(index);
+                continue;
+            };
+            accepted.push((
+                index,
+                Challenges {
+                    coefficients,
+                    e,
+                    rounds,
+                },
+            ));
+        }
+
+        let mut verifier = BatchVerifier::new(pp);
+        for (index, challenges) in accepted.iter() {
+            let (instance, proof, _) = &batch[*index];
+            Self::accumulate(&mut verifier, pp, instance, proof, challenges, rng);
+        }
+
+        // identify the offending proofs one by one
+        if !verifier.verify(pp) {
+            for (index, challenges) in accepted.iter() {
+                let (instance, proof, _) = &batch[*index];
+                let mut verifier = BatchVerifier::new(pp);
+                Self::accumulate(&mut verifier, pp, instance, proof, challenges, rng);
+                if !verifier.verify(pp) {
+                    rejected.push(*index);
+                }
+            }
+            rejected.sort_unstable();
+        }
+
+        end_timer!(batch_timer);
+        Ok(rejected)
+    }
+
+    /// Add the final CompDLEq equations on `g` and `g_hat` with independent random weights,
+    /// where `y` and `y_hat` are expanded as in
+    /// [`prepare_for_comp_dl_eq`](CompAmComEq::prepare_for_comp_dl_eq).
+    fn accumulate(
+        verifier: &mut BatchVerifier<C>,
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        challenges: &Challenges<C::ScalarField>,
+        rng: &mut impl Rng,
+    ) {
+        let alpha = C::ScalarField::rand(rng);
+        let beta = C::ScalarField::rand(rng);
+        let Challenges {
+            coefficients,
+            e,
+            rounds,
+        } = challenges;
+
+        let z = CompDLEq::<C>::expand_response(pp.poly_ck.g.len(), rounds, &proof.ace.z);
+        let (first, suffix) = CompDLEq::<C>::instance_scalars(rounds);
+        let omega = proof
+            .ace
+            .omega
+            .iter()
+            .map(|omega| *omega * first)
+            .collect::<Vec<_>>();
+        let omega_hat = proof
+            .ace
+            .omega_hat
+            .iter()
+            .map(|omega_hat| *omega_hat * first)
+            .collect::<Vec<_>>();
+
+        // <g_k, z> = P_0 · (a + e · c - <h, omega>) + Σ_r P_{r+1} · (left_r + x_r^2 · right_r)
+        verifier.add_poly(alpha, &z, &omega);
+        verifier.add_point(proof.ace.commitment.a, -alpha * first);
+        verifier.add_point(instance.c, -alpha * first * e);
+
+        // same on g_hat_j = ρ_i · coeff_ck.g_{j mod d0}, with a_hat and Σ ρ_i · c_hat_i
+        verifier.add_coeff(beta, &z, coefficients, &omega_hat);
+        verifier.add_point(proof.ace.commitment.a_hat, -beta * first);
+        instance
+            .c_hat
+            .iter()
+            .zip(coefficients)
+            .for_each(|(c_hat, rho)| verifier.add_point(*c_hat, -beta * first * e * rho));
+
+        proof
+            .commitments
+            .iter()
+            .zip(rounds)
+            .zip(suffix)
+            .for_each(|((commitment, x), p)| {
+                let sqr = *x * x;
+                verifier.add_point(commitment.left, -alpha * p);
+                verifier.add_point(commitment.right, -alpha * p * sqr);
+                verifier.add_point(commitment.left_hat, -beta * p);
+                verifier.add_point(commitment.right_hat, -beta * p * sqr);
+            });
+    }
+}
@@ -6,7 +6,8 @@ pub(super) use crate::linker::{
     am_com_eq::Proof as ACEProof,
     comp_dl_eq::{
         Commitment as RecursionCommitment, Instance as RecursionInstance, Proof as CDEProof,
-        PublicParameters as RecursionPublicParameters, Witness as RecursionWitness,
+        PublicParameters as RecursionPublicParameters, ScaledGenerators,
+        Witness as RecursionWitness,
     },
 };
 use crate::solidity::Solidity;
@@ -26,3 +27,13 @@ where
         vec![self.ace.to_solidity(), self.commitments.to_solidity()].concat()
     }
 }
+
+/// Last move of a composed [`CompAmComEq`](super::CompAmComEq): the blinding responses `ω` and
+/// `Ω`, and the CompDLEq folding of `z`.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Response<C: CurveGroup> {
+    pub commitments: Vec<RecursionCommitment<C>>,
+    pub z: Vec<C::ScalarField>,
+    pub omega: Vec<C::ScalarField>,
+    pub omega_hat: Vec<C::ScalarField>,
+}
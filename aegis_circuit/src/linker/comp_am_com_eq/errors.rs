@@ -0,0 +1,21 @@
+use crate::linker::{am_com_eq::AmComEqError, comp_dl_eq::CompDLEqError};
+
+#[derive(Debug, PartialEq)]
+pub enum CompAmComEqError {
+    /// Failure of the AmComEq step.
+    AmComEq(AmComEqError),
+    /// Failure of the compressed CompDLEq step.
+    CompDLEq(CompDLEqError),
+}
+
+impl From<AmComEqError> for CompAmComEqError {
+    fn from(e: AmComEqError) -> Self {
+        CompAmComEqError::AmComEq(e)
+    }
+}
+
+impl From<CompDLEqError> for CompAmComEqError {
+    fn from(e: CompDLEqError) -> Self {
+        CompAmComEqError::CompDLEq(e)
+    }
+}
@@ -4,14 +4,14 @@ use ark_ff::PrimeField;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use super::{data_structure::*, CompAmComEq};
+use super::{data_structure::*, CompAmComEq, CompAmComEqError};
 
 impl<C: CurveGroup> CompAmComEq<C> {
     pub fn prepare_for_comp_dl_eq(
         pp: &PublicParameters<C>,
         instance: &Instance<C>,
         proof: &ACEProof<C>,
-        powers_of_x: &[C::ScalarField],
+        coefficients: &[C::ScalarField],
         challenge: C::ScalarField,
     ) -> Result<
         (
@@ -19,19 +19,19 @@ impl<C: CurveGroup> CompAmComEq<C> {
             RecursionInstance<C>,
             RecursionWitness<C>,
         ),
-        (),
+        CompAmComEqError,
     > {
         let prepare_timer = start_timer!(|| "Prepare for CompDLEq");
 
-        let g = pp.poly_ck.g.clone();
-        let g_hat = cfg_iter!(powers_of_x)
-            .map(|x| {
-                cfg_iter!(pp.coeff_ck.g)
-                    .map(|g| (*g * x).into_affine())
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>()
-            .concat();
+        let g = pp.poly_ck.g.clone().into();
+        // g_hat_{i·d0 + j} = ρ_i · coeff_ck.g_j, folded lazily by CompDLEq
+        let d0 = pp.coeff_ck.g.len();
+        let g_hat = ScaledGenerators {
+            bases: pp.coeff_ck.g.clone(),
+            scalars: (0..pp.poly_ck.g.len())
+                .map(|j| coefficients[j / d0])
+                .collect(),
+        };
 
         let expected_timer = start_timer!(|| "Compute Expected");
 
@@ -45,23 +45,27 @@ impl<C: CurveGroup> CompAmComEq<C> {
         end_timer!(y_timer);
 
         let y_hat_timer = start_timer!(|| "Compute Y Hat");
-        let powers_of_x = cfg_iter!(powers_of_x)
+        let coefficients = cfg_iter!(coefficients)
             .map(|s| s.into_bigint())
             .collect::<Vec<_>>();
         let omega_hat = cfg_iter!(proof.omega_hat)
             .map(|s| s.into_bigint())
             .collect::<Vec<_>>();
         let y_hat = proof.commitment.a_hat
-            + C::msm_bigint(&instance.c_hat, &powers_of_x[..]) * challenge
+            + C::msm_bigint(&instance.c_hat, &coefficients[..]) * challenge
             - C::msm_bigint(&pp.coeff_ck.h, &omega_hat[..]);
-        drop(powers_of_x);
+        drop(coefficients);
         drop(omega_hat);
         end_timer!(y_hat_timer);
         end_timer!(expected_timer);
         end_timer!(prepare_timer);
 
         Ok((
-            RecursionPublicParameters { g, g_hat },
+            RecursionPublicParameters {
+                g,
+                g_hat,
+                threshold: pp.threshold,
+            },
             RecursionInstance {
                 y: y.into_affine(),
                 y_hat: y_hat.into_affine(),
@@ -0,0 +1,239 @@
+use ark_ec::CurveGroup;
+use ark_ff::UniformRand;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::{CryptoRng, RngCore};
+
+use crate::{
+    crypto::protocol::interactive::{
+        decode, encode, InteractiveError, InteractiveProver, InteractiveVerifier, Move,
+    },
+    linker::{
+        am_com_eq::{AmComEq, Commitment, Randomness},
+        comp_dl_eq,
+    },
+};
+
+use super::{data_structure::*, CompAmComEq, CompAmComEqError};
+
+/// Prover message answering the challenge `e`: the blinding responses `ω` and `Ω`.
+/// The response `z` is never sent; CompDLEq proves knowledge of it.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Openings<C: CurveGroup> {
+    pub omega: Vec<C::ScalarField>,
+    pub omega_hat: Vec<C::ScalarField>,
+}
+
+enum ProverRound<C: CurveGroup> {
+    Coefficients,
+    Challenge {
+        coefficients: Vec<C::ScalarField>,
+        randomness: Randomness<C>,
+        commitment: Commitment<C>,
+    },
+    Folding(comp_dl_eq::interactive::Prover<C>),
+}
+
+/// Interactive CompAmComEq prover: the AmComEq moves up to the challenge `e`, answered with the
+/// [`Openings`], then the rounds of the CompDLEq prover on `z`.
+pub struct Prover<'a, C: CurveGroup> {
+    pp: &'a PublicParameters<C>,
+    instance: &'a Instance<C>,
+    witness: &'a Witness<C>,
+    round: ProverRound<C>,
+}
+
+impl<'a, C: CurveGroup> Prover<'a, C> {
+    pub fn new(
+        pp: &'a PublicParameters<C>,
+        instance: &'a Instance<C>,
+        witness: &'a Witness<C>,
+    ) -> Result<Self, CompAmComEqError> {
+        AmComEq::check_instance(pp, instance)?;
+        AmComEq::check_witness(pp, instance, witness)?;
+        Ok(Self {
+            pp,
+            instance,
+            witness,
+            round: ProverRound::Coefficients,
+        })
+    }
+}
+
+impl<'a, C: CurveGroup> InteractiveProver for Prover<'a, C> {
+    type Error = CompAmComEqError;
+
+    fn next_message<R: RngCore + CryptoRng>(
+        &mut self,
+        challenge: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>, InteractiveError<Self::Error>> {
+        match &mut self.round {
+            ProverRound::Coefficients => {
+                let coefficients = decode::<Vec<C::ScalarField>>(challenge)
+                    .filter(|rho| rho.len() == self.instance.c_hat.len())
+                    .ok_or(InteractiveError::MalformedChallenge)?;
+                let (randomness, commitment) =
+                    AmComEq::create_random_commitment(self.pp, &coefficients, rng)
+                        .map_err(|e| InteractiveError::Protocol(e.into()))?;
+                let message = encode(&commitment);
+                self.round = ProverRound::Challenge {
+                    coefficients,
+                    randomness,
+                    commitment,
+                };
+                Ok(message)
+            }
+            ProverRound::Challenge {
+                coefficients,
+                randomness,
+                commitment,
+            } => {
+                let challenge = decode::<C::ScalarField>(challenge)
+                    .ok_or(InteractiveError::MalformedChallenge)?;
+                let ace_proof = AmComEq::create_proof_with_assignment(
+                    self.pp,
+                    self.witness,
+                    randomness,
+                    commitment,
+                    coefficients,
+                    challenge,
+                )
+                .map_err(|e| InteractiveError::Protocol(e.into()))?;
+                let (pp, instance, witness) = CompAmComEq::prepare_for_comp_dl_eq(
+                    self.pp,
+                    self.instance,
+                    &ace_proof,
+                    coefficients,
+                    challenge,
+                )
+                .map_err(InteractiveError::Protocol)?;
+                let prover = comp_dl_eq::interactive::Prover::new(&pp, instance, witness)
+                    .map_err(|e| InteractiveError::Protocol(e.into()))?;
+                self.round = ProverRound::Folding(prover);
+                Ok(encode(&Openings::<C> {
+                    omega: ace_proof.omega,
+                    omega_hat: ace_proof.omega_hat,
+                }))
+            }
+            ProverRound::Folding(prover) => prover
+                .next_message(challenge, rng)
+                .map_err(|e| e.map(Into::into)),
+        }
+    }
+}
+
+enum VerifierRound<C: CurveGroup> {
+    Start,
+    Commitment {
+        coefficients: Vec<C::ScalarField>,
+    },
+    Openings {
+        coefficients: Vec<C::ScalarField>,
+        commitment: Commitment<C>,
+        challenge: C::ScalarField,
+    },
+    Folding(comp_dl_eq::interactive::Verifier<C>),
+    Finished,
+}
+
+/// Interactive CompAmComEq verifier, handing over to the CompDLEq verifier once it has the
+/// [`Openings`].
+pub struct Verifier<'a, C: CurveGroup> {
+    pp: &'a PublicParameters<C>,
+    instance: &'a Instance<C>,
+    round: VerifierRound<C>,
+}
+
+impl<'a, C: CurveGroup> Verifier<'a, C> {
+    pub fn new(
+        pp: &'a PublicParameters<C>,
+        instance: &'a Instance<C>,
+    ) -> Result<Self, CompAmComEqError> {
+        AmComEq::check_instance(pp, instance)?;
+        Ok(Self {
+            pp,
+            instance,
+            round: VerifierRound::Start,
+        })
+    }
+}
+
+impl<'a, C: CurveGroup> InteractiveVerifier for Verifier<'a, C> {
+    type Error = CompAmComEqError;
+
+    fn next_move<R: RngCore + CryptoRng>(
+        &mut self,
+        message: Option<&[u8]>,
+        rng: &mut R,
+    ) -> Result<Move, InteractiveError<Self::Error>> {
+        let round = std::mem::replace(&mut self.round, VerifierRound::Finished);
+        match (round, message) {
+            (VerifierRound::Start, None) => {
+                let coefficients = (0..self.instance.c_hat.len())
+                    .map(|_| C::ScalarField::rand(rng))
+                    .collect::<Vec<_>>();
+                let challenge = encode(&coefficients);
+                self.round = VerifierRound::Commitment { coefficients };
+                Ok(Move::Challenge(challenge))
+            }
+            (VerifierRound::Commitment { coefficients }, Some(message)) => {
+                let Some(commitment) = decode::<Commitment<C>>(message) else {
+                    return Ok(Move::Decision(false));
+                };
+                let challenge = C::ScalarField::rand(rng);
+                self.round = VerifierRound::Openings {
+                    coefficients,
+                    commitment,
+                    challenge,
+                };
+                Ok(Move::Challenge(encode(&challenge)))
+            }
+            (
+                VerifierRound::Openings {
+                    coefficients,
+                    commitment,
+                    challenge,
+                },
+                Some(message),
+            ) => {
+                let Some(openings) = decode::<Openings<C>>(message).filter(|o| {
+                    o.omega.len() == self.pp.poly_ck.h.len()
+                        && o.omega_hat.len() == self.pp.coeff_ck.h.len()
+                }) else {
+                    return Ok(Move::Decision(false));
+                };
+                let ace = ACEProof {
+                    commitment,
+                    z: vec![],
+                    omega: openings.omega,
+                    omega_hat: openings.omega_hat,
+                };
+                let (pp, instance, _) = CompAmComEq::prepare_for_comp_dl_eq(
+                    self.pp,
+                    self.instance,
+                    &ace,
+                    &coefficients,
+                    challenge,
+                )
+                .map_err(InteractiveError::Protocol)?;
+                let mut verifier = comp_dl_eq::interactive::Verifier::new(pp, instance)
+                    .map_err(|e| InteractiveError::Protocol(e.into()))?;
+                let next = verifier
+                    .next_move(None, rng)
+                    .map_err(|e| e.map(Into::into))?;
+                self.round = VerifierRound::Folding(verifier);
+                Ok(next)
+            }
+            (VerifierRound::Folding(mut verifier), Some(message)) => {
+                let next = verifier
+                    .next_move(Some(message), rng)
+                    .map_err(|e| e.map(Into::into))?;
+                if let Move::Challenge(_) = next {
+                    self.round = VerifierRound::Folding(verifier);
+                }
+                Ok(next)
+            }
+            _ => Err(InteractiveError::OutOfOrder),
+        }
+    }
+}
@@ -1,16 +1,31 @@
 use std::marker::PhantomData;
 
 use ark_ec::CurveGroup;
+use ark_serialize::CanonicalSerialize;
 use ark_std::rand::{CryptoRng, RngCore};
 
 mod data_structure;
 pub use data_structure::*;
 
+mod errors;
+pub use errors::CompAmComEqError;
+
+mod batch;
 mod generator;
+pub mod interactive;
 mod prover;
 mod verifier;
 
-use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
+use crate::{
+    crypto::protocol::{
+        sigma::{ComposableSigma, SigmaProtocol},
+        transcript::{sha3::SHA3Base, TranscriptProtocol},
+    },
+    linker::{
+        am_com_eq::{self, AmComEq, Commitment, Randomness},
+        comp_dl_eq::CompDLEq,
+    },
+};
 
 pub struct CompAmComEq<C: CurveGroup> {
     _group: PhantomData<C>,
@@ -21,9 +36,9 @@ impl<C: CurveGroup> SigmaProtocol for CompAmComEq<C> {
     type Instance = Instance<C>;
     type Witness = Witness<C>;
     type Proof = Proof<C>;
-    type Error = ();
+    type Error = CompAmComEqError;
 
-    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, ()> {
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
         Ok(pp.clone())
     }
 
@@ -33,7 +48,7 @@ impl<C: CurveGroup> SigmaProtocol for CompAmComEq<C> {
         witness: &Self::Witness,
         transcript: &mut T,
         rng: &mut R,
-    ) -> Result<Self::Proof, ()> {
+    ) -> Result<Self::Proof, Self::Error> {
         Self::creat_proof_with_combined(pp, instance, witness, transcript, rng)
     }
 
@@ -42,7 +57,148 @@ impl<C: CurveGroup> SigmaProtocol for CompAmComEq<C> {
         instance: &Self::Instance,
         proof: &Self::Proof,
         transcript: &mut T,
-    ) -> Result<bool, ()> {
+    ) -> Result<bool, Self::Error> {
         Self::verify_proof(pp, instance, proof, transcript)
     }
 }
+
+/// Composition runs the AmComEq moves, and folds `z` once the shared challenge is known.
+///
+/// The simulated branch of an [`Or`](crate::crypto::protocol::sigma::or::Or) is answered before
+/// that, so the folding cannot continue the transcript of the composition. It runs on a
+/// transcript of its own, bound to the coefficients and to the folded statement `(y, y_hat)`.
+impl<C: CurveGroup> ComposableSigma for CompAmComEq<C> {
+    type Challenge = C::ScalarField;
+    /// The instance with its coefficients `ρ_i`
+    type Context = (Instance<C>, Vec<C::ScalarField>);
+    type Commitment = Commitment<C>;
+    type Response = Response<C>;
+    type State = (Randomness<C>, Commitment<C>);
+
+    fn append_instance<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        transcript: &mut T,
+    ) -> Result<Self::Context, Self::Error> {
+        Ok(AmComEq::append_instance(pp, instance, transcript)?)
+    }
+
+    fn commit<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> Result<(Self::State, Self::Commitment), Self::Error> {
+        let (randomness, commitment) = AmComEq::commit(pp, context, witness, rng)?;
+        Ok(((randomness, commitment.clone()), commitment))
+    }
+
+    fn append_commitment<T: TranscriptProtocol>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), Self::Error> {
+        Ok(AmComEq::append_commitment(commitment, transcript)?)
+    }
+
+    fn respond(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: Self::Challenge,
+    ) -> Result<Self::Response, Self::Error> {
+        let (randomness, commitment) = state;
+        let response = AmComEq::respond(pp, context, witness, randomness, challenge)?;
+        Self::fold_response(pp, context, commitment, response, challenge)
+    }
+
+    fn check(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        commitment: &Self::Commitment,
+        challenge: Self::Challenge,
+        response: &Self::Response,
+    ) -> Result<bool, Self::Error> {
+        CompDLEq::<C>::check_threshold(pp.threshold)?;
+        if response.z.len() != CompDLEq::<C>::final_len(pp.poly_ck.g.len(), pp.threshold)
+            || response.omega.len() != pp.poly_ck.h.len()
+            || response.omega_hat.len() != pp.coeff_ck.h.len()
+        {
+            return Ok(false);
+        }
+        let ace_proof = ACEProof {
+            commitment: commitment.clone(),
+            z: response.z.clone(),
+            omega: response.omega.clone(),
+            omega_hat: response.omega_hat.clone(),
+        };
+        let (pp, instance, _) =
+            Self::prepare_for_comp_dl_eq(pp, &context.0, &ace_proof, &context.1, challenge)?;
+        let cde_proof = CDEProof {
+            commitments: response.commitments.clone(),
+            z: response.z.clone(),
+        };
+        let mut transcript = Self::folding_transcript(&context.1, &instance);
+        Ok(CompDLEq::verify_proof(
+            &pp,
+            &instance,
+            &cde_proof,
+            &mut transcript,
+        )?)
+    }
+
+    /// Simulated AmComEq moves, with the chosen `z` folded honestly.
+    fn simulate<R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        context: &Self::Context,
+        challenge: Self::Challenge,
+        rng: &mut R,
+    ) -> Result<(Self::Commitment, Self::Response), Self::Error> {
+        let (commitment, response) = AmComEq::simulate(pp, context, challenge, rng)?;
+        let response = Self::fold_response(pp, context, commitment.clone(), response, challenge)?;
+        Ok((commitment, response))
+    }
+}
+
+impl<C: CurveGroup> CompAmComEq<C> {
+    /// Fold the AmComEq response `z` with CompDLEq.
+    fn fold_response(
+        pp: &PublicParameters<C>,
+        context: &(Instance<C>, Vec<C::ScalarField>),
+        commitment: Commitment<C>,
+        response: am_com_eq::interactive::Response<C>,
+        challenge: C::ScalarField,
+    ) -> Result<Response<C>, CompAmComEqError> {
+        let ace_proof = ACEProof {
+            commitment,
+            z: response.z,
+            omega: response.omega,
+            omega_hat: response.omega_hat,
+        };
+        let (pp, instance, witness) =
+            Self::prepare_for_comp_dl_eq(pp, &context.0, &ace_proof, &context.1, challenge)?;
+        let mut transcript = Self::folding_transcript(&context.1, &instance);
+        let cde_proof = CompDLEq::create_proof(&pp, &instance, &witness, &mut transcript)?;
+        Ok(Response {
+            commitments: cde_proof.commitments,
+            z: cde_proof.z,
+            omega: ace_proof.omega,
+            omega_hat: ace_proof.omega_hat,
+        })
+    }
+
+    fn folding_transcript(
+        coefficients: &[C::ScalarField],
+        instance: &RecursionInstance<C>,
+    ) -> SHA3Base {
+        let mut transcript = SHA3Base::new(true);
+        let mut bytes = vec![];
+        coefficients.serialize_uncompressed(&mut bytes).unwrap();
+        transcript.append(b"coefficients", &bytes);
+
+        let mut bytes = vec![];
+        instance.serialize_uncompressed(&mut bytes).unwrap();
+        transcript.append(b"instance", &bytes);
+        transcript
+    }
+}
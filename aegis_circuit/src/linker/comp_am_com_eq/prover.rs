@@ -6,7 +6,7 @@ use crate::{
     linker::{am_com_eq::AmComEq, comp_dl_eq::CompDLEq},
 };
 
-use super::{data_structure::*, CompAmComEq};
+use super::{data_structure::*, CompAmComEq, CompAmComEqError};
 
 impl<C: CurveGroup> CompAmComEq<C> {
     pub fn creat_proof_with_combined<R: RngCore + CryptoRng>(
@@ -15,23 +15,26 @@ impl<C: CurveGroup> CompAmComEq<C> {
         witness: &Witness<C>,
         transcript: &mut impl TranscriptProtocol,
         rng: &mut R,
-    ) -> Result<Proof<C>, ()> {
+    ) -> Result<Proof<C>, CompAmComEqError> {
+        AmComEq::check_instance(pp, instance)?;
+        AmComEq::check_witness(pp, instance, witness)?;
+
         let prover_timer = start_timer!(|| "CompAmComEq::Prover");
 
-        let powers_of_x = AmComEq::compute_powers_of_x(instance, transcript);
-        let (randomness, commitment) = AmComEq::create_random_commitment(pp, &powers_of_x, rng)?;
-        let challenge = AmComEq::compute_e(&commitment, transcript);
+        let coefficients = AmComEq::compute_coefficients(instance, transcript)?;
+        let (randomness, commitment) = AmComEq::create_random_commitment(pp, &coefficients, rng)?;
+        let challenge = AmComEq::compute_e(&commitment, transcript)?;
         let ace_proof = AmComEq::create_proof_with_assignment(
             pp,
             witness,
             &randomness,
             &commitment,
-            &powers_of_x,
+            &coefficients,
             challenge,
         )?;
 
         let (pp, instance, witness) =
-            Self::prepare_for_comp_dl_eq(pp, instance, &ace_proof, &powers_of_x, challenge)?;
+            Self::prepare_for_comp_dl_eq(pp, instance, &ace_proof, &coefficients, challenge)?;
         let cde_proof = CompDLEq::create_proof(&pp, &instance, &witness, transcript)?;
 
         end_timer!(prover_timer);
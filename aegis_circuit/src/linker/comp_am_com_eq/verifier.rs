@@ -5,7 +5,7 @@ use crate::{
     linker::{am_com_eq::AmComEq, comp_dl_eq::CompDLEq},
 };
 
-use super::{CDEProof, CompAmComEq, Instance, Proof, PublicParameters};
+use super::{CDEProof, CompAmComEq, CompAmComEqError, Instance, Proof, PublicParameters};
 
 impl<C: CurveGroup> CompAmComEq<C> {
     pub fn verify_proof<T: TranscriptProtocol>(
@@ -13,18 +13,25 @@ impl<C: CurveGroup> CompAmComEq<C> {
         instance: &Instance<C>,
         proof: &Proof<C>,
         transcript: &mut T,
-    ) -> Result<bool, ()> {
-        if proof.ace.z.len() != 2 {
-            return Err(());
+    ) -> Result<bool, CompAmComEqError> {
+        AmComEq::check_instance(pp, instance)?;
+        CompDLEq::<C>::check_threshold(pp.threshold)?;
+        if proof.ace.z.len() != CompDLEq::<C>::final_len(pp.poly_ck.g.len(), pp.threshold)
+            || proof.ace.omega.len() != pp.poly_ck.h.len()
+            || proof.ace.omega_hat.len() != pp.coeff_ck.h.len()
+        {
+            return Ok(false);
         }
 
         let verifier_timer = start_timer!(|| "CompAmComEq::Verify");
 
-        let powers_of_x = AmComEq::compute_powers_of_x(instance, transcript);
-        let challenge = AmComEq::compute_e(&proof.ace.commitment, transcript);
+        let coefficients = AmComEq::compute_coefficients(instance, transcript)?;
+        let Ok(challenge) = AmComEq::compute_e(&proof.ace.commitment, transcript) else {
+            return Ok(false);
+        };
 
         let (pp, instance, witness) =
-            Self::prepare_for_comp_dl_eq(pp, instance, &proof.ace, &powers_of_x, challenge)?;
+            Self::prepare_for_comp_dl_eq(pp, instance, &proof.ace, &coefficients, challenge)?;
 
         let cde_proof = CDEProof {
             commitments: proof.commitments.clone(),
@@ -0,0 +1,43 @@
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+pub use crate::linker::com_pok::{Instance, PublicParameters, Witness};
+use crate::solidity::Solidity;
+
+/// Cross terms of a folding round, `left = <g_R, z_L>` and `right = <g_L, z_R>`.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<C: CurveGroup> {
+    pub left: C::Affine,
+    pub right: C::Affine,
+}
+
+impl<C: CurveGroup> Solidity for Commitment<C>
+where
+    C::Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [self.left.to_solidity(), self.right.to_solidity()].concat()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: CurveGroup> {
+    pub a: C::Affine,
+    pub commitments: Vec<Commitment<C>>,
+    pub z: Vec<C::ScalarField>,
+}
+
+impl<C: CurveGroup> Solidity for Proof<C>
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [
+            self.z.to_solidity(),
+            self.a.to_solidity(),
+            self.commitments.to_solidity(),
+        ]
+        .concat()
+    }
+}
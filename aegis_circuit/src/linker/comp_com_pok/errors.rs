@@ -0,0 +1,21 @@
+use crate::linker::{com_pok::ComPoKError, comp_dl_eq::CompDLEqError};
+
+#[derive(Debug, PartialEq)]
+pub enum CompComPoKError {
+    /// Failure of the ComPoK step.
+    ComPoK(ComPoKError),
+    /// Failure of the folding step, checked as in CompDLEq.
+    CompDLEq(CompDLEqError),
+}
+
+impl From<ComPoKError> for CompComPoKError {
+    fn from(e: ComPoKError) -> Self {
+        CompComPoKError::ComPoK(e)
+    }
+}
+
+impl From<CompDLEqError> for CompComPoKError {
+    fn from(e: CompDLEqError) -> Self {
+        CompComPoKError::CompDLEq(e)
+    }
+}
@@ -0,0 +1,55 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{CryptoRng, RngCore};
+
+mod data_structure;
+pub use data_structure::*;
+
+mod errors;
+pub use errors::CompComPoKError;
+
+mod prover;
+mod verifier;
+
+use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
+
+/// [`ComPoK`](crate::linker::com_pok::ComPoK) with the response `z` compressed by CompDLEq
+/// folding, so the proof is logarithmic in the length of the openings.
+///
+/// There is a single statement `<g, z> = a + Σ ρ_i · c_i`, so the fold is one-sided: every round
+/// sends `left` and `right` only, with the halving and the unrolled final check of CompDLEq.
+pub struct CompComPoK<C: CurveGroup> {
+    _group: PhantomData<C>,
+}
+
+impl<C: CurveGroup> SigmaProtocol for CompComPoK<C> {
+    type PublicParameters = PublicParameters<C>;
+    type Instance = Instance<C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+    type Error = CompComPoKError;
+
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Ok(pp.clone())
+    }
+
+    fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error> {
+        Self::create_proof(pp, instance, witness, transcript, rng)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        Self::verify_proof(pp, instance, proof, transcript)
+    }
+}
@@ -0,0 +1,83 @@
+use ark_ec::CurveGroup;
+use ark_std::rand::{CryptoRng, RngCore};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol,
+    linker::{
+        com_pok::ComPoK,
+        comp_dl_eq::{self, CompDLEq, CompDLEqError},
+    },
+};
+
+use super::{data_structure::*, CompComPoK, CompComPoKError};
+
+impl<C: CurveGroup> CompComPoK<C> {
+    pub fn create_proof<R: RngCore + CryptoRng>(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+        transcript: &mut impl TranscriptProtocol,
+        rng: &mut R,
+    ) -> Result<Proof<C>, CompComPoKError> {
+        let prover_timer = start_timer!(|| "CompComPoK::Prover");
+
+        ComPoK::check_witness(pp, instance, witness)?;
+        CompDLEq::<C>::check_threshold(pp.threshold)?;
+        let (r, a) = ComPoK::create_random_commitment(pp, rng)?;
+        let coefficients = ComPoK::compute_coefficients(instance, &a, transcript)?;
+        let mut witness = comp_dl_eq::Witness::<C> {
+            z: ComPoK::compute_response(&r, witness, &coefficients),
+        };
+
+        let mut commitments = vec![];
+        let mut g = pp.g.clone();
+        while g.len() > pp.threshold {
+            let commitment = Self::compute_round_commitment(&g, &witness.z)?;
+            let challenge = Self::compute_challenge(&commitment, transcript)?;
+            g = Self::fold_generators(&g, challenge);
+            witness = CompDLEq::update_witness(&witness, challenge)?;
+            commitments.push(commitment);
+        }
+
+        end_timer!(prover_timer);
+        Ok(Proof {
+            a,
+            commitments,
+            z: witness.z,
+        })
+    }
+
+    /// Cross terms `<g_R, z_L>` and `<g_L, z_R>` of the halves split as in CompDLEq.
+    pub fn compute_round_commitment(
+        g: &[C::Affine],
+        z: &[C::ScalarField],
+    ) -> Result<Commitment<C>, CompDLEqError> {
+        if z.len() != g.len() {
+            return Err(CompDLEqError::MismatchParameterLength);
+        }
+        let (mid, right_len) = CompDLEq::<C>::split(z.len());
+        let left = C::msm_unchecked(&g[mid..], &z[..right_len]);
+        let right = C::msm_unchecked(&g[..right_len], &z[mid..]);
+
+        Ok(Commitment {
+            left: left.into_affine(),
+            right: right.into_affine(),
+        })
+    }
+
+    /// `g'_i = x · g_i + g_{mid + i}`, so that `<g', z_L + x · z_R> = x · y + left + x^2 · right`.
+    pub fn fold_generators(g: &[C::Affine], challenge: C::ScalarField) -> Vec<C::Affine> {
+        let (mid, right_len) = CompDLEq::<C>::split(g.len());
+        let g = cfg_iter!(g[..mid])
+            .enumerate()
+            .map(|(i, l)| match i < right_len {
+                true => *l * challenge + g[mid + i],
+                false => *l * challenge,
+            })
+            .collect::<Vec<_>>();
+        C::normalize_batch(&g)
+    }
+}
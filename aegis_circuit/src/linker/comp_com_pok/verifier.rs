@@ -0,0 +1,79 @@
+use ark_ec::{AffineRepr, CurveGroup};
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol,
+    linker::{
+        com_pok::ComPoK,
+        comp_dl_eq::{CompDLEq, CompDLEqError},
+        points_to_bytes,
+    },
+};
+
+use super::{data_structure::*, CompComPoK, CompComPoKError};
+
+impl<C: CurveGroup> CompComPoK<C> {
+    pub fn verify_proof<T: TranscriptProtocol>(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<bool, CompComPoKError> {
+        CompDLEq::<C>::check_threshold(pp.threshold)?;
+        if proof.a.is_zero()
+            || proof.commitments.len() != CompDLEq::<C>::num_rounds(pp.g.len(), pp.threshold)
+            || proof.z.len() != CompDLEq::<C>::final_len(pp.g.len(), pp.threshold)
+        {
+            return Ok(false);
+        }
+
+        let verifier_timer = start_timer!(|| "CompComPoK::Verifier");
+
+        let coefficients = ComPoK::compute_coefficients(instance, &proof.a, transcript)?;
+        let y = ComPoK::compute_expected(instance, &proof.a, &coefficients).into_affine();
+        let mut challenges = Vec::with_capacity(proof.commitments.len());
+        for commitment in proof.commitments.iter() {
+            let Ok(challenge) = Self::compute_challenge(commitment, transcript) else {
+                return Ok(false);
+            };
+            challenges.push(challenge);
+        }
+        let result = Self::check_folded(pp, &y, &proof.commitments, &challenges, &proof.z);
+
+        end_timer!(verifier_timer);
+        Ok(result)
+    }
+
+    /// Check `<g_k, z> = y_k` of a proof with the expected lengths, unrolled onto the original
+    /// generators and the round commitments in one MSM.
+    pub fn check_folded(
+        pp: &PublicParameters<C>,
+        y: &C::Affine,
+        commitments: &[Commitment<C>],
+        challenges: &[C::ScalarField],
+        z: &[C::ScalarField],
+    ) -> bool {
+        let z = CompDLEq::<C>::expand_response(pp.g.len(), challenges, z);
+        let (first, suffix) = CompDLEq::<C>::instance_scalars(challenges);
+        let mut points = vec![*y];
+        let mut scalars = vec![-first];
+        for ((commitment, x), p) in commitments.iter().zip(challenges).zip(suffix) {
+            points.extend([commitment.left, commitment.right]);
+            scalars.extend([-p, -p * x * x]);
+        }
+
+        let bases = [&pp.g[..], &points].concat();
+        let scalars = [z, scalars].concat();
+        C::msm_unchecked(&bases, &scalars).is_zero()
+    }
+
+    pub fn compute_challenge(
+        commitment: &Commitment<C>,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<C::ScalarField, CompDLEqError> {
+        let bytes = points_to_bytes(&[commitment.left, commitment.right])
+            .ok_or(CompDLEqError::IdentityPoint)?;
+
+        transcript.append(b"commitments", &bytes[..]);
+        Ok(transcript.challenge_scalar(b"challenge"))
+    }
+}
@@ -1,12 +1,71 @@
 use ark_ec::CurveGroup;
+use ark_ff::{One, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::solidity::Solidity;
 
 #[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct PublicParameters<C: CurveGroup> {
-    pub g: Vec<C::Affine>,
-    pub g_hat: Vec<C::Affine>,
+    pub g: ScaledGenerators<C>,
+    pub g_hat: ScaledGenerators<C>,
+    /// Folding stops once at most `threshold` generators remain, and the prover sends the
+    /// remaining response in the clear
+    pub threshold: usize,
+}
+
+/// Generators kept implicit as `g[j] = scalars[j] · bases[j mod |bases|]`,
+/// e.g. the coefficient key scaled by the linker coefficients, so the verifier never materializes
+/// them.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ScaledGenerators<C: CurveGroup> {
+    pub bases: Vec<C::Affine>,
+    pub scalars: Vec<C::ScalarField>,
+}
+
+impl<C: CurveGroup> ScaledGenerators<C> {
+    pub fn len(&self) -> usize {
+        self.scalars.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Explicit generators, for the prover who folds them.
+    pub fn materialize(&self) -> Vec<C::Affine> {
+        if self.bases.len() == self.scalars.len() && self.scalars.iter().all(|s| s.is_one()) {
+            return self.bases.clone();
+        }
+        let d = self.bases.len();
+        let g = cfg_iter!(self.scalars)
+            .enumerate()
+            .map(|(j, s)| self.bases[j % d] * s)
+            .collect::<Vec<_>>();
+        C::normalize_batch(&g)
+    }
+
+    /// Scalars on the bases with `<bases, collapse(z)> = <g, z>`.
+    pub fn collapse(&self, z: &[C::ScalarField]) -> Vec<C::ScalarField> {
+        let d = self.bases.len();
+        let mut scalars = vec![C::ScalarField::zero(); d];
+        for (j, (s, z)) in self.scalars.iter().zip(z).enumerate() {
+            scalars[j % d] += *s * z;
+        }
+        scalars
+    }
+}
+
+/// Explicit generators, each scaled by one.
+impl<C: CurveGroup> From<Vec<C::Affine>> for ScaledGenerators<C> {
+    fn from(bases: Vec<C::Affine>) -> Self {
+        Self {
+            scalars: vec![C::ScalarField::one(); bases.len()],
+            bases,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
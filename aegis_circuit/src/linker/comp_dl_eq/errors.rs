@@ -0,0 +1,11 @@
+#[derive(Debug, PartialEq)]
+pub enum CompDLEqError {
+    /// `g`, `g_hat` and the witness do not have the same length.
+    MismatchParameterLength,
+    /// The fold threshold is zero.
+    InvalidThreshold,
+    /// A point appended to the transcript is the identity.
+    IdentityPoint,
+    /// The proof is malformed.
+    InvalidProof,
+}
@@ -1,46 +1,41 @@
-use ark_ec::{AffineRepr, CurveGroup};
-use ark_std::Zero;
+use ark_ec::CurveGroup;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use super::{data_structure::*, CompDLEq};
+use super::{data_structure::*, CompDLEq, CompDLEqError};
 
 impl<C: CurveGroup> CompDLEq<C> {
-    /// Prepare public parameters for the protocol.
-    /// if g and g_hat are not of the same length, return an error.
-    /// Otherwise, return the public parameters with an extended power-of-two length for g and g_hat.
-    pub fn prepare_public_parameters(pp: &PublicParameters<C>) -> Result<PublicParameters<C>, ()> {
-        if pp.g.len() != pp.g_hat.len() {
-            return Err(());
+    /// Check that `g` and `g_hat` have the same length, that non-empty generators have bases,
+    /// and that the threshold is positive. No padding is needed: odd lengths are folded directly.
+    pub fn check_public_parameters(pp: &PublicParameters<C>) -> Result<(), CompDLEqError> {
+        if pp.g.len() != pp.g_hat.len()
+            || (!pp.g.is_empty() && (pp.g.bases.is_empty() || pp.g_hat.bases.is_empty()))
+        {
+            return Err(CompDLEqError::MismatchParameterLength);
         }
-
-        let diff = Self::rescale_size(pp.g.len()) - pp.g.len();
-
-        Ok(PublicParameters {
-            g: vec![pp.g.clone(), vec![C::Affine::generator(); diff]].concat(),
-            g_hat: vec![pp.g_hat.clone(), vec![C::Affine::generator(); diff]].concat(),
-        })
+        Self::check_threshold(pp.threshold)
     }
 
-    pub fn prepare_witness(witness: &Witness<C>) -> Result<Witness<C>, ()> {
-        let diff = Self::rescale_size(witness.z.len()) - witness.z.len();
-
-        Ok(Witness {
-            z: vec![witness.z.clone(), vec![C::ScalarField::zero(); diff]].concat(),
-        })
+    /// Check that the threshold is positive, as folding cannot go below one generator.
+    pub fn check_threshold(threshold: usize) -> Result<(), CompDLEqError> {
+        if threshold == 0 {
+            return Err(CompDLEqError::InvalidThreshold);
+        }
+        Ok(())
     }
 
-    /// Both the prover and verifier need to update the public parameters
-    pub fn update_public_parameters_and_instance(
-        pp: &PublicParameters<C>,
+    /// Fold `g` and the instance with the challenge; `g_hat` is folded lazily by
+    /// [`LazyGenerators`].
+    pub fn update_generators_and_instance(
+        g: &[C::Affine],
         instance: &Instance<C>,
         commitment: &Commitment<C>,
         challenge: C::ScalarField,
-    ) -> Result<(PublicParameters<C>, Instance<C>), ()> {
+    ) -> Result<(Vec<C::Affine>, Instance<C>), CompDLEqError> {
         let update_timer = start_timer!(|| "CompDLEq::Update (Fold)");
 
-        let mid = pp.g.len() / 2;
+        let (mid, right_len) = Self::split(g.len());
         let sqr_challenge = challenge * challenge;
 
         let expected_timer = start_timer!(|| "Update expected");
@@ -50,25 +45,18 @@ impl<C: CurveGroup> CompDLEq<C> {
         end_timer!(expected_timer);
 
         let g_timer = start_timer!(|| "Update G");
-        let g = cfg_iter!(pp.g[..mid])
-            .zip(&pp.g[mid..])
-            .map(|(l, r)| *l * challenge + r)
+        let g = cfg_iter!(g[..mid])
+            .enumerate()
+            .map(|(i, l)| match i < right_len {
+                true => *l * challenge + g[mid + i],
+                false => *l * challenge,
+            })
             .collect::<Vec<_>>();
         end_timer!(g_timer);
-
-        let g_hat_timer = start_timer!(|| "Update G Hat");
-        let g_hat = cfg_iter!(pp.g_hat[..mid])
-            .zip(&pp.g_hat[mid..])
-            .map(|(l, r)| *l * challenge + r)
-            .collect::<Vec<_>>();
-        end_timer!(g_hat_timer);
         end_timer!(update_timer);
 
         Ok((
-            PublicParameters {
-                g: C::normalize_batch(&g),
-                g_hat: C::normalize_batch(&g_hat),
-            },
+            C::normalize_batch(&g),
             Instance {
                 y: y.into_affine(),
                 y_hat: y_hat.into_affine(),
@@ -76,11 +64,93 @@ impl<C: CurveGroup> CompDLEq<C> {
         ))
     }
 
-    fn rescale_size(l: usize) -> usize {
-        if l.is_power_of_two() {
-            l
-        } else {
-            l.next_power_of_two()
+    /// Number of folding rounds until at most `threshold` generators remain, with a zero
+    /// threshold counted as one.
+    pub fn num_rounds(l: usize, threshold: usize) -> usize {
+        let mut rounds = 0;
+        let mut l = l;
+        while l > threshold.max(1) {
+            l = Self::split(l).0;
+            rounds += 1;
         }
+        rounds
+    }
+
+    /// Length of the final response after folding `l` generators down to `threshold`, with a
+    /// zero threshold counted as one.
+    pub fn final_len(l: usize, threshold: usize) -> usize {
+        let mut l = l;
+        while l > threshold.max(1) {
+            l = Self::split(l).0;
+        }
+        l
+    }
+
+    /// Split a vector of length `l` into a left half of `⌈l / 2⌉` and a right half of `⌊l / 2⌋`.
+    /// For odd `l` the last left element has no partner and is only scaled.
+    pub fn split(l: usize) -> (usize, usize) {
+        (l.div_ceil(2), l / 2)
+    }
+}
+
+/// [`ScaledGenerators`] during folding: the `p`-th current generator is
+/// `Σ_{j: positions_j = p} weights_j · bases[j mod |bases|]`, with `weights_j` starting at the
+/// scalar of the original generator `j`. Folding only updates the weights and positions.
+#[derive(Clone)]
+pub struct LazyGenerators<C: CurveGroup> {
+    bases: Vec<C::Affine>,
+    weights: Vec<C::ScalarField>,
+    positions: Vec<usize>,
+    len: usize,
+}
+
+impl<C: CurveGroup> LazyGenerators<C> {
+    pub fn new(g_hat: &ScaledGenerators<C>) -> Self {
+        Self {
+            bases: g_hat.bases.clone(),
+            weights: g_hat.scalars.clone(),
+            positions: (0..g_hat.len()).collect(),
+            len: g_hat.len(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// `<g_hat[offset..offset + |z|], z>` with a single MSM over the bases.
+    pub fn inner_product(&self, offset: usize, z: &[C::ScalarField]) -> C {
+        let d = self.bases.len();
+        let n = self.weights.len();
+        let scalars = cfg_into_iter!(0..d)
+            .map(|b| {
+                (b..n)
+                    .step_by(d)
+                    .filter_map(|j| {
+                        let p = self.positions[j];
+                        (offset..offset + z.len())
+                            .contains(&p)
+                            .then(|| self.weights[j] * z[p - offset])
+                    })
+                    .sum::<C::ScalarField>()
+            })
+            .collect::<Vec<_>>();
+        C::msm_unchecked(&self.bases, &scalars)
+    }
+
+    /// `g_hat' = challenge · g_hat_L + g_hat_R`
+    pub fn fold(&mut self, challenge: C::ScalarField) {
+        let (mid, _) = CompDLEq::<C>::split(self.len);
+        cfg_iter_mut!(self.weights)
+            .zip(cfg_iter_mut!(self.positions))
+            .for_each(|(w, p)| match *p < mid {
+                true => *w *= challenge,
+                false => *p -= mid,
+            });
+        self.len = mid;
     }
 }
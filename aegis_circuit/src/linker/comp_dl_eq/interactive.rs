@@ -0,0 +1,164 @@
+use ark_ec::CurveGroup;
+use ark_ff::UniformRand;
+use ark_std::rand::{CryptoRng, RngCore};
+
+use crate::crypto::protocol::interactive::{
+    decode, encode, InteractiveError, InteractiveProver, InteractiveVerifier, Move,
+};
+
+use super::{
+    Commitment, CompDLEq, CompDLEqError, Instance, LazyGenerators, PublicParameters, Witness,
+};
+
+/// Interactive CompDLEq prover: it opens every folding round with a [`Commitment`], folds with
+/// the challenge it receives, and sends the final response `z` once at most `threshold`
+/// generators remain.
+pub struct Prover<C: CurveGroup> {
+    threshold: usize,
+    g: Vec<C::Affine>,
+    g_hat: LazyGenerators<C>,
+    instance: Instance<C>,
+    witness: Witness<C>,
+    /// Commitment of the current round, waiting for its challenge
+    commitment: Option<Commitment<C>>,
+    finished: bool,
+}
+
+impl<C: CurveGroup> Prover<C> {
+    pub fn new(
+        pp: &PublicParameters<C>,
+        instance: Instance<C>,
+        witness: Witness<C>,
+    ) -> Result<Self, CompDLEqError> {
+        CompDLEq::check_public_parameters(pp)?;
+        if witness.z.len() != pp.g.len() {
+            return Err(CompDLEqError::MismatchParameterLength);
+        }
+        Ok(Self {
+            threshold: pp.threshold,
+            g: pp.g.materialize(),
+            g_hat: LazyGenerators::new(&pp.g_hat),
+            instance,
+            witness,
+            commitment: None,
+            finished: false,
+        })
+    }
+}
+
+impl<C: CurveGroup> InteractiveProver for Prover<C> {
+    type Error = CompDLEqError;
+
+    /// The first challenge is empty; every later one folds the previous round.
+    fn next_message<R: RngCore + CryptoRng>(
+        &mut self,
+        challenge: &[u8],
+        _: &mut R,
+    ) -> Result<Vec<u8>, InteractiveError<Self::Error>> {
+        if self.finished {
+            return Err(InteractiveError::OutOfOrder);
+        }
+        if let Some(commitment) = &self.commitment {
+            let challenge =
+                decode::<C::ScalarField>(challenge).ok_or(InteractiveError::MalformedChallenge)?;
+            (self.g, self.instance) = CompDLEq::update_generators_and_instance(
+                &self.g,
+                &self.instance,
+                commitment,
+                challenge,
+            )
+            .map_err(InteractiveError::Protocol)?;
+            self.g_hat.fold(challenge);
+            self.witness = CompDLEq::update_witness(&self.witness, challenge)
+                .map_err(InteractiveError::Protocol)?;
+        }
+
+        if self.g.len() > self.threshold {
+            let commitment = CompDLEq::compute_depth_commitment_from_updated_parameters(
+                &self.g,
+                &self.g_hat,
+                &self.witness,
+            )
+            .map_err(InteractiveError::Protocol)?;
+            let message = encode(&commitment);
+            self.commitment = Some(commitment);
+            Ok(message)
+        } else {
+            self.commitment = None;
+            self.finished = true;
+            Ok(encode(&self.witness.z))
+        }
+    }
+}
+
+/// Interactive CompDLEq verifier, sampling a folding challenge per round and the weight of the
+/// final check.
+pub struct Verifier<C: CurveGroup> {
+    pp: PublicParameters<C>,
+    instance: Instance<C>,
+    commitments: Vec<Commitment<C>>,
+    challenges: Vec<C::ScalarField>,
+    started: bool,
+    finished: bool,
+}
+
+impl<C: CurveGroup> Verifier<C> {
+    pub fn new(pp: PublicParameters<C>, instance: Instance<C>) -> Result<Self, CompDLEqError> {
+        CompDLEq::check_public_parameters(&pp)?;
+        Ok(Self {
+            pp,
+            instance,
+            commitments: vec![],
+            challenges: vec![],
+            started: false,
+            finished: false,
+        })
+    }
+}
+
+impl<C: CurveGroup> InteractiveVerifier for Verifier<C> {
+    type Error = CompDLEqError;
+
+    fn next_move<R: RngCore + CryptoRng>(
+        &mut self,
+        message: Option<&[u8]>,
+        rng: &mut R,
+    ) -> Result<Move, InteractiveError<Self::Error>> {
+        let message = match (self.started, self.finished, message) {
+            (false, _, None) => {
+                self.started = true;
+                return Ok(Move::Challenge(vec![]));
+            }
+            (true, false, Some(message)) => message,
+            _ => return Err(InteractiveError::OutOfOrder),
+        };
+
+        let n = self.pp.g.len();
+        if self.commitments.len() < CompDLEq::<C>::num_rounds(n, self.pp.threshold) {
+            let Some(commitment) = decode::<Commitment<C>>(message) else {
+                self.finished = true;
+                return Ok(Move::Decision(false));
+            };
+            let challenge = C::ScalarField::rand(rng);
+            self.commitments.push(commitment);
+            self.challenges.push(challenge);
+            return Ok(Move::Challenge(encode(&challenge)));
+        }
+
+        self.finished = true;
+        let Some(z) = decode::<Vec<C::ScalarField>>(message)
+            .filter(|z| z.len() == CompDLEq::<C>::final_len(n, self.pp.threshold))
+        else {
+            return Ok(Move::Decision(false));
+        };
+        let weight = C::ScalarField::rand(rng);
+        Ok(Move::Decision(CompDLEq::check_folded(
+            &self.pp,
+            &self.instance,
+            &self.commitments,
+            &self.challenges,
+            &z,
+            weight,
+        )))
+    }
+}
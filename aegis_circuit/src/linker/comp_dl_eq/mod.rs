@@ -6,12 +6,20 @@ use ark_std::rand::{CryptoRng, RngCore};
 mod data_structure;
 pub use data_structure::*;
 
+mod errors;
+pub use errors::CompDLEqError;
+
 mod generator;
+pub use generator::LazyGenerators;
+pub mod interactive;
 mod prover;
 mod verifier;
 
 use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
 
+/// Default fold threshold, leaving a response of at most two scalars.
+pub const DEFAULT_THRESHOLD: usize = 2;
+
 pub struct CompDLEq<C: CurveGroup> {
     _group: PhantomData<C>,
 }
@@ -21,10 +29,11 @@ impl<C: CurveGroup> SigmaProtocol for CompDLEq<C> {
     type Instance = Instance<C>;
     type Witness = Witness<C>;
     type Proof = Proof<C>;
-    type Error = ();
+    type Error = CompDLEqError;
 
-    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, ()> {
-        Self::prepare_public_parameters(pp)
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Self::check_public_parameters(pp)?;
+        Ok(pp.clone())
     }
 
     fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
@@ -33,7 +42,7 @@ impl<C: CurveGroup> SigmaProtocol for CompDLEq<C> {
         witness: &Self::Witness,
         transcript: &mut T,
         _: &mut R,
-    ) -> Result<Self::Proof, ()> {
+    ) -> Result<Self::Proof, Self::Error> {
         Self::create_proof(pp, instance, witness, transcript)
     }
 
@@ -42,7 +51,7 @@ impl<C: CurveGroup> SigmaProtocol for CompDLEq<C> {
         instance: &Self::Instance,
         proof: &Self::Proof,
         transcript: &mut T,
-    ) -> Result<bool, ()> {
+    ) -> Result<bool, Self::Error> {
         Self::verify_proof(pp, instance, proof, transcript)
     }
 }
@@ -6,32 +6,33 @@ use rayon::prelude::*;
 
 use crate::crypto::protocol::transcript::TranscriptProtocol;
 
-use super::{data_structure::*, CompDLEq};
+use super::{data_structure::*, CompDLEq, CompDLEqError, LazyGenerators};
 
 impl<C: CurveGroup> CompDLEq<C> {
     pub fn compute_depth_commitment_from_updated_parameters(
-        pp: &PublicParameters<C>,
+        g: &[C::Affine],
+        g_hat: &LazyGenerators<C>,
         witness: &Witness<C>,
-    ) -> Result<Commitment<C>, ()> {
-        if witness.z.len() != pp.g.len() || witness.z.len() != pp.g_hat.len() {
-            return Err(());
+    ) -> Result<Commitment<C>, CompDLEqError> {
+        if witness.z.len() != g.len() || witness.z.len() != g_hat.len() {
+            return Err(CompDLEqError::MismatchParameterLength);
         }
         let commit_timer = start_timer!(|| "CompDLEq::Depth Commit");
-        let mid = witness.z.len() / 2;
+        let (mid, right_len) = Self::split(witness.z.len());
 
         let z_bigint = cfg_iter!(witness.z)
             .map(|z| z.into_bigint())
             .collect::<Vec<_>>();
 
         let lr_timer = start_timer!(|| "Compute LR");
-        let left = C::msm_bigint(&pp.g[mid..], &z_bigint[..mid]);
-        let right = C::msm_bigint(&pp.g[..mid], &z_bigint[mid..]);
+        let left = C::msm_bigint(&g[mid..], &z_bigint[..right_len]);
+        let right = C::msm_bigint(&g[..right_len], &z_bigint[mid..]);
 
         end_timer!(lr_timer);
 
         let lr_hat_timer = start_timer!(|| "Compute LR Hat");
-        let left_hat = C::msm_bigint(&pp.g_hat[mid..], &z_bigint[..mid]);
-        let right_hat = C::msm_bigint(&pp.g_hat[..mid], &z_bigint[mid..]);
+        let left_hat = g_hat.inner_product(mid, &witness.z[..right_len]);
+        let right_hat = g_hat.inner_product(0, &witness.z[mid..]);
 
         end_timer!(lr_hat_timer);
         end_timer!(commit_timer);
@@ -49,12 +50,15 @@ impl<C: CurveGroup> CompDLEq<C> {
     pub fn update_witness(
         witness: &Witness<C>,
         challenge: C::ScalarField,
-    ) -> Result<Witness<C>, ()> {
+    ) -> Result<Witness<C>, CompDLEqError> {
         let witness_timer = start_timer!(|| "Update witness");
-        let mid = witness.z.len() / 2;
+        let (mid, right_len) = Self::split(witness.z.len());
         let z = cfg_iter!(witness.z[..mid])
-            .zip(&witness.z[mid..])
-            .map(|(l, r)| *l + challenge * r)
+            .enumerate()
+            .map(|(i, l)| match i < right_len {
+                true => *l + challenge * witness.z[mid + i],
+                false => *l,
+            })
             .collect::<Vec<_>>();
         end_timer!(witness_timer);
 
@@ -66,29 +70,25 @@ impl<C: CurveGroup> CompDLEq<C> {
         instance: &Instance<C>,
         witness: &Witness<C>,
         transcript: &mut impl TranscriptProtocol,
-    ) -> Result<Proof<C>, ()> {
-        let threshold = 2;
-        if pp.g.len() != pp.g_hat.len() {
-            return Err(());
-        }
+    ) -> Result<Proof<C>, CompDLEqError> {
+        Self::check_public_parameters(pp)?;
 
         let prover_timer = start_timer!(|| "CompDLEq::Prover");
         let mut commitments = vec![];
 
-        let mut pp = pp.clone();
+        let mut g = pp.g.materialize();
+        let mut g_hat = LazyGenerators::new(&pp.g_hat);
         let mut instance = instance.clone();
-        let mut witness = Self::prepare_witness(witness)?;
-        while pp.g.len() > threshold {
-            let commitment = Self::compute_depth_commitment_from_updated_parameters(&pp, &witness)?;
-
-            let challenge = Self::compute_challenge(&commitment, transcript);
-
-            (pp, instance) = Self::update_public_parameters_and_instance(
-                &pp,
-                &instance,
-                &commitment,
-                challenge,
-            )?;
+        let mut witness = witness.clone();
+        while g.len() > pp.threshold {
+            let commitment =
+                Self::compute_depth_commitment_from_updated_parameters(&g, &g_hat, &witness)?;
+
+            let challenge = Self::compute_challenge(&commitment, transcript)?;
+
+            (g, instance) =
+                Self::update_generators_and_instance(&g, &instance, &commitment, challenge)?;
+            g_hat.fold(challenge);
             witness = Self::update_witness(&witness, challenge)?;
             commitments.push(commitment);
         }
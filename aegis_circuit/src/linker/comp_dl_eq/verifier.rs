@@ -1,12 +1,10 @@
-use ark_ec::{AffineRepr, CurveGroup};
+use ark_ec::CurveGroup;
+use ark_ff::One;
 use ark_serialize::CanonicalSerialize;
 
-use crate::crypto::protocol::transcript::TranscriptProtocol;
+use crate::{crypto::protocol::transcript::TranscriptProtocol, linker::points_to_bytes};
 
-#[cfg(feature = "parallel")]
-use rayon::prelude::*;
-
-use super::{Commitment, CompDLEq, Instance, Proof, PublicParameters};
+use super::{Commitment, CompDLEq, CompDLEqError, Instance, Proof, PublicParameters};
 
 impl<C: CurveGroup> CompDLEq<C> {
     pub fn verify_proof(
@@ -14,57 +12,135 @@ impl<C: CurveGroup> CompDLEq<C> {
         instance: &Instance<C>,
         proof: &Proof<C>,
         transcript: &mut impl TranscriptProtocol,
-    ) -> Result<bool, ()> {
-        let threshold = 2;
-        if proof.z.len() != threshold || pp.g.len() != pp.g_hat.len() {
-            return Err(());
+    ) -> Result<bool, CompDLEqError> {
+        Self::check_public_parameters(pp)?;
+        if proof.commitments.len() != Self::num_rounds(pp.g.len(), pp.threshold)
+            || proof.z.len() != Self::final_len(pp.g.len(), pp.threshold)
+        {
+            return Ok(false);
         }
 
         let verifier_timer = start_timer!(|| "CompDLEq::Verifier");
-        let mut pp = pp.clone();
-        let mut instance = instance.clone();
-        proof.commitments.iter().for_each(|commitment| {
-            let challenge = Self::compute_challenge(commitment, transcript);
+        let mut challenges = Vec::with_capacity(proof.commitments.len());
+        for commitment in proof.commitments.iter() {
+            let Ok(challenge) = Self::compute_challenge(commitment, transcript) else {
+                return Ok(false);
+            };
+            challenges.push(challenge);
+        }
 
-            (pp, instance) =
-                Self::update_public_parameters_and_instance(&pp, &instance, commitment, challenge)
-                    .unwrap();
-        });
+        // both final equations under a weight bound to the whole proof; the fork leaves the
+        // transcript as the prover left it
+        let mut fork = transcript.fork(b"CompDLEq::final");
+        let mut bytes = vec![];
+        let Ok(()) = proof.z.serialize_uncompressed(&mut bytes) else {
+            return Ok(false);
+        };
+        fork.append(b"response", &bytes);
+        let weight: C::ScalarField = fork.challenge_scalar(b"weight");
 
-        if proof.z.len() != pp.g.len() {
-            return Err(());
-        }
-        let y_real = (pp.g[0] * proof.z[0] + pp.g[1] * proof.z[1]).into_affine();
-        let y_hat_real = (pp.g_hat[0] * proof.z[0] + pp.g_hat[1] * proof.z[1]).into_affine();
+        let result = Self::check_folded(
+            pp,
+            instance,
+            &proof.commitments,
+            &challenges,
+            &proof.z,
+            weight,
+        );
         end_timer!(verifier_timer);
 
-        Ok(instance.y == y_real && instance.y_hat == y_hat_real)
+        Ok(result)
+    }
+
+    /// Check both folded equations `<g_k, z> = y_k` and `<g_hat_k, z> = y_hat_k` of a proof with
+    /// the expected lengths, combined under `weight` into one MSM.
+    pub fn check_folded(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        commitments: &[Commitment<C>],
+        challenges: &[C::ScalarField],
+        z: &[C::ScalarField],
+        weight: C::ScalarField,
+    ) -> bool {
+        // <g_k, z> = y_k, unrolled onto the original generators and the round commitments
+        let z = Self::expand_response(pp.g.len(), challenges, z);
+        // both sides collapse onto their bases, `g_hat` under the weight
+        let g_scalars = pp.g.collapse(&z);
+        let g_hat_scalars = pp
+            .g_hat
+            .collapse(&z)
+            .into_iter()
+            .map(|s| s * weight)
+            .collect::<Vec<_>>();
+
+        let (first, suffix) = Self::instance_scalars(challenges);
+        let mut points = vec![instance.y, instance.y_hat];
+        let mut scalars = vec![-first, -first * weight];
+        for ((commitment, x), p) in commitments.iter().zip(challenges).zip(suffix) {
+            let sqr = *x * x;
+            points.extend([
+                commitment.left,
+                commitment.right,
+                commitment.left_hat,
+                commitment.right_hat,
+            ]);
+            scalars.extend([-p, -p * sqr, -p * weight, -p * sqr * weight]);
+        }
+
+        let bases = [&pp.g.bases[..], &pp.g_hat.bases, &points].concat();
+        let scalars = [g_scalars, g_hat_scalars, scalars].concat();
+        C::msm_unchecked(&bases, &scalars).is_zero()
     }
 
     pub fn compute_challenge(
         commitment: &Commitment<C>,
         transcript: &mut impl TranscriptProtocol,
-    ) -> C::ScalarField {
-        let commitment = vec![
+    ) -> Result<C::ScalarField, CompDLEqError> {
+        let bytes = points_to_bytes(&[
             commitment.left,
             commitment.right,
             commitment.left_hat,
             commitment.right_hat,
-        ];
-
-        let bytes = cfg_iter!(commitment)
-            .map(|p| {
-                let mut _bytes = vec![];
-                let (x, y) = p.xy().unwrap();
-                y.serialize_uncompressed(&mut _bytes).unwrap();
-                x.serialize_uncompressed(&mut _bytes).unwrap();
-                _bytes.reverse();
-                _bytes
-            })
-            .flatten()
-            .collect::<Vec<_>>();
+        ])
+        .ok_or(CompDLEqError::IdentityPoint)?;
 
         transcript.append(b"commitments", &bytes[..]);
-        transcript.challenge_scalar(b"challenge")
+        Ok(transcript.challenge_scalar(b"challenge"))
+    }
+
+    /// Scalars of the original generators in `<g_final, z>`, i.e. `s_j · z_{p_j}` where the
+    /// folded generator at `p_j` is `Σ s_j · g_j`; a generator in the left half of a round is
+    /// scaled by its challenge. Built from the last round outwards, in `O(n)`.
+    pub fn expand_response(
+        n: usize,
+        challenges: &[C::ScalarField],
+        z: &[C::ScalarField],
+    ) -> Vec<C::ScalarField> {
+        let mut lens = vec![n];
+        for _ in challenges {
+            lens.push(Self::split(*lens.last().unwrap()).0);
+        }
+
+        let mut scalars = z.to_vec();
+        for (challenge, len) in challenges.iter().zip(lens).rev() {
+            let (mid, right_len) = Self::split(len);
+            let left = scalars.iter().map(|s| *s * challenge);
+            scalars = left.chain(scalars[..right_len].iter().copied()).collect();
+            debug_assert_eq!(scalars.len(), mid + right_len);
+        }
+        scalars
+    }
+
+    /// Scalars `(P_0, [P_1, ..., P_k])` with `P_r = Π_{r' ≥ r} x_{r'}`, so the folded instance is
+    /// `y_k = P_0 · y + Σ_r P_{r+1} · (left_r + x_r^2 · right_r)`.
+    pub fn instance_scalars(
+        challenges: &[C::ScalarField],
+    ) -> (C::ScalarField, Vec<C::ScalarField>) {
+        let mut suffix = vec![C::ScalarField::one(); challenges.len() + 1];
+        for (r, challenge) in challenges.iter().enumerate().rev() {
+            suffix[r] = suffix[r + 1] * challenge;
+        }
+        let first = suffix.remove(0);
+        (first, suffix)
     }
 }
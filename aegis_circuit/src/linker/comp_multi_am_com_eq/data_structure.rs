@@ -0,0 +1,19 @@
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+pub use crate::linker::multi_am_com_eq::{CommittingKey, Instance, PublicParameters, Witness};
+pub(super) use crate::linker::{
+    comp_dl_eq::{
+        Commitment as RecursionCommitment, Instance as RecursionInstance, Proof as CDEProof,
+        PublicParameters as RecursionPublicParameters, ScaledGenerators,
+        Witness as RecursionWitness,
+    },
+    multi_am_com_eq::Proof as MACEProof,
+};
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: CurveGroup> {
+    pub commitments: Vec<RecursionCommitment<C>>,
+    /// MultiAmComEq proof whose `z` is the final CompDLEq response
+    pub mace: MACEProof<C>,
+}
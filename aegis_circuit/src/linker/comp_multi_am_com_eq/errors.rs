@@ -0,0 +1,21 @@
+use crate::linker::{comp_dl_eq::CompDLEqError, multi_am_com_eq::MultiAmComEqError};
+
+#[derive(Debug, PartialEq)]
+pub enum CompMultiAmComEqError {
+    /// Failure of the MultiAmComEq step.
+    MultiAmComEq(MultiAmComEqError),
+    /// Failure of the compressed CompDLEq step.
+    CompDLEq(CompDLEqError),
+}
+
+impl From<MultiAmComEqError> for CompMultiAmComEqError {
+    fn from(e: MultiAmComEqError) -> Self {
+        CompMultiAmComEqError::MultiAmComEq(e)
+    }
+}
+
+impl From<CompDLEqError> for CompMultiAmComEqError {
+    fn from(e: CompDLEqError) -> Self {
+        CompMultiAmComEqError::CompDLEq(e)
+    }
+}
@@ -0,0 +1,80 @@
+use ark_ec::CurveGroup;
+use ark_ff::One;
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol, linker::multi_am_com_eq::MultiAmComEq,
+};
+
+use super::{data_structure::*, CompMultiAmComEq, CompMultiAmComEqError};
+
+impl<C: CurveGroup> CompMultiAmComEq<C> {
+    /// Weights `σ` of the single-commitment equations with `σ_0 = 1`, so a single `c` is not
+    /// scaled.
+    pub fn compute_weights<T: TranscriptProtocol>(
+        k: usize,
+        transcript: &mut T,
+    ) -> Vec<C::ScalarField> {
+        let mut weights = vec![C::ScalarField::one()];
+        weights.extend(
+            transcript.challenge_scalars::<C::ScalarField>(b"weights", k.saturating_sub(1)),
+        );
+        weights
+    }
+
+    /// CompDLEq statement on `g = σ_0 · poly_ck_0.g || ... || σ_{k-1} · poly_ck_{k-1}.g` with
+    /// `y = Σ σ_t · (a_t + e · c_t - <h_t, ω_t>)`, and on the coefficient generators with
+    /// `y_hat = a_hat + e · Σ ρ_i · c_hat_i - Σ <h_hat_κ, Ω_κ>`.
+    pub fn prepare_for_comp_dl_eq(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &MACEProof<C>,
+        coefficients: &[C::ScalarField],
+        challenge: C::ScalarField,
+        weights: &[C::ScalarField],
+    ) -> Result<(RecursionPublicParameters<C>, RecursionInstance<C>), CompMultiAmComEqError> {
+        let prepare_timer = start_timer!(|| "Prepare for CompDLEq");
+
+        let g = ScaledGenerators {
+            bases: pp.poly_cks.iter().flat_map(|ck| ck.g.clone()).collect(),
+            scalars: (pp.poly_cks.iter())
+                .zip(weights)
+                .flat_map(|(ck, sigma)| vec![*sigma; ck.g.len()])
+                .collect(),
+        };
+        let g_hat = MultiAmComEq::coefficient_generators(pp, coefficients);
+
+        let y_timer = start_timer!(|| "Compute Y");
+        let y = (pp.poly_cks.iter())
+            .zip(&proof.omega)
+            .zip(&proof.commitment.a)
+            .zip(&instance.c)
+            .zip(weights)
+            .map(|((((ck, omega), a), c), sigma)| {
+                (*c * challenge + a - C::msm_unchecked(&ck.h, omega)) * sigma
+            })
+            .sum::<C>();
+        end_timer!(y_timer);
+
+        let y_hat_timer = start_timer!(|| "Compute Y Hat");
+        let y_hat = proof.commitment.a_hat
+            + C::msm_unchecked(&instance.c_hat, coefficients) * challenge
+            - (pp.coeff_cks.iter())
+                .zip(&proof.omega_hat)
+                .map(|(ck, omega_hat)| C::msm_unchecked(&ck.h, omega_hat))
+                .sum::<C>();
+        end_timer!(y_hat_timer);
+        end_timer!(prepare_timer);
+
+        Ok((
+            RecursionPublicParameters {
+                g,
+                g_hat,
+                threshold: pp.threshold,
+            },
+            RecursionInstance {
+                y: y.into_affine(),
+                y_hat: y_hat.into_affine(),
+            },
+        ))
+    }
+}
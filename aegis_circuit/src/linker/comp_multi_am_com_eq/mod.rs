@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_std::rand::{CryptoRng, RngCore};
+
+mod data_structure;
+pub use data_structure::*;
+
+mod errors;
+pub use errors::CompMultiAmComEqError;
+
+mod generator;
+mod prover;
+mod verifier;
+
+use crate::crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol};
+
+/// [`MultiAmComEq`](crate::linker::multi_am_com_eq::MultiAmComEq) with the response `z`
+/// compressed by CompDLEq. The `k` single-commitment equations are merged with transcript
+/// weights into one statement on the concatenated `poly_cks`.
+pub struct CompMultiAmComEq<C: CurveGroup> {
+    _group: PhantomData<C>,
+}
+
+impl<C: CurveGroup> SigmaProtocol for CompMultiAmComEq<C> {
+    type PublicParameters = PublicParameters<C>;
+    type Instance = Instance<C>;
+    type Witness = Witness<C>;
+    type Proof = Proof<C>;
+    type Error = CompMultiAmComEqError;
+
+    fn setup(pp: &Self::PublicParameters) -> Result<Self::PublicParameters, Self::Error> {
+        Ok(pp.clone())
+    }
+
+    fn prove<T: TranscriptProtocol, R: RngCore + CryptoRng>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        witness: &Self::Witness,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Self::Proof, Self::Error> {
+        Self::create_proof(pp, instance, witness, transcript, rng)
+    }
+
+    fn verify<T: TranscriptProtocol>(
+        pp: &Self::PublicParameters,
+        instance: &Self::Instance,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<bool, Self::Error> {
+        Self::verify_proof(pp, instance, proof, transcript)
+    }
+}
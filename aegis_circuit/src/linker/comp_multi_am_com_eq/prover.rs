@@ -0,0 +1,64 @@
+use ark_ec::CurveGroup;
+use ark_std::rand::{CryptoRng, RngCore};
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol,
+    linker::{comp_dl_eq::CompDLEq, multi_am_com_eq::MultiAmComEq},
+};
+
+use super::{data_structure::*, CompMultiAmComEq, CompMultiAmComEqError};
+
+impl<C: CurveGroup> CompMultiAmComEq<C> {
+    pub fn create_proof<R: RngCore + CryptoRng>(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+        transcript: &mut impl TranscriptProtocol,
+        rng: &mut R,
+    ) -> Result<Proof<C>, CompMultiAmComEqError> {
+        MultiAmComEq::check_instance(pp, instance)?;
+        MultiAmComEq::check_witness(pp, instance, witness)?;
+
+        let prover_timer = start_timer!(|| "CompMultiAmComEq::Prover");
+
+        let coefficients = MultiAmComEq::compute_coefficients(instance, transcript)?;
+        let (randomness, commitment) =
+            MultiAmComEq::create_random_commitment(pp, &coefficients, rng)?;
+        let challenge = MultiAmComEq::compute_e(&commitment, transcript)?;
+        let mace_proof = MultiAmComEq::create_proof_with_assignment(
+            pp,
+            witness,
+            &randomness,
+            &commitment,
+            &coefficients,
+            challenge,
+        )?;
+        let weights = Self::compute_weights(pp.poly_cks.len(), transcript);
+
+        let (cde_pp, cde_instance) = Self::prepare_for_comp_dl_eq(
+            pp,
+            instance,
+            &mace_proof,
+            &coefficients,
+            challenge,
+            &weights,
+        )?;
+        let cde_proof = CompDLEq::create_proof(
+            &cde_pp,
+            &cde_instance,
+            &RecursionWitness { z: mace_proof.z },
+            transcript,
+        )?;
+
+        end_timer!(prover_timer);
+        Ok(Proof {
+            commitments: cde_proof.commitments,
+            mace: MACEProof {
+                commitment,
+                z: cde_proof.z,
+                omega: mace_proof.omega,
+                omega_hat: mace_proof.omega_hat,
+            },
+        })
+    }
+}
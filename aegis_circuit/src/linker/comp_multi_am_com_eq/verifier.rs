@@ -0,0 +1,51 @@
+use ark_ec::CurveGroup;
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol,
+    linker::{comp_dl_eq::CompDLEq, multi_am_com_eq::MultiAmComEq},
+};
+
+use super::{data_structure::*, CompMultiAmComEq, CompMultiAmComEqError};
+
+impl<C: CurveGroup> CompMultiAmComEq<C> {
+    pub fn verify_proof<T: TranscriptProtocol>(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<bool, CompMultiAmComEqError> {
+        MultiAmComEq::check_instance(pp, instance)?;
+        CompDLEq::<C>::check_threshold(pp.threshold)?;
+        let n = pp.poly_cks.iter().map(|ck| ck.g.len()).sum::<usize>();
+        if proof.mace.z.len() != CompDLEq::<C>::final_len(n, pp.threshold)
+            || MultiAmComEq::check_openings(pp, &proof.mace).is_err()
+        {
+            return Ok(false);
+        }
+
+        let verifier_timer = start_timer!(|| "CompMultiAmComEq::Verify");
+
+        let coefficients = MultiAmComEq::compute_coefficients(instance, transcript)?;
+        let Ok(challenge) = MultiAmComEq::compute_e(&proof.mace.commitment, transcript) else {
+            return Ok(false);
+        };
+        let weights = Self::compute_weights(pp.poly_cks.len(), transcript);
+
+        let (cde_pp, cde_instance) = Self::prepare_for_comp_dl_eq(
+            pp,
+            instance,
+            &proof.mace,
+            &coefficients,
+            challenge,
+            &weights,
+        )?;
+        let cde_proof = CDEProof {
+            commitments: proof.commitments.clone(),
+            z: proof.mace.z.clone(),
+        };
+        let result = CompDLEq::verify_proof(&cde_pp, &cde_instance, &cde_proof, transcript)?;
+
+        end_timer!(verifier_timer);
+        Ok(result)
+    }
+}
@@ -0,0 +1,46 @@
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+pub use crate::linker::am_com_eq::CommittingKey;
+pub(super) use crate::linker::comp_dl_eq::{
+    Commitment as RecursionCommitment, Instance as RecursionInstance, Proof as CDEProof,
+    PublicParameters as RecursionPublicParameters, ScaledGenerators, Witness as RecursionWitness,
+};
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicParameters<C: CurveGroup> {
+    /// Key of the committed values, `c = <g, w> + <h, α>`
+    pub ck: CommittingKey<C>,
+    /// Key of the total, `T = t · g_0 + <h, τ>` with a single `g`; `h` is empty for a public total
+    pub total_ck: CommittingKey<C>,
+    /// Public weights `b` of the relation `<w, b> = t`, all one for a plain sum
+    pub weights: Vec<C::ScalarField>,
+    /// Fold threshold of the CompDLEq step
+    pub threshold: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Instance<C: CurveGroup> {
+    pub c: C::Affine,
+    /// Committed total `T`, or `t · g_0` for a public total `t`
+    pub total: C::Affine,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<C: CurveGroup> {
+    pub w: Vec<C::ScalarField>,
+    pub alpha: Vec<C::ScalarField>,
+    /// Opening of the total, empty for a public total
+    pub tau: Vec<C::ScalarField>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: CurveGroup> {
+    pub a: C::Affine,
+    pub a_hat: C::Affine,
+    pub commitments: Vec<RecursionCommitment<C>>,
+    /// Final CompDLEq response
+    pub z: Vec<C::ScalarField>,
+    pub omega: Vec<C::ScalarField>,
+    pub omega_hat: Vec<C::ScalarField>,
+}
@@ -0,0 +1,18 @@
+use crate::linker::comp_dl_eq::CompDLEqError;
+
+#[derive(Debug, PartialEq)]
+pub enum CompSumEqError {
+    /// The keys, weights and witness do not have matching lengths, or the total key has more
+    /// than one value generator.
+    MismatchParameterLength,
+    /// A point appended to the transcript is the identity.
+    IdentityPoint,
+    /// Failure of the compressed CompDLEq step.
+    CompDLEq(CompDLEqError),
+}
+
+impl From<CompDLEqError> for CompSumEqError {
+    fn from(e: CompDLEqError) -> Self {
+        CompSumEqError::CompDLEq(e)
+    }
+}
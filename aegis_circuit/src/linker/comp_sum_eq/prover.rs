@@ -0,0 +1,86 @@
+use ark_ec::CurveGroup;
+use ark_ff::UniformRand;
+use ark_std::rand::Rng;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{crypto::protocol::transcript::TranscriptProtocol, linker::comp_dl_eq::CompDLEq};
+
+use super::{data_structure::*, CompSumEq, CompSumEqError};
+
+impl<C: CurveGroup> CompSumEq<C> {
+    pub fn create_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+        transcript: &mut impl TranscriptProtocol,
+        rng: &mut impl Rng,
+    ) -> Result<Proof<C>, CompSumEqError> {
+        Self::check_public_parameters(pp)?;
+        Self::check_witness(pp, witness)?;
+
+        let prover_timer = start_timer!(|| "CompSumEq::Prover");
+
+        Self::append_instance(instance, transcript);
+
+        let commit_timer = start_timer!(|| "CompSumEq::Commit");
+        let mut random_vec = |len: usize| {
+            (0..len)
+                .map(|_| C::ScalarField::rand(rng))
+                .collect::<Vec<_>>()
+        };
+        let r = random_vec(pp.ck.g.len());
+        let delta = random_vec(pp.ck.h.len());
+        let gamma = random_vec(pp.total_ck.h.len());
+
+        let a = C::msm_unchecked(&pp.ck.g, &r) + C::msm_unchecked(&pp.ck.h, &delta);
+        let a_hat = pp.total_ck.g[0] * Self::weighted_sum(pp, &r)
+            + C::msm_unchecked(&pp.total_ck.h, &gamma);
+        let (a, a_hat) = (a.into_affine(), a_hat.into_affine());
+        end_timer!(commit_timer);
+
+        let challenge = Self::compute_e(&a, &a_hat, transcript)?;
+
+        let response_timer = start_timer!(|| "CompSumEq::Response");
+        let respond = |random: &[C::ScalarField], secret: &[C::ScalarField]| {
+            cfg_iter!(random)
+                .zip(secret)
+                .map(|(r, s)| *r + challenge * s)
+                .collect::<Vec<_>>()
+        };
+        let z = respond(&r, &witness.w);
+        let omega = respond(&delta, &witness.alpha);
+        let omega_hat = respond(&gamma, &witness.tau);
+        end_timer!(response_timer);
+
+        let (cde_pp, cde_instance) =
+            Self::prepare_for_comp_dl_eq(pp, instance, &a, &a_hat, &omega, &omega_hat, challenge);
+        let cde_proof =
+            CompDLEq::create_proof(&cde_pp, &cde_instance, &RecursionWitness { z }, transcript)?;
+
+        end_timer!(prover_timer);
+        Ok(Proof {
+            a,
+            a_hat,
+            commitments: cde_proof.commitments,
+            z: cde_proof.z,
+            omega,
+            omega_hat,
+        })
+    }
+
+    /// Check that the witness matches the dimensions of the public parameters.
+    pub fn check_witness(
+        pp: &PublicParameters<C>,
+        witness: &Witness<C>,
+    ) -> Result<(), CompSumEqError> {
+        if witness.w.len() != pp.ck.g.len()
+            || witness.alpha.len() != pp.ck.h.len()
+            || witness.tau.len() != pp.total_ck.h.len()
+        {
+            return Err(CompSumEqError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+}
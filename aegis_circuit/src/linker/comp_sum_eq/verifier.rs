@@ -0,0 +1,117 @@
+use ark_ec::CurveGroup;
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol,
+    linker::{comp_dl_eq::CompDLEq, points_to_bytes, points_to_bytes_with_identity},
+};
+
+use super::{data_structure::*, CompSumEq, CompSumEqError};
+
+impl<C: CurveGroup> CompSumEq<C> {
+    pub fn verify_proof<T: TranscriptProtocol>(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<bool, CompSumEqError> {
+        Self::check_public_parameters(pp)?;
+        if proof.z.len() != CompDLEq::<C>::final_len(pp.ck.g.len(), pp.threshold)
+            || proof.omega.len() != pp.ck.h.len()
+            || proof.omega_hat.len() != pp.total_ck.h.len()
+        {
+            return Ok(false);
+        }
+
+        let verifier_timer = start_timer!(|| "CompSumEq::Verifier");
+
+        Self::append_instance(instance, transcript);
+        let Ok(challenge) = Self::compute_e(&proof.a, &proof.a_hat, transcript) else {
+            return Ok(false);
+        };
+
+        let (cde_pp, cde_instance) = Self::prepare_for_comp_dl_eq(
+            pp,
+            instance,
+            &proof.a,
+            &proof.a_hat,
+            &proof.omega,
+            &proof.omega_hat,
+            challenge,
+        );
+        let cde_proof = CDEProof {
+            commitments: proof.commitments.clone(),
+            z: proof.z.clone(),
+        };
+        let result = CompDLEq::verify_proof(&cde_pp, &cde_instance, &cde_proof, transcript)?;
+
+        end_timer!(verifier_timer);
+        Ok(result)
+    }
+
+    /// Check that there is one weight per value and a single value generator for the total.
+    pub fn check_public_parameters(pp: &PublicParameters<C>) -> Result<(), CompSumEqError> {
+        if pp.weights.len() != pp.ck.g.len() || pp.total_ck.g.len() != 1 {
+            return Err(CompSumEqError::MismatchParameterLength);
+        }
+        CompDLEq::<C>::check_threshold(pp.threshold)?;
+        Ok(())
+    }
+
+    /// `t · g_0` of the total key, the instance total for a public `t`.
+    pub fn public_total(pp: &PublicParameters<C>, t: C::ScalarField) -> C::Affine {
+        (pp.total_ck.g[0] * t).into_affine()
+    }
+
+    /// `<x, b>` with the public weights `b`.
+    pub fn weighted_sum(pp: &PublicParameters<C>, x: &[C::ScalarField]) -> C::ScalarField {
+        x.iter().zip(&pp.weights).map(|(x, b)| *x * b).sum()
+    }
+
+    /// Append `c` and `T`, either of which may be the identity, as a zero total `t · g_0` is.
+    pub fn append_instance<T: TranscriptProtocol>(instance: &Instance<C>, transcript: &mut T) {
+        let bytes = points_to_bytes_with_identity(&[instance.c, instance.total]);
+        transcript.append(b"instance", &bytes);
+    }
+
+    pub fn compute_e<T: TranscriptProtocol>(
+        a: &C::Affine,
+        a_hat: &C::Affine,
+        transcript: &mut T,
+    ) -> Result<C::ScalarField, CompSumEqError> {
+        let bytes = points_to_bytes(&[*a, *a_hat]).ok_or(CompSumEqError::IdentityPoint)?;
+        transcript.append(b"commitment", &bytes);
+        Ok(transcript.challenge_scalar::<C::ScalarField>(b"challenge"))
+    }
+
+    /// CompDLEq statement `<g, z> = a + e · c - <h, ω>` and
+    /// `<g_hat, z> = a_hat + e · T - <v, Ω>` with `g_hat_j = b_j · u`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prepare_for_comp_dl_eq(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        a: &C::Affine,
+        a_hat: &C::Affine,
+        omega: &[C::ScalarField],
+        omega_hat: &[C::ScalarField],
+        challenge: C::ScalarField,
+    ) -> (RecursionPublicParameters<C>, RecursionInstance<C>) {
+        let y = instance.c * challenge + a - C::msm_unchecked(&pp.ck.h, omega);
+        let y_hat =
+            instance.total * challenge + a_hat - C::msm_unchecked(&pp.total_ck.h, omega_hat);
+
+        (
+            RecursionPublicParameters {
+                g: pp.ck.g.clone().into(),
+                g_hat: ScaledGenerators {
+                    bases: pp.total_ck.g.clone(),
+                    scalars: pp.weights.clone(),
+                },
+                threshold: pp.threshold,
+            },
+            RecursionInstance {
+                y: y.into_affine(),
+                y_hat: y_hat.into_affine(),
+            },
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use ark_ec::pairing::Pairing;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{
+    gro::{self, VerifyingKey},
+    linker::{am_com_eq, comp_am_com_eq},
+    solidity::Solidity,
+};
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicParameters<E: Pairing> {
+    pub vk: VerifyingKey<E>,
+    /// `poly_ck` is taken from the committing key of `vk`, `coeff_ck` is the external key
+    pub linker: am_com_eq::PublicParameters<E::G1>,
+}
+
+impl<E: Pairing> Solidity for PublicParameters<E>
+where
+    E::G1Affine: Solidity,
+    E::G2Affine: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [self.vk.to_solidity(), self.linker.to_solidity()].concat()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Instance<E: Pairing> {
+    /// Public inputs of the circuit
+    pub public_inputs: Vec<E::ScalarField>,
+    /// External commitments `c_hat_i = <g_hat, w_i> + <h_hat, beta_i>`
+    pub c_hat: Vec<E::G1Affine>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<E: Pairing> {
+    /// Committed values, `w_i` is opened by `c_hat_i` and `w` flattened by `D`
+    pub w: Vec<Vec<E::ScalarField>>,
+    /// Openings of the external commitments
+    pub beta: Vec<Vec<E::ScalarField>>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: Pairing> {
+    /// ccGroth16 proof carrying `D`
+    pub snark: gro::Proof<E>,
+    pub link: comp_am_com_eq::Proof<E::G1>,
+}
+
+impl<E: Pairing> Solidity for Proof<E>
+where
+    E::G1Affine: Solidity,
+    E::G2Affine: Solidity,
+    E::ScalarField: Solidity,
+{
+    fn to_solidity(&self) -> Vec<String> {
+        [self.snark.to_solidity(), self.link.to_solidity()].concat()
+    }
+}
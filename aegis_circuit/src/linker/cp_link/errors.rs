@@ -0,0 +1,25 @@
+use ark_relations::r1cs::SynthesisError;
+
+use crate::linker::comp_am_com_eq::CompAmComEqError;
+
+#[derive(Debug, PartialEq)]
+pub enum CPLinkError {
+    /// The committed witness does not fit the proof-dependent key, or the external key.
+    MismatchParameterLength,
+    /// Failure of the ccGroth16 setup, commitment or proof.
+    Snark(SynthesisError),
+    /// Failure of the CompAmComEq link.
+    Linker(CompAmComEqError),
+}
+
+impl From<SynthesisError> for CPLinkError {
+    fn from(e: SynthesisError) -> Self {
+        CPLinkError::Snark(e)
+    }
+}
+
+impl From<CompAmComEqError> for CPLinkError {
+    fn from(e: CompAmComEqError) -> Self {
+        CPLinkError::Linker(e)
+    }
+}
@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+use ark_ec::pairing::Pairing;
+
+mod data_structure;
+pub use data_structure::*;
+
+mod errors;
+pub use errors::CPLinkError;
+
+mod prover;
+mod verifier;
+
+/// CP-link between a ccGroth16 proof and external Pedersen commitments.
+///
+/// The proof-dependent commitment `D = <proof_dependent_g1, w> + gamma_eta_g1 · opening` of the
+/// SNARK plays the single commitment of [`CompAmComEq`](crate::linker::comp_am_com_eq::CompAmComEq)
+/// and the external commitments `c_hat_i = <g_hat, w_i> + <h_hat, beta_i>` the multiple ones,
+/// so the circuit is proven over the same values the external commitments hide.
+pub struct CPLink<E: Pairing> {
+    _pairing: PhantomData<E>,
+}
@@ -0,0 +1,97 @@
+use ark_ec::pairing::Pairing;
+use ark_relations::r1cs::ConstraintSynthesizer;
+use ark_std::rand::{CryptoRng, RngCore};
+
+use crate::{
+    crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol},
+    gro::{self, CCGroth16, ProvingKey},
+    linker::{
+        am_com_eq,
+        comp_am_com_eq::{CompAmComEq, CompAmComEqError},
+        comp_dl_eq::CompDLEq,
+    },
+    snark::CCSNARK,
+};
+
+use super::{CPLink, CPLinkError, Instance, Proof, PublicParameters, Witness};
+
+impl<E: Pairing> CPLink<E> {
+    /// Derive the linker parameters from the committing key of the SNARK:
+    /// `poly_ck = (proof_dependent_g1, [gamma_eta_g1])` next to the external `coeff_ck`, with the
+    /// link folded down to `threshold` scalars.
+    pub fn setup(
+        vk: &gro::VerifyingKey<E>,
+        coeff_ck: am_com_eq::CommittingKey<E::G1>,
+        threshold: usize,
+    ) -> Result<PublicParameters<E>, CPLinkError> {
+        let linker = Self::linker_parameters(&vk.ck, coeff_ck, threshold)?;
+        Ok(PublicParameters {
+            vk: vk.clone(),
+            linker,
+        })
+    }
+
+    pub fn linker_parameters(
+        ck: &gro::CommittingKey<E>,
+        coeff_ck: am_com_eq::CommittingKey<E::G1>,
+        threshold: usize,
+    ) -> Result<am_com_eq::PublicParameters<E::G1>, CPLinkError> {
+        let d0 = coeff_ck.g.len();
+        if d0 == 0 || !ck.proof_dependent_g1.len().is_multiple_of(d0) {
+            return Err(CPLinkError::MismatchParameterLength);
+        }
+        CompDLEq::<E::G1>::check_threshold(threshold).map_err(CompAmComEqError::from)?;
+        Ok(am_com_eq::PublicParameters {
+            poly_ck: am_com_eq::CommittingKey {
+                g: ck.proof_dependent_g1.clone(),
+                h: vec![ck.gamma_eta_g1],
+            },
+            coeff_ck,
+            threshold,
+        })
+    }
+
+    /// Commit to the flattened witness with the SNARK, prove the circuit, and link `D` to the
+    /// external commitments.
+    pub fn prove<C, T, R>(
+        pk: &ProvingKey<E>,
+        pp: &PublicParameters<E>,
+        circuit: C,
+        instance: &Instance<E>,
+        witness: &Witness<E>,
+        transcript: &mut T,
+        rng: &mut R,
+    ) -> Result<Proof<E>, CPLinkError>
+    where
+        C: ConstraintSynthesizer<E::ScalarField>,
+        T: TranscriptProtocol,
+        R: RngCore + CryptoRng,
+    {
+        let prover_timer = start_timer!(|| "CPLink::Prover");
+
+        let w_flat = witness.w.concat();
+        if w_flat.len() != pp.linker.poly_ck.g.len() {
+            return Err(CPLinkError::MismatchParameterLength);
+        }
+        let commitment = CCGroth16::<E>::commit(&pp.vk.ck, &w_flat, rng)?;
+        let snark = CCGroth16::<E>::prove(pk, circuit, &commitment, rng)?;
+
+        let link = CompAmComEq::<E::G1>::prove(
+            &pp.linker,
+            &am_com_eq::Instance {
+                c: snark.d,
+                c_hat: instance.c_hat.clone(),
+            },
+            &am_com_eq::Witness {
+                w: witness.w.clone(),
+                alpha: vec![commitment.opening],
+                beta: witness.beta.clone(),
+            },
+            transcript,
+            rng,
+        )?;
+
+        end_timer!(prover_timer);
+        Ok(Proof { snark, link })
+    }
+}
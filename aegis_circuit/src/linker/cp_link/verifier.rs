@@ -0,0 +1,38 @@
+use ark_ec::pairing::Pairing;
+
+use crate::{
+    crypto::protocol::{sigma::SigmaProtocol, transcript::TranscriptProtocol},
+    gro::CCGroth16,
+    linker::{am_com_eq, comp_am_com_eq::CompAmComEq},
+    snark::CCSNARK,
+};
+
+use super::{CPLink, CPLinkError, Instance, Proof, PublicParameters};
+
+impl<E: Pairing> CPLink<E> {
+    /// Verify the SNARK and the link of its `D` to the external commitments.
+    pub fn verify<T: TranscriptProtocol>(
+        pp: &PublicParameters<E>,
+        instance: &Instance<E>,
+        proof: &Proof<E>,
+        transcript: &mut T,
+    ) -> Result<bool, CPLinkError> {
+        let verifier_timer = start_timer!(|| "CPLink::Verifier");
+
+        if !CCGroth16::<E>::verify(&pp.vk, &instance.public_inputs, &proof.snark)? {
+            return Ok(false);
+        }
+        let result = CompAmComEq::<E::G1>::verify(
+            &pp.linker,
+            &am_com_eq::Instance {
+                c: proof.snark.d,
+                c_hat: instance.c_hat.clone(),
+            },
+            &proof.link,
+            transcript,
+        )?;
+
+        end_timer!(verifier_timer);
+        Ok(result)
+    }
+}
@@ -1,3 +1,40 @@
+use ark_ec::AffineRepr;
+use ark_ff::Zero;
+use ark_serialize::CanonicalSerialize;
+
 pub mod am_com_eq;
+pub mod com_enc_eq;
+pub mod com_pok;
 pub mod comp_am_com_eq;
+pub mod comp_com_pok;
 pub mod comp_dl_eq;
+pub mod comp_multi_am_com_eq;
+pub mod comp_sum_eq;
+pub mod cp_link;
+pub mod multi_am_com_eq;
+
+/// Big-endian `x || y` of every point, as appended to the transcript.
+/// Returns `None` if a point is the identity, which has no affine coordinates.
+pub(crate) fn points_to_bytes<G: AffineRepr>(points: &[G]) -> Option<Vec<u8>> {
+    if points.iter().any(|p| p.is_zero()) {
+        return None;
+    }
+    Some(points_to_bytes_with_identity(points))
+}
+
+/// [`points_to_bytes`] with the identity encoded as `(0, 0)`, as in the EVM precompiles.
+/// `(0, 0)` is off every curve `y^2 = x^3 + ax + b` with `b != 0`, such as BN254, so it cannot
+/// collide with another point.
+pub(crate) fn points_to_bytes_with_identity<G: AffineRepr>(points: &[G]) -> Vec<u8> {
+    let zero = G::BaseField::zero();
+    let mut bytes = vec![];
+    for p in points {
+        let (x, y) = p.xy().unwrap_or((&zero, &zero));
+        let mut _bytes = vec![];
+        y.serialize_uncompressed(&mut _bytes).unwrap();
+        x.serialize_uncompressed(&mut _bytes).unwrap();
+        _bytes.reverse();
+        bytes.extend(_bytes);
+    }
+    bytes
+}
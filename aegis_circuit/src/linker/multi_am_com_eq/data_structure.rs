@@ -0,0 +1,67 @@
+use ark_ec::CurveGroup;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+pub use crate::linker::am_com_eq::CommittingKey;
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicParameters<C: CurveGroup> {
+    /// Key of each single commitment `c_t`, which commits to the next `|g_t| / d0` rows
+    pub poly_cks: Vec<CommittingKey<C>>,
+    /// Key shared by every `c_hat_i`, or one key per index
+    pub coeff_cks: Vec<CommittingKey<C>>,
+    /// Fold threshold of the CompDLEq step of
+    /// [`CompMultiAmComEq`](crate::linker::comp_multi_am_com_eq::CompMultiAmComEq), unused by
+    /// [`MultiAmComEq`](super::MultiAmComEq)
+    pub threshold: usize,
+}
+
+impl<C: CurveGroup> PublicParameters<C> {
+    /// Length `d0` of each row.
+    pub fn d0(&self) -> usize {
+        self.coeff_cks.first().map_or(0, |ck| ck.g.len())
+    }
+
+    /// Key of `c_hat_i`.
+    pub fn coeff_ck(&self, i: usize) -> &CommittingKey<C> {
+        &self.coeff_cks[i % self.coeff_cks.len()]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Instance<C: CurveGroup> {
+    pub c: Vec<C::Affine>,
+    pub c_hat: Vec<C::Affine>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<C: CurveGroup> {
+    pub w: Vec<Vec<C::ScalarField>>,
+    /// Opening of each single commitment
+    pub alpha: Vec<Vec<C::ScalarField>>,
+    /// Opening of each multi commitment
+    pub beta: Vec<Vec<C::ScalarField>>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Randomness<C: CurveGroup> {
+    pub r: Vec<C::ScalarField>,
+    pub delta: Vec<Vec<C::ScalarField>>,
+    pub gamma: Vec<Vec<C::ScalarField>>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<C: CurveGroup> {
+    /// One per single commitment
+    pub a: Vec<C::Affine>,
+    pub a_hat: C::Affine,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<C: CurveGroup> {
+    pub commitment: Commitment<C>,
+    pub z: Vec<C::ScalarField>,
+    /// One per single commitment
+    pub omega: Vec<Vec<C::ScalarField>>,
+    /// One per coefficient key
+    pub omega_hat: Vec<Vec<C::ScalarField>>,
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq)]
+pub enum MultiAmComEqError {
+    /// The committing keys, instance and witness do not agree on `k`, `l` or the row lengths.
+    MismatchParameterLength,
+    /// A point appended to the transcript is the identity.
+    IdentityPoint,
+    /// The proof is malformed.
+    InvalidProof,
+}
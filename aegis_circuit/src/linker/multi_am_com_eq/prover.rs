@@ -0,0 +1,196 @@
+use ark_ec::CurveGroup;
+use ark_ff::{UniformRand, Zero};
+use ark_std::rand::Rng;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::crypto::protocol::transcript::TranscriptProtocol;
+
+use super::{
+    Commitment, Instance, MultiAmComEq, MultiAmComEqError, Proof, PublicParameters, Randomness,
+    Witness,
+};
+
+impl<C: CurveGroup> MultiAmComEq<C> {
+    /// Generate a random and compute the commitment
+    /// Returns the randomness and the commitment
+    pub fn create_random_commitment(
+        pp: &PublicParameters<C>,
+        coefficients: &[C::ScalarField],
+        rng: &mut impl Rng,
+    ) -> Result<(Randomness<C>, Commitment<C>), MultiAmComEqError> {
+        let commit_timer = start_timer!(|| "MultiAmComEq::Commit");
+        let n = pp.poly_cks.iter().map(|ck| ck.g.len()).sum::<usize>();
+
+        let random_timer = start_timer!(|| "Generate Random");
+        let mut random_vec = |len: usize| {
+            (0..len)
+                .map(|_| C::ScalarField::rand(rng))
+                .collect::<Vec<_>>()
+        };
+        let r = random_vec(n);
+        let delta = pp
+            .poly_cks
+            .iter()
+            .map(|ck| random_vec(ck.h.len()))
+            .collect();
+        let gamma = pp
+            .coeff_cks
+            .iter()
+            .map(|ck| random_vec(ck.h.len()))
+            .collect();
+        let random = Randomness { r, delta, gamma };
+        end_timer!(random_timer);
+
+        let commitment = Self::create_commitment_from_random(pp, coefficients, &random)?;
+        end_timer!(commit_timer);
+
+        Ok((random, commitment))
+    }
+
+    /// Compute the commitment from the randomness
+    pub fn create_commitment_from_random(
+        pp: &PublicParameters<C>,
+        coefficients: &[C::ScalarField],
+        random: &Randomness<C>,
+    ) -> Result<Commitment<C>, MultiAmComEqError> {
+        let a_timer = start_timer!(|| "Compute A");
+        let a = cfg_into_iter!(Self::segments(pp))
+            .zip(cfg_iter!(pp.poly_cks))
+            .zip(cfg_iter!(random.delta))
+            .map(|((range, ck), delta)| {
+                C::msm_unchecked(&ck.g, &random.r[range]) + C::msm_unchecked(&ck.h, delta)
+            })
+            .collect::<Vec<_>>();
+        end_timer!(a_timer);
+
+        let a_hat_timer = start_timer!(|| "Compute A hat");
+        let g_hat = Self::coefficient_generators(pp, coefficients);
+        let a_hat = C::msm_unchecked(&g_hat.bases, &g_hat.collapse(&random.r))
+            + pp.coeff_cks
+                .iter()
+                .zip(&random.gamma)
+                .map(|(ck, gamma)| C::msm_unchecked(&ck.h, gamma))
+                .sum::<C>();
+        end_timer!(a_hat_timer);
+
+        Ok(Commitment {
+            a: C::normalize_batch(&a),
+            a_hat: a_hat.into(),
+        })
+    }
+
+    pub fn create_proof_with_assignment(
+        pp: &PublicParameters<C>,
+        witness: &Witness<C>,
+        randomness: &Randomness<C>,
+        commitment: &Commitment<C>,
+        coefficients: &[C::ScalarField],
+        challenge: C::ScalarField,
+    ) -> Result<Proof<C>, MultiAmComEqError> {
+        let z_timer = start_timer!(|| "Compute Z");
+        let z = cfg_iter!(randomness.r)
+            .zip(witness.w.concat())
+            .map(|(&r, w)| r + challenge * w)
+            .collect();
+        end_timer!(z_timer);
+
+        let omega_timer = start_timer!(|| "Compute ω");
+        let omega = (randomness.delta.iter())
+            .zip(&witness.alpha)
+            .map(|(delta, alpha)| {
+                delta
+                    .iter()
+                    .zip(alpha)
+                    .map(|(&delta, alpha)| delta + challenge * alpha)
+                    .collect()
+            })
+            .collect();
+        end_timer!(omega_timer);
+
+        // Ω_κ = γ_κ + e · Σ_{i with key κ} ρ_i · β_i
+        let omega_hat_timer = start_timer!(|| "Compute Ω");
+        let m = pp.coeff_cks.len();
+        let omega_hat = (randomness.gamma.iter())
+            .enumerate()
+            .map(|(key, gamma)| {
+                let mut aggregated_beta = vec![C::ScalarField::zero(); gamma.len()];
+                for (i, (beta, x)) in witness.beta.iter().zip(coefficients).enumerate() {
+                    if i % m == key {
+                        aggregated_beta
+                            .iter_mut()
+                            .zip(beta)
+                            .for_each(|(acc, beta)| *acc += *beta * x);
+                    }
+                }
+                gamma
+                    .iter()
+                    .zip(aggregated_beta)
+                    .map(|(&gamma, beta)| gamma + challenge * beta)
+                    .collect()
+            })
+            .collect();
+        end_timer!(omega_hat_timer);
+
+        Ok(Proof {
+            commitment: commitment.clone(),
+            z,
+            omega,
+            omega_hat,
+        })
+    }
+
+    /// Check that the witness matches the dimensions of the public parameters and instance.
+    pub fn check_witness(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+    ) -> Result<(), MultiAmComEqError> {
+        let (l, d0) = (instance.c_hat.len(), pp.d0());
+        if witness.w.len() != l
+            || witness.beta.len() != l
+            || witness.alpha.len() != pp.poly_cks.len()
+            || witness.w.iter().any(|w_i| w_i.len() != d0)
+            || (witness.beta.iter().enumerate())
+                .any(|(i, beta_i)| beta_i.len() != pp.coeff_ck(i).h.len())
+            || (witness.alpha.iter())
+                .zip(&pp.poly_cks)
+                .any(|(alpha_t, ck)| alpha_t.len() != ck.h.len())
+        {
+            return Err(MultiAmComEqError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+
+    /// Create a proof
+    pub fn create_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        witness: &Witness<C>,
+        transcript: &mut impl TranscriptProtocol,
+        rng: &mut impl Rng,
+    ) -> Result<Proof<C>, MultiAmComEqError> {
+        Self::check_instance(pp, instance)?;
+        Self::check_witness(pp, instance, witness)?;
+
+        let proof_timer = start_timer!(|| "MultiAmComEq::Prover");
+
+        let coefficients = Self::compute_coefficients(instance, transcript)?;
+        let (randomness, commitment) = Self::create_random_commitment(pp, &coefficients, rng)?;
+        let challenge = Self::compute_e(&commitment, transcript)?;
+
+        let proof = Self::create_proof_with_assignment(
+            pp,
+            witness,
+            &randomness,
+            &commitment,
+            &coefficients,
+            challenge,
+        )?;
+
+        end_timer!(proof_timer);
+
+        Ok(proof)
+    }
+}
@@ -0,0 +1,164 @@
+use std::ops::Range;
+
+use ark_ec::CurveGroup;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    crypto::protocol::transcript::TranscriptProtocol,
+    linker::{comp_dl_eq::ScaledGenerators, points_to_bytes},
+};
+
+use super::{Commitment, Instance, MultiAmComEq, MultiAmComEqError, Proof, PublicParameters};
+
+impl<C: CurveGroup> MultiAmComEq<C> {
+    pub fn verify_proof(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+        transcript: &mut impl TranscriptProtocol,
+    ) -> Result<bool, MultiAmComEqError> {
+        Self::check_instance(pp, instance)?;
+        if Self::check_proof(pp, proof).is_err() {
+            return Ok(false);
+        }
+
+        let verifier_timer = start_timer!(|| "MultiAmComEq::Verifier");
+
+        let coefficients = Self::compute_coefficients(instance, transcript)?;
+        let Ok(challenge) = Self::compute_e(&proof.commitment, transcript) else {
+            return Ok(false);
+        };
+
+        let single_timer = start_timer!(|| "Single Commitments");
+        let single = cfg_into_iter!(Self::segments(pp))
+            .zip(cfg_iter!(pp.poly_cks))
+            .zip(cfg_iter!(proof.omega))
+            .zip(cfg_iter!(proof.commitment.a))
+            .zip(cfg_iter!(instance.c))
+            .all(|((((range, ck), omega), a), c)| {
+                let s_real =
+                    C::msm_unchecked(&ck.g, &proof.z[range]) + C::msm_unchecked(&ck.h, omega);
+                s_real == *a + *c * challenge
+            });
+        end_timer!(single_timer);
+
+        let multiple_timer = start_timer!(|| "Multiple Commitment");
+        let g_hat = Self::coefficient_generators(pp, &coefficients);
+        let m_real = C::msm_unchecked(&g_hat.bases, &g_hat.collapse(&proof.z))
+            + pp.coeff_cks
+                .iter()
+                .zip(&proof.omega_hat)
+                .map(|(ck, omega_hat)| C::msm_unchecked(&ck.h, omega_hat))
+                .sum::<C>();
+        let m_expected =
+            proof.commitment.a_hat + C::msm_unchecked(&instance.c_hat, &coefficients) * challenge;
+        end_timer!(multiple_timer);
+
+        end_timer!(verifier_timer);
+
+        Ok(single && m_real == m_expected)
+    }
+
+    /// Check that the instance matches the dimensions of the public parameters, i.e. one key per
+    /// single commitment, the keys covering `l` rows of `d0`, and one or `l` coefficient keys.
+    pub fn check_instance(
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+    ) -> Result<(), MultiAmComEqError> {
+        let (l, d0) = (instance.c_hat.len(), pp.d0());
+        if d0 == 0
+            || pp.poly_cks.len() != instance.c.len()
+            || (pp.coeff_cks.len() != 1 && pp.coeff_cks.len() != l)
+            || pp.coeff_cks.iter().any(|ck| ck.g.len() != d0)
+            || pp.poly_cks.iter().any(|ck| ck.g.len() % d0 != 0)
+            || pp.poly_cks.iter().map(|ck| ck.g.len()).sum::<usize>() != l * d0
+        {
+            return Err(MultiAmComEqError::MismatchParameterLength);
+        }
+        Ok(())
+    }
+
+    /// Check that the commitment and responses of the proof match the dimensions of the public
+    /// parameters.
+    pub fn check_proof(
+        pp: &PublicParameters<C>,
+        proof: &Proof<C>,
+    ) -> Result<(), MultiAmComEqError> {
+        let n = pp.poly_cks.iter().map(|ck| ck.g.len()).sum::<usize>();
+        if proof.z.len() != n {
+            return Err(MultiAmComEqError::InvalidProof);
+        }
+        Self::check_openings(pp, proof)
+    }
+
+    /// Check everything but `z`, which the compressed variant folds.
+    pub fn check_openings(
+        pp: &PublicParameters<C>,
+        proof: &Proof<C>,
+    ) -> Result<(), MultiAmComEqError> {
+        if proof.commitment.a.len() != pp.poly_cks.len()
+            || proof.omega.len() != pp.poly_cks.len()
+            || proof.omega_hat.len() != pp.coeff_cks.len()
+            || (pp.poly_cks.iter())
+                .zip(&proof.omega)
+                .any(|(ck, omega)| ck.h.len() != omega.len())
+            || (pp.coeff_cks.iter())
+                .zip(&proof.omega_hat)
+                .any(|(ck, omega_hat)| ck.h.len() != omega_hat.len())
+        {
+            return Err(MultiAmComEqError::InvalidProof);
+        }
+        Ok(())
+    }
+
+    /// Range of the flattened rows committed by each single commitment.
+    pub fn segments(pp: &PublicParameters<C>) -> Vec<Range<usize>> {
+        let mut start = 0;
+        pp.poly_cks
+            .iter()
+            .map(|ck| {
+                let range = start..start + ck.g.len();
+                start = range.end;
+                range
+            })
+            .collect()
+    }
+
+    /// `g_hat_{i·d0 + j} = ρ_i · coeff_ck(i).g_j`, so that `<g_hat, z> = Σ ρ_i · <coeff_ck(i).g, z_i>`.
+    pub fn coefficient_generators(
+        pp: &PublicParameters<C>,
+        coefficients: &[C::ScalarField],
+    ) -> ScaledGenerators<C> {
+        let d0 = pp.d0();
+        ScaledGenerators {
+            bases: pp.coeff_cks.iter().flat_map(|ck| ck.g.clone()).collect(),
+            scalars: (0..coefficients.len() * d0)
+                .map(|j| coefficients[j / d0])
+                .collect(),
+        }
+    }
+
+    /// Sample independent coefficients for the random linear combination of `c_hat`.
+    pub fn compute_coefficients<T: TranscriptProtocol>(
+        instance: &Instance<C>,
+        transcript: &mut T,
+    ) -> Result<Vec<C::ScalarField>, MultiAmComEqError> {
+        let l = instance.c_hat.len();
+        let instance = [&instance.c[..], &instance.c_hat].concat();
+        let bytes = points_to_bytes(&instance).ok_or(MultiAmComEqError::IdentityPoint)?;
+        transcript.append(b"instance", &bytes);
+        Ok(transcript.challenge_scalars::<C::ScalarField>(b"challenge", l))
+    }
+
+    pub fn compute_e<T: TranscriptProtocol>(
+        commitment: &Commitment<C>,
+        transcript: &mut T,
+    ) -> Result<C::ScalarField, MultiAmComEqError> {
+        let points = [&commitment.a[..], &[commitment.a_hat]].concat();
+        let bytes = points_to_bytes(&points).ok_or(MultiAmComEqError::IdentityPoint)?;
+        transcript.append(b"commitment", &bytes);
+        Ok(transcript.challenge_scalar::<C::ScalarField>(b"challenge"))
+    }
+}
@@ -0,0 +1,127 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use sha3::{Digest, Keccak256};
+
+use crate::{bulletproofs, gro, linker::com_pok};
+
+use super::PolError;
+
+/// Account commitments and liabilities after the batches of [`Pol::apply`](super::Pol::apply).
+pub type State<E> = (Vec<<E as Pairing>::G1Affine>, <E as Pairing>::ScalarField);
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PublicParameters<E: Pairing> {
+    /// Range proof parameters, with `g` and `h` the balance and blinding bases of the DBT key
+    pub range: bulletproofs::PublicParameters<E::G1>,
+}
+
+/// DBT batch applied between two epochs.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct AppliedBatch<E: Pairing> {
+    /// Account index of every vector of the batch
+    pub accounts: Vec<u64>,
+    /// Current then delta commitments, as verified by [`Aegis::verify`](crate::aegis::Aegis::verify)
+    pub commitments: Vec<E::G1Affine>,
+    pub net_issuance: E::ScalarField,
+    pub proof: gro::Proof<E>,
+}
+
+/// Published state of an epoch.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Epoch<E: Pairing> {
+    pub number: u64,
+    /// Digest of the previous epoch, zero for the first one
+    pub previous: [u8; 32],
+    /// Digest of the batches applied since the previous epoch
+    pub batches: [u8; 32],
+    /// Total liabilities `L`
+    pub liabilities: E::ScalarField,
+    /// Account commitments `c_i = b_i · g + γ_i · h`
+    pub commitments: Vec<E::G1Affine>,
+}
+
+impl<E: Pairing> Epoch<E> {
+    /// First epoch, with no previous epoch and no applied batch.
+    pub fn genesis(liabilities: E::ScalarField, commitments: Vec<E::G1Affine>) -> Self {
+        Self {
+            number: 0,
+            previous: [0u8; 32],
+            batches: digest::<[AppliedBatch<E>]>(&[]),
+            liabilities,
+            commitments,
+        }
+    }
+
+    /// Epoch following `self` after `batches`.
+    pub fn next(
+        &self,
+        batches: &[AppliedBatch<E>],
+        liabilities: E::ScalarField,
+        commitments: Vec<E::G1Affine>,
+    ) -> Self {
+        Self {
+            number: self.number + 1,
+            previous: self.digest(),
+            batches: digest(batches),
+            liabilities,
+            commitments,
+        }
+    }
+
+    /// Keccak256 of the compressed epoch.
+    pub fn digest(&self) -> [u8; 32] {
+        digest(self)
+    }
+}
+
+/// Balances and blindings of the accounts of an epoch.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Witness<F: PrimeField> {
+    pub balances: Vec<u64>,
+    pub blindings: Vec<F>,
+}
+
+impl<F: PrimeField> Witness<F> {
+    /// Split the `[balance, blinding]` account vectors.
+    pub fn from_accounts(accounts: &[Vec<F>]) -> Result<Self, PolError> {
+        let mut balances = Vec::with_capacity(accounts.len());
+        let mut blindings = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            let [balance, blinding] = account[..] else {
+                return Err(PolError::MismatchParameterLength);
+            };
+            let balance = balance.into_bigint();
+            if balance.num_bits() > 64 {
+                return Err(PolError::OutOfRange);
+            }
+            balances.push(balance.as_ref()[0]);
+            blindings.push(blinding);
+        }
+        Ok(Self {
+            balances,
+            blindings,
+        })
+    }
+
+    /// `Σ b_i`, which cannot wrap around for fewer than `2^64` accounts.
+    pub fn total(&self) -> F {
+        F::from(self.balances.iter().map(|b| *b as u128).sum::<u128>())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<E: Pairing> {
+    /// Range proof of every balance
+    pub range: bulletproofs::Proof<E::G1>,
+    /// Proof of knowledge of `Γ` in `Σ c_i - L · g = Γ · h`
+    pub total: com_pok::Proof<E::G1>,
+}
+
+/// Keccak256 of the compressed `item`.
+pub(super) fn digest<T: CanonicalSerialize + ?Sized>(item: &T) -> [u8; 32] {
+    let mut bytes = Vec::new();
+    item.serialize_compressed(&mut bytes)
+        .expect("serialization into a vector cannot fail");
+    Keccak256::digest(&bytes).into()
+}
@@ -0,0 +1,36 @@
+use crate::{aegis::AegisError, bulletproofs::RangeProofError, linker::com_pok::ComPoKError};
+
+#[derive(Debug, PartialEq)]
+pub enum PolError {
+    /// The DBT key does not commit to `[balance, blinding]`, or the witness does not match the
+    /// epoch.
+    MismatchParameterLength,
+    /// A balance does not fit in 64 bits.
+    OutOfRange,
+    /// The balances do not sum to the published liabilities.
+    LiabilitiesMismatch,
+    /// Failure of the range proof of the balances.
+    RangeProof(RangeProofError),
+    /// Failure of the proof of knowledge of the total blinding.
+    ComPoK(ComPoKError),
+    /// Failure of the verification of an applied DBT batch.
+    Aegis(AegisError),
+}
+
+impl From<RangeProofError> for PolError {
+    fn from(e: RangeProofError) -> Self {
+        PolError::RangeProof(e)
+    }
+}
+
+impl From<ComPoKError> for PolError {
+    fn from(e: ComPoKError) -> Self {
+        PolError::ComPoK(e)
+    }
+}
+
+impl From<AegisError> for PolError {
+    fn from(e: AegisError) -> Self {
+        PolError::Aegis(e)
+    }
+}
@@ -0,0 +1,25 @@
+use std::marker::PhantomData;
+
+use ark_ec::pairing::Pairing;
+
+mod data_structure;
+pub use data_structure::*;
+
+mod errors;
+pub use errors::PolError;
+
+mod prover;
+mod verifier;
+
+/// Dynamic proof of liabilities over the account commitments of [`Aegis`](crate::aegis::Aegis).
+///
+/// Accounts commit to `[balance, blinding]` under the DBT key `batch_g1 = [g, h]`.
+/// At every epoch the custodian publishes the account commitments `c_i` and the total
+/// liabilities `L`, and proves that every balance is in `[0, 2^bits)` with an aggregated range
+/// proof and that `Σ c_i - L · g = Γ · h` with a proof of knowledge of `Γ`.
+/// An epoch commits to the previous one and to the DBT batches applied since, so a verifier
+/// holding the previous epoch replays the batches, checks their proofs, and recomputes the
+/// commitments and liabilities the new epoch must publish.
+pub struct Pol<E: Pairing> {
+    _pairing: PhantomData<E>,
+}
@@ -0,0 +1,85 @@
+use ark_ec::pairing::Pairing;
+use ark_std::rand::{CryptoRng, Rng, RngCore};
+
+use crate::{
+    bulletproofs::{self, RangeProof},
+    crypto::protocol::sigma::SigmaProtocol,
+    gro::CommittingKey,
+    linker::com_pok::{self, ComPoK},
+};
+
+use super::{Epoch, Pol, PolError, Proof, PublicParameters, Witness};
+
+impl<E: Pairing> Pol<E> {
+    /// Take the balance and blinding bases from the DBT key `ck`, and sample the range proof
+    /// generators for up to `max_accounts` balances of `bits` bits.
+    pub fn setup<R: Rng>(
+        ck: &CommittingKey<E>,
+        bits: usize,
+        max_accounts: usize,
+        rng: &mut R,
+    ) -> Result<PublicParameters<E>, PolError> {
+        let [g, h] = ck.batch_g1[..] else {
+            return Err(PolError::MismatchParameterLength);
+        };
+        let mut range = RangeProof::<E::G1>::generate_parameters(bits, max_accounts, rng)?;
+        range.g = g;
+        range.h = h;
+        Ok(PublicParameters { range })
+    }
+
+    /// Prove the range of every balance of `epoch` and that they sum to its liabilities.
+    pub fn prove<R: RngCore + CryptoRng>(
+        pp: &PublicParameters<E>,
+        epoch: &Epoch<E>,
+        witness: &Witness<E::ScalarField>,
+        rng: &mut R,
+    ) -> Result<Proof<E>, PolError> {
+        Self::check_witness(epoch, witness)?;
+
+        let prover_timer = start_timer!(|| "Pol::Prover");
+        let mut transcript = Self::transcript(epoch);
+
+        let range_witness = bulletproofs::Witness {
+            v: witness.balances.clone(),
+            gamma: witness.blindings.clone(),
+        };
+        let range = RangeProof::<E::G1>::prove(
+            &pp.range,
+            &Self::range_instance(epoch),
+            &range_witness,
+            &mut transcript,
+            rng,
+        )?;
+
+        let (total_pp, total_instance) = Self::total_statement(pp, epoch);
+        let total_witness = com_pok::Witness {
+            m: vec![vec![witness.blindings.iter().sum()]],
+        };
+        let total = ComPoK::<E::G1>::prove(
+            &total_pp,
+            &total_instance,
+            &total_witness,
+            &mut transcript,
+            rng,
+        )?;
+        end_timer!(prover_timer);
+
+        Ok(Proof { range, total })
+    }
+
+    pub fn check_witness(
+        epoch: &Epoch<E>,
+        witness: &Witness<E::ScalarField>,
+    ) -> Result<(), PolError> {
+        if witness.balances.len() != epoch.commitments.len()
+            || witness.blindings.len() != epoch.commitments.len()
+        {
+            return Err(PolError::MismatchParameterLength);
+        }
+        if witness.total() != epoch.liabilities {
+            return Err(PolError::LiabilitiesMismatch);
+        }
+        Ok(())
+    }
+}
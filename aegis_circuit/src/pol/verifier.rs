@@ -0,0 +1,148 @@
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+
+use crate::{
+    aegis::Aegis,
+    bulletproofs::{self, RangeProof},
+    crypto::{
+        commitment::{pedersen::Pedersen, BatchCommitmentScheme},
+        protocol::{
+            sigma::SigmaProtocol,
+            transcript::{sha3::SHA3Base, TranscriptProtocol},
+        },
+    },
+    gro::VerifyingKey,
+    linker::{
+        com_pok::{self, ComPoK},
+        comp_dl_eq::DEFAULT_THRESHOLD,
+    },
+};
+
+use super::{
+    data_structure::digest, AppliedBatch, Epoch, Pol, PolError, Proof, PublicParameters, State,
+};
+
+impl<E: Pairing> Pol<E> {
+    /// Verify the liabilities proof of `epoch` alone.
+    pub fn verify(
+        pp: &PublicParameters<E>,
+        epoch: &Epoch<E>,
+        proof: &Proof<E>,
+    ) -> Result<bool, PolError> {
+        let verifier_timer = start_timer!(|| "Pol::Verifier");
+        let mut transcript = Self::transcript(epoch);
+
+        let range = RangeProof::<E::G1>::verify(
+            &pp.range,
+            &Self::range_instance(epoch),
+            &proof.range,
+            &mut transcript,
+        )?;
+        if !range {
+            return Ok(false);
+        }
+
+        let (total_pp, total_instance) = Self::total_statement(pp, epoch);
+        let result =
+            ComPoK::<E::G1>::verify(&total_pp, &total_instance, &proof.total, &mut transcript)?;
+        end_timer!(verifier_timer);
+
+        Ok(result)
+    }
+
+    /// Verify that `epoch` follows `previous` through `batches`, and its liabilities proof.
+    pub fn verify_chain(
+        pp: &PublicParameters<E>,
+        vk: &VerifyingKey<E>,
+        previous: &Epoch<E>,
+        batches: &[AppliedBatch<E>],
+        epoch: &Epoch<E>,
+        proof: &Proof<E>,
+    ) -> Result<bool, PolError> {
+        if epoch.number != previous.number + 1
+            || epoch.previous != previous.digest()
+            || epoch.batches != digest(batches)
+        {
+            return Ok(false);
+        }
+
+        let Some((commitments, liabilities)) = Self::apply(vk, previous, batches)? else {
+            return Ok(false);
+        };
+        if commitments != epoch.commitments || liabilities != epoch.liabilities {
+            return Ok(false);
+        }
+
+        Self::verify(pp, epoch, proof)
+    }
+
+    /// Replay `batches` from `previous`: verify every DBT proof, and check that the current
+    /// commitments of every batch are the running account commitments plus the deltas.
+    /// Returns the account commitments and the liabilities after the batches, or `None` if a
+    /// batch does not apply, whether it is malformed, names an unknown account or does not
+    /// verify. Errors are left to a DBT key of invalid dimensions.
+    pub fn apply(
+        vk: &VerifyingKey<E>,
+        previous: &Epoch<E>,
+        batches: &[AppliedBatch<E>],
+    ) -> Result<Option<State<E>>, PolError> {
+        let (batch_size, _) = Aegis::<E>::dimensions(&vk.ck)?;
+        let mut commitments = previous.commitments.clone();
+        let mut liabilities = previous.liabilities;
+
+        for batch in batches {
+            if batch.accounts.len() != batch_size || batch.commitments.len() != 2 * batch_size {
+                return Ok(None);
+            }
+            if !Aegis::<E>::verify(vk, &batch.commitments, batch.net_issuance, &batch.proof)? {
+                return Ok(None);
+            }
+
+            let (curr, delta) = batch.commitments.split_at(batch_size);
+            for ((account, curr), delta) in batch.accounts.iter().zip(curr).zip(delta) {
+                let Some(commitment) = usize::try_from(*account)
+                    .ok()
+                    .and_then(|account| commitments.get_mut(account))
+                else {
+                    return Ok(None);
+                };
+                if (*commitment + delta).into_affine() != *curr {
+                    return Ok(None);
+                }
+                *commitment = *curr;
+            }
+            liabilities += batch.net_issuance;
+        }
+
+        Ok(Some((commitments, liabilities)))
+    }
+
+    /// Transcript bound to the digest of `epoch`.
+    pub(super) fn transcript(epoch: &Epoch<E>) -> SHA3Base {
+        let mut transcript = SHA3Base::new(true);
+        transcript.append(b"epoch", &epoch.digest());
+        transcript
+    }
+
+    pub(super) fn range_instance(epoch: &Epoch<E>) -> bulletproofs::Instance<E::G1> {
+        bulletproofs::Instance {
+            v: epoch.commitments.clone(),
+        }
+    }
+
+    /// `Σ c_i - L · g` under the key `[h]`, with the sum by [`Pedersen::aggregate`] at `tau = 1`.
+    pub(super) fn total_statement(
+        pp: &PublicParameters<E>,
+        epoch: &Epoch<E>,
+    ) -> (com_pok::PublicParameters<E::G1>, com_pok::Instance<E::G1>) {
+        let one = E::ScalarField::from(1u64);
+        let (total, _) = Pedersen::<E::G1>::aggregate(&epoch.commitments, one, None);
+        let blinding = (total.into_group() - pp.range.g * epoch.liabilities).into_affine();
+        (
+            com_pok::PublicParameters {
+                g: vec![pp.range.h],
+                threshold: DEFAULT_THRESHOLD,
+            },
+            com_pok::Instance { c: vec![blinding] },
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use sha3::{Digest, Keccak256};
+
+/// Big-endian 32-byte word of a decimal string, as produced by [`Solidity`](super::Solidity).
+pub fn to_word(decimal: &str) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    for digit in decimal.bytes() {
+        let mut carry = (digit - b'0') as u32;
+        for byte in word.iter_mut().rev() {
+            let v = *byte as u32 * 10 + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+    }
+    word
+}
+
+/// First four bytes of the keccak of the function signature, e.g. `verify(uint256[],uint256[])`.
+pub fn selector(signature: &str) -> [u8; 4] {
+    let hash = Keccak256::digest(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// `abi.encode` of `uint256[]` arguments: the offsets of every array, then each length and its
+/// elements.
+pub fn encode_arrays(arrays: &[Vec<String>]) -> Vec<u8> {
+    let mut head = vec![];
+    let mut tail = vec![];
+    let mut offset = 32 * arrays.len();
+    for array in arrays {
+        head.extend(to_word(&offset.to_string()));
+        tail.extend(to_word(&array.len().to_string()));
+        tail.extend(array.iter().flat_map(|x| to_word(x)));
+        offset += 32 * (1 + array.len());
+    }
+    [head, tail].concat()
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    let hex = bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    format!("0x{}", hex)
+}
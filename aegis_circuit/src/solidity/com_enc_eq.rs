@@ -0,0 +1,131 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, Field, PrimeField};
+use sha3::{Digest, Keccak256};
+
+use crate::linker::com_enc_eq::{Instance, Proof, PublicParameters};
+
+use super::{abi, Solidity};
+
+const TEMPLATE: &str = include_str!("com_enc_eq.sol");
+
+/// Generator of a Solidity verifier for [`ComEncEq`](crate::linker::com_enc_eq::ComEncEq)
+/// proofs over any number of commitments, on the alt_bn128 precompiles.
+///
+/// The contract replays the transcript of `SHA3Base::new(false)`, so proofs must be created with
+/// a label-free `SHA3Base`. Public parameters, instances and proofs are passed as the
+/// `uint256[]` of their [`Solidity`] encoding.
+pub struct ComEncEqVerifier<C: CurveGroup> {
+    _group: PhantomData<C>,
+}
+
+impl<C: CurveGroup> ComEncEqVerifier<C> {
+    /// Source of the `ComEncEqVerifier` contract.
+    pub fn contract() -> String
+    where
+        C::Affine: Solidity,
+    {
+        let r = C::ScalarField::MODULUS;
+        let q = <C::BaseField as Field>::BasePrimeField::MODULUS;
+        let r256 = C::ScalarField::from_be_bytes_mod_order(&[&[1u8][..], &[0u8; 32]].concat());
+        let generator = C::generator().into_affine().to_solidity();
+
+        [
+            ("R", r.to_string()),
+            ("Q", q.to_string()),
+            ("R256", r256.into_bigint().to_string()),
+            ("GX", generator[0].clone()),
+            ("GY", generator[1].clone()),
+        ]
+        .iter()
+        .fold(TEMPLATE.to_string(), |source, (key, value)| {
+            source.replace(&format!("{{{{{}}}}}", key), value)
+        })
+    }
+
+    /// Challenges `[rho, e]` as the contract derives them from the `uint256[]` encodings, or
+    /// `None` if the encodings do not have the contract's lengths.
+    pub fn challenges(instance: &Instance<C>, proof: &Proof<C>) -> Option<Vec<C::ScalarField>>
+    where
+        C::Affine: Solidity,
+        C::ScalarField: Solidity,
+    {
+        let instance = instance.to_solidity();
+        let proof = proof.to_solidity();
+        let l = instance.first()?.parse::<usize>().ok()?;
+        if Some(instance.len()) != l.checked_mul(6).map(|n| n + 1) || proof.len() != 9 {
+            return None;
+        }
+
+        let words = |xs: &[String]| xs.iter().flat_map(|x| abi::to_word(x)).collect::<Vec<_>>();
+        let challenge = |t: &[u8]| {
+            let bytes = [0u8, 1]
+                .iter()
+                .flat_map(|i| {
+                    Keccak256::new()
+                        .chain_update(t)
+                        .chain_update([*i])
+                        .finalize()
+                })
+                .collect::<Vec<_>>();
+            C::ScalarField::from_be_bytes_mod_order(&bytes)
+        };
+
+        let (c, ciphertexts) = instance[1..].split_at(2 * l);
+        let mut t = c
+            .chunks(2)
+            .zip(ciphertexts.chunks(4))
+            .flat_map(|(c, ct)| [words(c), words(ct)].concat())
+            .collect::<Vec<_>>();
+        let mut challenges = vec![];
+        for _ in 0..l {
+            challenges.push(challenge(&t));
+            t = challenges.last()?.into_bigint().to_bytes_be();
+        }
+        t.extend(words(&proof[3..]));
+        challenges.push(challenge(&t));
+        Some(challenges)
+    }
+
+    /// TypeScript module with the `uint256[]` arguments, the expected challenges and the
+    /// ABI-encoded constructor arguments and `verify` calldata.
+    pub fn fixture(pp: &PublicParameters<C>, instance: &Instance<C>, proof: &Proof<C>) -> String
+    where
+        C::Affine: Solidity,
+        C::ScalarField: Solidity,
+    {
+        let challenges = Self::challenges(instance, proof)
+            .map(|c| c.to_solidity())
+            .unwrap_or_default();
+        let l = instance.c.len();
+        let (pp, instance, proof) = (
+            pp.to_solidity(),
+            instance.to_solidity(),
+            proof.to_solidity(),
+        );
+        let constructor_args = abi::encode_arrays(std::slice::from_ref(&pp));
+        let calldata = [
+            &abi::selector("verify(uint256[],uint256[])")[..],
+            &abi::encode_arrays(&[instance.clone(), proof.clone()]),
+        ]
+        .concat();
+
+        let name = format!("comEncEq{}", l);
+        [
+            format!("const dims = {{ l: {} }}", l),
+            format!("const pp = {:?}", pp),
+            format!("const instance = {:?}", instance),
+            format!("const proof = {:?}", proof),
+            format!("const challenges = {:?}", challenges),
+            format!("const constructorArgs = {:?}", abi::to_hex(&constructor_args)),
+            format!("const calldata = {:?}", abi::to_hex(&calldata)),
+            format!(
+                "\nconst {} = {{ dims, pp, instance, proof, challenges, constructorArgs, calldata }}",
+                name,
+            ),
+            format!("\nexport default {}\n", name),
+        ]
+        .join("\n")
+    }
+}
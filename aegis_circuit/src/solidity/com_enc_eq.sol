@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.23;
+
+/// Verifier for ComEncEq proofs that ElGamal ciphertexts under an auditor key encrypt the values
+/// of Pedersen commitments, for any number of commitments.
+/// Generated by `ComEncEqVerifier` in `aegis_circuit/src/solidity`, do not edit by hand.
+///
+/// The Fiat-Shamir transcript is `SHA3Base` without labels: every challenge is
+/// `keccak(t || 0) || keccak(t || 1)` reduced mod R, after which the transcript `t` is reset to
+/// the 32-byte challenge. Points are appended as big-endian `x || y`.
+contract ComEncEqVerifier {
+    uint256 internal constant R = {{R}};
+    uint256 internal constant Q = {{Q}};
+    // 2^256 mod R, to reduce the 64-byte challenge expansion
+    uint256 internal constant R256 = {{R256}};
+    // generator `G` of the ElGamal randomness `k · G`
+    uint256 internal constant GX = {{GX}};
+    uint256 internal constant GY = {{GY}};
+
+    // pp = [g, h, pk]
+    uint256 internal constant PP_G = 0;
+    uint256 internal constant PP_H = 2;
+    uint256 internal constant PP_PK = 4;
+    uint256 internal constant PP_LEN = 6;
+
+    // instance = [l, c, (c1, c2) * l]
+    uint256 internal constant INSTANCE_C = 1;
+
+    // proof = [z_m, z_r, z_k, a, a1, a2]
+    uint256 internal constant P_Z_M = 0;
+    uint256 internal constant P_Z_R = 1;
+    uint256 internal constant P_Z_K = 2;
+    uint256 internal constant P_A = 3;
+    uint256 internal constant P_A1 = 5;
+    uint256 internal constant P_A2 = 7;
+    uint256 internal constant PROOF_LEN = 9;
+
+    struct Challenges {
+        uint256[] rho;
+        uint256 e;
+    }
+
+    uint256[] internal pp;
+
+    constructor(uint256[] memory _pp) {
+        require(_pp.length == PP_LEN, "ComEncEq: invalid parameters length");
+        pp = _pp;
+    }
+
+    function verify(
+        uint256[] calldata instance,
+        uint256[] calldata proof
+    ) public view returns (bool) {
+        (bool ok, Challenges memory ch) = _transcript(instance, proof);
+        if (!ok) {
+            return false;
+        }
+        return _check(pp, instance, proof, ch);
+    }
+
+    /// `[rho, e]` as derived by the verifier, for comparison with the prover side.
+    function challenges(
+        uint256[] calldata instance,
+        uint256[] calldata proof
+    ) public pure returns (uint256[] memory result) {
+        (bool ok, Challenges memory ch) = _transcript(instance, proof);
+        require(ok, "ComEncEq: malformed proof");
+        uint256 l = ch.rho.length;
+        result = new uint256[](l + 1);
+        for (uint256 i = 0; i < l; i++) {
+            result[i] = ch.rho[i];
+        }
+        result[l] = ch.e;
+    }
+
+    function _transcript(
+        uint256[] calldata instance,
+        uint256[] calldata proof
+    ) internal pure returns (bool ok, Challenges memory ch) {
+        if (instance.length == 0 || proof.length != PROOF_LEN) {
+            return (false, ch);
+        }
+        uint256 l = instance[0];
+        if ((instance.length - 1) % 6 != 0 || (instance.length - 1) / 6 != l) {
+            return (false, ch);
+        }
+        for (uint256 i = 0; i < P_A; i++) {
+            if (proof[i] >= R) {
+                return (false, ch);
+            }
+        }
+        for (uint256 i = INSTANCE_C; i < instance.length; i += 2) {
+            if (instance[i] == 0 && instance[i + 1] == 0) {
+                return (false, ch);
+            }
+        }
+        for (uint256 i = P_A; i < PROOF_LEN; i += 2) {
+            if (proof[i] == 0 && proof[i + 1] == 0) {
+                return (false, ch);
+            }
+        }
+
+        // coefficients from the commitments and ciphertexts, appended as (c_i, c1_i, c2_i)
+        uint256 ct = INSTANCE_C + 2 * l;
+        bytes memory t;
+        for (uint256 i = 0; i < l; i++) {
+            t = abi.encodePacked(
+                t,
+                abi.encodePacked(instance[INSTANCE_C + 2 * i], instance[INSTANCE_C + 2 * i + 1]),
+                abi.encodePacked(instance[ct + 4 * i], instance[ct + 4 * i + 1]),
+                abi.encodePacked(instance[ct + 4 * i + 2], instance[ct + 4 * i + 3])
+            );
+        }
+        ch.rho = new uint256[](l);
+        for (uint256 i = 0; i < l; i++) {
+            ch.rho[i] = _challenge(t);
+            t = abi.encodePacked(ch.rho[i]);
+        }
+
+        // challenge on the commitment (a, a1, a2)
+        t = abi.encodePacked(
+            t,
+            abi.encodePacked(proof[P_A], proof[P_A + 1], proof[P_A1], proof[P_A1 + 1]),
+            abi.encodePacked(proof[P_A2], proof[P_A2 + 1])
+        );
+        ch.e = _challenge(t);
+        ok = true;
+    }
+
+    function _check(
+        uint256[] memory _pp,
+        uint256[] calldata instance,
+        uint256[] calldata proof,
+        Challenges memory ch
+    ) internal view returns (bool) {
+        uint256 l = ch.rho.length;
+        uint256 ct = INSTANCE_C + 2 * l;
+        // - e * rho_i, the scalar of every statement
+        uint256[] memory w = new uint256[](l);
+        for (uint256 i = 0; i < l; i++) {
+            w[i] = _neg(mulmod(ch.e, ch.rho[i], R));
+        }
+
+        // io = [acc.x, acc.y, base.x, base.y, scalar]
+        uint256[5] memory io;
+
+        // z_m · g + z_r · h = a + e · Σ ρ_i · c_i
+        bool ok = _mulAdd(io, _pp[PP_G], _pp[PP_G + 1], proof[P_Z_M]) &&
+            _mulAdd(io, _pp[PP_H], _pp[PP_H + 1], proof[P_Z_R]) &&
+            _mulAdd(io, proof[P_A], proof[P_A + 1], R - 1) &&
+            _accumulate(io, instance, INSTANCE_C, 2, w) &&
+            _isIdentity(io);
+
+        // z_k · G = a1 + e · Σ ρ_i · c1_i
+        ok = ok &&
+            _mulAdd(io, GX, GY, proof[P_Z_K]) &&
+            _mulAdd(io, proof[P_A1], proof[P_A1 + 1], R - 1) &&
+            _accumulate(io, instance, ct, 4, w) &&
+            _isIdentity(io);
+
+        // z_m · g + z_k · pk = a2 + e · Σ ρ_i · c2_i
+        return
+            ok &&
+            _mulAdd(io, _pp[PP_G], _pp[PP_G + 1], proof[P_Z_M]) &&
+            _mulAdd(io, _pp[PP_PK], _pp[PP_PK + 1], proof[P_Z_K]) &&
+            _mulAdd(io, proof[P_A2], proof[P_A2 + 1], R - 1) &&
+            _accumulate(io, instance, ct + 2, 4, w) &&
+            _isIdentity(io);
+    }
+
+    /// acc += Σ w_i · P_i with P_i at `instance[offset + stride * i]`.
+    function _accumulate(
+        uint256[5] memory io,
+        uint256[] calldata instance,
+        uint256 offset,
+        uint256 stride,
+        uint256[] memory w
+    ) internal view returns (bool ok) {
+        ok = true;
+        for (uint256 i = 0; i < w.length; i++) {
+            uint256 o = offset + stride * i;
+            ok = ok && _mulAdd(io, instance[o], instance[o + 1], w[i]);
+        }
+    }
+
+    /// Whether the accumulator is the identity, after which it is reset for the next equation.
+    function _isIdentity(uint256[5] memory io) internal pure returns (bool identity) {
+        identity = io[0] == 0 && io[1] == 0;
+        io[0] = 0;
+        io[1] = 0;
+    }
+
+    function _challenge(bytes memory t) internal pure returns (uint256) {
+        uint256 hi = uint256(keccak256(abi.encodePacked(t, uint8(0))));
+        uint256 lo = uint256(keccak256(abi.encodePacked(t, uint8(1))));
+        return addmod(mulmod(hi, R256, R), lo, R);
+    }
+
+    /// acc += scalar * (x, y) with the ecMul (0x07) and ecAdd (0x06) precompiles.
+    function _mulAdd(
+        uint256[5] memory io,
+        uint256 x,
+        uint256 y,
+        uint256 scalar
+    ) internal view returns (bool ok) {
+        if (x >= Q || y >= Q) {
+            return false;
+        }
+        io[2] = x;
+        io[3] = y;
+        io[4] = scalar;
+        assembly {
+            let base := add(io, 0x40)
+            ok := staticcall(gas(), 0x07, base, 0x60, base, 0x40)
+            ok := and(ok, staticcall(gas(), 0x06, io, 0x80, io, 0x40))
+        }
+    }
+
+    function _neg(uint256 v) internal pure returns (uint256) {
+        return v == 0 ? 0 : R - v;
+    }
+}
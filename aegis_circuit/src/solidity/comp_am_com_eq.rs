@@ -0,0 +1,212 @@
+use std::marker::PhantomData;
+
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, Field, PrimeField};
+use sha3::{Digest, Keccak256};
+
+use crate::linker::{
+    am_com_eq::AmComEqError,
+    comp_am_com_eq::{CompAmComEqError, Instance, Proof, PublicParameters},
+    comp_dl_eq::CompDLEq,
+};
+
+use super::{abi, Solidity};
+
+const TEMPLATE: &str = include_str!("comp_am_com_eq.sol");
+
+/// Generator of a Solidity verifier for [`CompAmComEq`](crate::linker::comp_am_com_eq::CompAmComEq)
+/// proofs of fixed dimensions `(l, d0, d1, d2)` and fold threshold, on the alt_bn128
+/// precompiles.
+///
+/// The contract replays the transcript of `SHA3Base::new(false)`, so proofs must be created with
+/// a label-free `SHA3Base`. Public parameters, instances and proofs are passed as the
+/// `uint256[]` of their [`Solidity`] encoding.
+pub struct CompAmComEqVerifier<C: CurveGroup> {
+    pub l: usize,
+    pub d0: usize,
+    pub d1: usize,
+    pub d2: usize,
+    pub threshold: usize,
+    _group: PhantomData<C>,
+}
+
+impl<C: CurveGroup> CompAmComEqVerifier<C> {
+    /// Fails if there are no commitments or no rows, or if the threshold is zero.
+    pub fn new(
+        l: usize,
+        d0: usize,
+        d1: usize,
+        d2: usize,
+        threshold: usize,
+    ) -> Result<Self, CompAmComEqError> {
+        if l == 0 || d0 == 0 {
+            return Err(AmComEqError::MismatchParameterLength.into());
+        }
+        CompDLEq::<C>::check_threshold(threshold)?;
+        Ok(Self {
+            l,
+            d0,
+            d1,
+            d2,
+            threshold,
+            _group: PhantomData,
+        })
+    }
+
+    /// Dimensions and threshold of `pp`, which must hold `l` whole rows of length `d0`.
+    pub fn from_parameters(pp: &PublicParameters<C>) -> Result<Self, CompAmComEqError> {
+        let d0 = pp.coeff_ck.g.len();
+        if d0 == 0 || !pp.poly_ck.g.len().is_multiple_of(d0) {
+            return Err(AmComEqError::MismatchParameterLength.into());
+        }
+        Self::new(
+            pp.poly_ck.g.len() / d0,
+            d0,
+            pp.poly_ck.h.len(),
+            pp.coeff_ck.h.len(),
+            pp.threshold,
+        )
+    }
+
+    fn rounds(&self) -> usize {
+        CompDLEq::<C>::num_rounds(self.l * self.d0, self.threshold)
+    }
+
+    fn final_len(&self) -> usize {
+        CompDLEq::<C>::final_len(self.l * self.d0, self.threshold)
+    }
+
+    /// Source of the `CompAmComEqVerifier` contract.
+    pub fn contract(&self) -> String {
+        let r = C::ScalarField::MODULUS;
+        let q = <C::BaseField as Field>::BasePrimeField::MODULUS;
+        let r256 = C::ScalarField::from_be_bytes_mod_order(&[&[1u8][..], &[0u8; 32]].concat());
+        let final_len_le = abi::to_hex(&(self.final_len() as u64).to_le_bytes());
+
+        [
+            ("R", r.to_string()),
+            ("Q", q.to_string()),
+            ("R256", r256.into_bigint().to_string()),
+            ("L", self.l.to_string()),
+            ("D0", self.d0.to_string()),
+            ("D1", self.d1.to_string()),
+            ("D2", self.d2.to_string()),
+            ("N", (self.l * self.d0).to_string()),
+            ("ROUNDS", self.rounds().to_string()),
+            ("FINAL_LEN", self.final_len().to_string()),
+            ("FINAL_LEN_LE", final_len_le),
+        ]
+        .iter()
+        .fold(TEMPLATE.to_string(), |source, (key, value)| {
+            source.replace(&format!("{{{{{}}}}}", key), value)
+        })
+    }
+
+    /// Challenges `[rho, e, x, weight]` as the contract derives them from the `uint256[]`
+    /// encodings, or `None` if the encodings do not have the contract's lengths.
+    pub fn challenges(
+        &self,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+    ) -> Option<Vec<C::ScalarField>>
+    where
+        C::Affine: Solidity,
+        C::ScalarField: Solidity,
+    {
+        let instance = instance.to_solidity();
+        let proof = proof.to_solidity();
+        let (rounds, final_len) = (self.rounds(), self.final_len());
+        let p_a = final_len + self.d1 + self.d2;
+        if instance.len() != 2 + 2 * self.l || proof.len() != p_a + 4 + 8 * rounds {
+            return None;
+        }
+
+        let words = |xs: &[String]| xs.iter().flat_map(|x| abi::to_word(x)).collect::<Vec<_>>();
+        let challenge = |t: &[u8]| {
+            let bytes = [0u8, 1]
+                .iter()
+                .flat_map(|i| {
+                    Keccak256::new()
+                        .chain_update(t)
+                        .chain_update([*i])
+                        .finalize()
+                })
+                .collect::<Vec<_>>();
+            C::ScalarField::from_be_bytes_mod_order(&bytes)
+        };
+
+        let mut challenges = vec![];
+        let mut t = words(&instance);
+        for _ in 0..self.l {
+            challenges.push(challenge(&t));
+            t = challenges.last()?.into_bigint().to_bytes_be();
+        }
+        t.extend(words(&proof[p_a..p_a + 4]));
+        for r in 0..=rounds {
+            challenges.push(challenge(&t));
+            t = challenges.last()?.into_bigint().to_bytes_be();
+            if r < rounds {
+                let o = p_a + 4 + 8 * r;
+                t.extend(words(&proof[o..o + 8]));
+            }
+        }
+
+        t.extend(b"CompDLEq::final");
+        t.extend((final_len as u64).to_le_bytes());
+        for z in &proof[..final_len] {
+            t.extend(abi::to_word(z).iter().rev());
+        }
+        challenges.push(challenge(&t));
+        Some(challenges)
+    }
+
+    /// TypeScript module with the `uint256[]` arguments, the expected challenges and the
+    /// ABI-encoded constructor arguments and `verify` calldata.
+    pub fn fixture(
+        &self,
+        pp: &PublicParameters<C>,
+        instance: &Instance<C>,
+        proof: &Proof<C>,
+    ) -> String
+    where
+        C::Affine: Solidity,
+        C::ScalarField: Solidity,
+    {
+        let challenges = self
+            .challenges(instance, proof)
+            .map(|c| c.to_solidity())
+            .unwrap_or_default();
+        let (pp, instance, proof) = (
+            pp.to_solidity(),
+            instance.to_solidity(),
+            proof.to_solidity(),
+        );
+        let constructor_args = abi::encode_arrays(std::slice::from_ref(&pp));
+        let calldata = [
+            &abi::selector("verify(uint256[],uint256[])")[..],
+            &abi::encode_arrays(&[instance.clone(), proof.clone()]),
+        ]
+        .concat();
+
+        let name = format!("compAmComEq{}", self.l);
+        let dims = format!(
+            "const dims = {{ l: {}, d0: {}, d1: {}, d2: {}, threshold: {} }}",
+            self.l, self.d0, self.d1, self.d2, self.threshold
+        );
+        [
+            dims,
+            format!("const pp = {:?}", pp),
+            format!("const instance = {:?}", instance),
+            format!("const proof = {:?}", proof),
+            format!("const challenges = {:?}", challenges),
+            format!("const constructorArgs = {:?}", abi::to_hex(&constructor_args)),
+            format!("const calldata = {:?}", abi::to_hex(&calldata)),
+            format!(
+                "\nconst {} = {{ dims, pp, instance, proof, challenges, constructorArgs, calldata }}",
+                name,
+            ),
+            format!("\nexport default {}\n", name),
+        ]
+        .join("\n")
+    }
+}
@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.23;
+
+/// Verifier for compressed AmComEq proofs with l = {{L}}, d0 = {{D0}}, d1 = {{D1}}, d2 = {{D2}}.
+/// Generated by `CompAmComEqVerifier` in `aegis_circuit/src/solidity`, do not edit by hand.
+///
+/// The Fiat-Shamir transcript is `SHA3Base` without labels: every challenge is
+/// `keccak(t || 0) || keccak(t || 1)` reduced mod R, after which the transcript `t` is reset to
+/// the 32-byte challenge. Points are appended as big-endian `x || y`.
+contract CompAmComEqVerifier {
+    uint256 internal constant R = {{R}};
+    uint256 internal constant Q = {{Q}};
+    // 2^256 mod R, to reduce the 64-byte challenge expansion
+    uint256 internal constant R256 = {{R256}};
+
+    uint256 internal constant L = {{L}};
+    uint256 internal constant D0 = {{D0}};
+    uint256 internal constant D1 = {{D1}};
+    uint256 internal constant D2 = {{D2}};
+    uint256 internal constant N = {{N}};
+    // CompDLEq folds `N` generators down to `FINAL_LEN` in `ROUNDS` rounds
+    uint256 internal constant ROUNDS = {{ROUNDS}};
+    uint256 internal constant FINAL_LEN = {{FINAL_LEN}};
+    // `FINAL_LEN` as the little-endian u64 length prefix of the serialized response
+    bytes8 internal constant FINAL_LEN_LE = {{FINAL_LEN_LE}};
+
+    // pp = [l, d0, d1, d2, generator, poly_ck.g, poly_ck.h, coeff_ck.g, coeff_ck.h]
+    uint256 internal constant PP_G = 6;
+    uint256 internal constant PP_H = PP_G + 2 * N;
+    uint256 internal constant PP_G_HAT = PP_H + 2 * D1;
+    uint256 internal constant PP_H_HAT = PP_G_HAT + 2 * D0;
+    uint256 internal constant PP_LEN = PP_H_HAT + 2 * D2;
+
+    // instance = [c, c_hat]
+    uint256 internal constant INSTANCE_LEN = 2 + 2 * L;
+
+    // proof = [z, omega, omega_hat, a, a_hat, (left, right, left_hat, right_hat) * ROUNDS]
+    uint256 internal constant P_OMEGA = FINAL_LEN;
+    uint256 internal constant P_OMEGA_HAT = P_OMEGA + D1;
+    uint256 internal constant P_A = P_OMEGA_HAT + D2;
+    uint256 internal constant P_ROUNDS = P_A + 4;
+    uint256 internal constant PROOF_LEN = P_ROUNDS + 8 * ROUNDS;
+
+    struct Challenges {
+        uint256[] rho;
+        uint256 e;
+        uint256[] x;
+        uint256 weight;
+    }
+
+    uint256[] internal pp;
+
+    constructor(uint256[] memory _pp) {
+        require(_pp.length == PP_LEN, "CompAmComEq: invalid parameters length");
+        require(
+            _pp[0] == L && _pp[1] == D0 && _pp[2] == D1 && _pp[3] == D2,
+            "CompAmComEq: invalid dimensions"
+        );
+        pp = _pp;
+    }
+
+    function verify(
+        uint256[] calldata instance,
+        uint256[] calldata proof
+    ) public view returns (bool) {
+        (bool ok, Challenges memory ch) = _transcript(instance, proof);
+        if (!ok) {
+            return false;
+        }
+        return _check(pp, instance, proof, ch);
+    }
+
+    /// `[rho, e, x, weight]` as derived by the verifier, for comparison with the prover side.
+    function challenges(
+        uint256[] calldata instance,
+        uint256[] calldata proof
+    ) public pure returns (uint256[] memory result) {
+        (bool ok, Challenges memory ch) = _transcript(instance, proof);
+        require(ok, "CompAmComEq: malformed proof");
+        result = new uint256[](L + ROUNDS + 2);
+        for (uint256 i = 0; i < L; i++) {
+            result[i] = ch.rho[i];
+        }
+        result[L] = ch.e;
+        for (uint256 r = 0; r < ROUNDS; r++) {
+            result[L + 1 + r] = ch.x[r];
+        }
+        result[L + ROUNDS + 1] = ch.weight;
+    }
+
+    function _transcript(
+        uint256[] calldata instance,
+        uint256[] calldata proof
+    ) internal pure returns (bool ok, Challenges memory ch) {
+        if (instance.length != INSTANCE_LEN || proof.length != PROOF_LEN) {
+            return (false, ch);
+        }
+        for (uint256 i = 0; i < P_A; i++) {
+            if (proof[i] >= R) {
+                return (false, ch);
+            }
+        }
+        for (uint256 i = 0; i < INSTANCE_LEN; i += 2) {
+            if (instance[i] == 0 && instance[i + 1] == 0) {
+                return (false, ch);
+            }
+        }
+        for (uint256 i = P_A; i < PROOF_LEN; i += 2) {
+            if (proof[i] == 0 && proof[i + 1] == 0) {
+                return (false, ch);
+            }
+        }
+
+        // AmComEq: coefficients from the instance, then the challenge on the commitment
+        bytes memory t = abi.encodePacked(instance);
+        ch.rho = new uint256[](L);
+        for (uint256 i = 0; i < L; i++) {
+            ch.rho[i] = _challenge(t);
+            t = abi.encodePacked(ch.rho[i]);
+        }
+        t = abi.encodePacked(t, proof[P_A], proof[P_A + 1], proof[P_A + 2], proof[P_A + 3]);
+        ch.e = _challenge(t);
+        t = abi.encodePacked(ch.e);
+
+        // CompDLEq: one challenge per folding round
+        ch.x = new uint256[](ROUNDS);
+        for (uint256 r = 0; r < ROUNDS; r++) {
+            uint256 o = P_ROUNDS + 8 * r;
+            t = abi.encodePacked(
+                t,
+                abi.encodePacked(proof[o], proof[o + 1], proof[o + 2], proof[o + 3]),
+                abi.encodePacked(proof[o + 4], proof[o + 5], proof[o + 6], proof[o + 7])
+            );
+            ch.x[r] = _challenge(t);
+            t = abi.encodePacked(ch.x[r]);
+        }
+
+        // final weight, forked with the little-endian serialization of the response
+        t = abi.encodePacked(t, "CompDLEq::final", FINAL_LEN_LE);
+        for (uint256 i = 0; i < FINAL_LEN; i++) {
+            t = abi.encodePacked(t, _reverse(proof[i]));
+        }
+        ch.weight = _challenge(t);
+        ok = true;
+    }
+
+    function _check(
+        uint256[] memory _pp,
+        uint256[] calldata instance,
+        uint256[] calldata proof,
+        Challenges memory ch
+    ) internal view returns (bool) {
+        // (P_0, P_1, ..., P_ROUNDS) with P_r the product of the challenges from round r on
+        uint256[] memory p = new uint256[](ROUNDS + 1);
+        p[ROUNDS] = 1;
+        for (uint256 r = ROUNDS; r > 0; r--) {
+            p[r - 1] = mulmod(p[r], ch.x[r - 1], R);
+        }
+
+        // io = [acc.x, acc.y, base.x, base.y, scalar]
+        uint256[5] memory io;
+        return
+            _accumulateGenerators(io, _pp, _expandResponse(proof, ch.x), ch) &&
+            _accumulateInstance(io, _pp, instance, proof, ch, p[0]) &&
+            _accumulateRounds(io, proof, ch, p) &&
+            io[0] == 0 &&
+            io[1] == 0;
+    }
+
+    /// <g, s> + weight * <g_hat, s> with g_hat_{i * D0 + b} = rho_i * coeff_ck.g_b
+    function _accumulateGenerators(
+        uint256[5] memory io,
+        uint256[] memory _pp,
+        uint256[] memory s,
+        Challenges memory ch
+    ) internal view returns (bool ok) {
+        ok = true;
+        for (uint256 j = 0; j < N; j++) {
+            ok = ok && _mulAdd(io, _pp[PP_G + 2 * j], _pp[PP_G + 2 * j + 1], s[j]);
+        }
+        for (uint256 b = 0; b < D0; b++) {
+            uint256 scalar = 0;
+            for (uint256 i = 0; i < L; i++) {
+                scalar = addmod(scalar, mulmod(s[i * D0 + b], ch.rho[i], R), R);
+            }
+            scalar = mulmod(scalar, ch.weight, R);
+            ok = ok && _mulAdd(io, _pp[PP_G_HAT + 2 * b], _pp[PP_G_HAT + 2 * b + 1], scalar);
+        }
+    }
+
+    /// - P_0 * (y + weight * y_hat) with y = a + e * c - <h, omega> and
+    /// y_hat = a_hat + e * <rho, c_hat> - <h_hat, omega_hat>
+    function _accumulateInstance(
+        uint256[5] memory io,
+        uint256[] memory _pp,
+        uint256[] calldata instance,
+        uint256[] calldata proof,
+        Challenges memory ch,
+        uint256 p0
+    ) internal view returns (bool ok) {
+        ok = _mulAdd(io, proof[P_A], proof[P_A + 1], _neg(p0));
+        ok = ok && _mulAdd(io, instance[0], instance[1], _neg(mulmod(p0, ch.e, R)));
+        for (uint256 k = 0; k < D1; k++) {
+            uint256 scalar = mulmod(p0, proof[P_OMEGA + k], R);
+            ok = ok && _mulAdd(io, _pp[PP_H + 2 * k], _pp[PP_H + 2 * k + 1], scalar);
+        }
+
+        uint256 pw = mulmod(p0, ch.weight, R);
+        ok = ok && _mulAdd(io, proof[P_A + 2], proof[P_A + 3], _neg(pw));
+        for (uint256 i = 0; i < L; i++) {
+            uint256 scalar = _neg(mulmod(mulmod(pw, ch.e, R), ch.rho[i], R));
+            ok = ok && _mulAdd(io, instance[2 + 2 * i], instance[3 + 2 * i], scalar);
+        }
+        for (uint256 k = 0; k < D2; k++) {
+            uint256 scalar = mulmod(pw, proof[P_OMEGA_HAT + k], R);
+            ok = ok && _mulAdd(io, _pp[PP_H_HAT + 2 * k], _pp[PP_H_HAT + 2 * k + 1], scalar);
+        }
+    }
+
+    /// - P_{r+1} * (left + x^2 * right + weight * (left_hat + x^2 * right_hat))
+    function _accumulateRounds(
+        uint256[5] memory io,
+        uint256[] calldata proof,
+        Challenges memory ch,
+        uint256[] memory p
+    ) internal view returns (bool ok) {
+        ok = true;
+        for (uint256 r = 0; r < ROUNDS; r++) {
+            uint256 o = P_ROUNDS + 8 * r;
+            uint256 left = _neg(p[r + 1]);
+            uint256 right = mulmod(left, mulmod(ch.x[r], ch.x[r], R), R);
+            ok = ok && _mulAdd(io, proof[o], proof[o + 1], left);
+            ok = ok && _mulAdd(io, proof[o + 2], proof[o + 3], right);
+            ok = ok && _mulAdd(io, proof[o + 4], proof[o + 5], mulmod(left, ch.weight, R));
+            ok = ok && _mulAdd(io, proof[o + 6], proof[o + 7], mulmod(right, ch.weight, R));
+        }
+    }
+
+    /// Scalars of the original generators in `<g_final, z>`, unrolled from the last round.
+    function _expandResponse(
+        uint256[] calldata proof,
+        uint256[] memory x
+    ) internal pure returns (uint256[] memory s) {
+        uint256[] memory lens = new uint256[](ROUNDS + 1);
+        lens[0] = N;
+        for (uint256 r = 0; r < ROUNDS; r++) {
+            lens[r + 1] = (lens[r] + 1) / 2;
+        }
+
+        s = new uint256[](N);
+        for (uint256 i = 0; i < FINAL_LEN; i++) {
+            s[i] = proof[i];
+        }
+        for (uint256 r = ROUNDS; r > 0; r--) {
+            uint256 mid = lens[r];
+            uint256 right = lens[r - 1] - mid;
+            // the right half reuses the unscaled scalars before the left half is scaled
+            for (uint256 i = 0; i < right; i++) {
+                s[mid + i] = s[i];
+            }
+            for (uint256 i = 0; i < mid; i++) {
+                s[i] = mulmod(s[i], x[r - 1], R);
+            }
+        }
+    }
+
+    function _challenge(bytes memory t) internal pure returns (uint256) {
+        uint256 hi = uint256(keccak256(abi.encodePacked(t, uint8(0))));
+        uint256 lo = uint256(keccak256(abi.encodePacked(t, uint8(1))));
+        return addmod(mulmod(hi, R256, R), lo, R);
+    }
+
+    /// acc += scalar * (x, y) with the ecMul (0x07) and ecAdd (0x06) precompiles.
+    function _mulAdd(
+        uint256[5] memory io,
+        uint256 x,
+        uint256 y,
+        uint256 scalar
+    ) internal view returns (bool ok) {
+        if (x >= Q || y >= Q) {
+            return false;
+        }
+        io[2] = x;
+        io[3] = y;
+        io[4] = scalar;
+        assembly {
+            let base := add(io, 0x40)
+            ok := staticcall(gas(), 0x07, base, 0x60, base, 0x40)
+            ok := and(ok, staticcall(gas(), 0x06, io, 0x80, io, 0x40))
+        }
+    }
+
+    function _neg(uint256 v) internal pure returns (uint256) {
+        return v == 0 ? 0 : R - v;
+    }
+
+    /// Byte order swap, for the little-endian serialization of scalars.
+    function _reverse(uint256 v) internal pure returns (uint256) {
+        v =
+            ((v & 0xFF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00) >> 8) |
+            ((v & 0x00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF) << 8);
+        v =
+            ((v & 0xFFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000) >> 16) |
+            ((v & 0x0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF) << 16);
+        v =
+            ((v & 0xFFFFFFFF00000000FFFFFFFF00000000FFFFFFFF00000000FFFFFFFF00000000) >> 32) |
+            ((v & 0x00000000FFFFFFFF00000000FFFFFFFF00000000FFFFFFFF00000000FFFFFFFF) << 32);
+        v =
+            ((v & 0xFFFFFFFFFFFFFFFF0000000000000000FFFFFFFFFFFFFFFF0000000000000000) >> 64) |
+            ((v & 0x0000000000000000FFFFFFFFFFFFFFFF0000000000000000FFFFFFFFFFFFFFFF) << 64);
+        return (v >> 128) | (v << 128);
+    }
+}
@@ -1,5 +1,11 @@
+pub mod abi;
+mod com_enc_eq;
+mod comp_am_com_eq;
 mod short_weierstrass;
 mod twisted_edwards;
+
+pub use com_enc_eq::ComEncEqVerifier;
+pub use comp_am_com_eq::CompAmComEqVerifier;
 use std::fmt::Display;
 
 use ark_ff::{Fp, Fp2, Fp2Config, FpConfig};
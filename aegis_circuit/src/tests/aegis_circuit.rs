@@ -1,32 +1,31 @@
-use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
-use ark_ff::PrimeField;
-use ark_ff::Zero;
-use ark_r1cs_std::eq::EqGadget;
-use ark_r1cs_std::{
-    alloc::AllocVar,
-    bits::ToBitsGadget,
-    fields::{fp::FpVar, FieldVar},
-    prelude::Boolean,
-};
-use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{PrimeField, Zero};
+use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, SynthesisError};
 use rand::{CryptoRng, RngCore};
-use rayon::prelude::*; // Add this line
+use rayon::prelude::*;
 use std::fs::File;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::time::Instant;
 
-use crate::crypto::commitment;
 use crate::solidity::Solidity;
 use crate::{
-    crypto::commitment::{
-        pedersen::{Pedersen, PedersenGadget},
-        BatchCommitmentGadget, BatchCommitmentScheme,
+    aegis::{Aegis, AegisCircuit, AegisError, BatchUpdate},
+    crypto::{
+        commitment::{
+            pedersen::{Pedersen, PedersenGadget, PedersenHornerGadget},
+            BatchCommitmentGadget, BatchCommitmentScheme,
+        },
+        dlog,
+        range::{self, RangeGadget},
     },
-    gro::{CCGroth16, Commitment, CommittingKey, Proof, ProvingKey, VerifyingKey},
-    snark::{CircuitSpecificSetupCCSNARK, CCSNARK},
+    gro::{CommittingKey, Proof, VerifyingKey},
 };
 
+/// Range-check width of the amounts.
+const BITS: usize = 64;
+
 fn test_delta_commitment<F: PrimeField>(num_commitments: usize, length: usize) -> Vec<Vec<F>> {
     let mut commitments = vec![];
     for _ in 0..(num_commitments >> 1) {
@@ -36,224 +35,211 @@ fn test_delta_commitment<F: PrimeField>(num_commitments: usize, length: usize) -
     commitments
 }
 
-#[derive(Clone)]
-struct AegisCircuit<C: CurveGroup> {
-    // public input
-    pub tau: Option<C::ScalarField>,
-
-    // committed witness
-    pub aggregation: Option<Vec<C::ScalarField>>,
-    pub curr_commitments: Option<Vec<Vec<C::ScalarField>>>,
-    pub prev_commitments: Option<Vec<Vec<C::ScalarField>>>,
+fn batch_commit<E: Pairing>(
+    ck: &CommittingKey<E>,
+    cm: &Vec<Vec<E::ScalarField>>,
+) -> Vec<E::G1Affine> {
+    let slices = cfg_iter!(cm).map(|cm| &cm[..]).collect::<Vec<_>>();
+    Pedersen::<E::G1>::batch_commit(&ck.batch_g1, &slices)
 }
 
-impl<C: CurveGroup> AegisCircuit<C> {
-    pub fn new(
-        tau: C::ScalarField,
-        curr_commitments: Vec<Vec<C::ScalarField>>,
-        prev_commitments: Vec<Vec<C::ScalarField>>,
-    ) -> Self {
-        let delta_commitments = prev_commitments
-            .iter()
-            .zip(curr_commitments.iter())
-            .map(|(prev, curr)| {
-                curr.iter()
-                    .zip(prev.iter())
-                    .map(|(curr, prev)| *curr - *prev)
-                    .collect::<Vec<C::ScalarField>>()
-            })
-            .collect::<Vec<_>>();
-        let commitments = [&curr_commitments[..], &delta_commitments[..]].concat();
-        let slices: Vec<&[C::ScalarField]> = commitments.iter().map(|cm| &cm[..]).collect();
-        let (aggregation, _) = Pedersen::<C>::scalar_aggregate(&slices[..], tau, None);
-
-        Self {
-            tau: Some(tau),
-            aggregation: Some(aggregation),
-            curr_commitments: Some(curr_commitments),
-            prev_commitments: Some(prev_commitments),
-        }
-    }
+/// Prove a DBT batch, write the verification time, and reject invalid dimensions and a
+/// tampered commitment.
+fn aegis_prove_and_verify<E: Pairing, R: RngCore + CryptoRng>(
+    batch_size: usize,
+    length: usize,
+    rng: &mut R,
+) -> (
+    Vec<E::G1Affine>,
+    Proof<E>,
+    VerifyingKey<E>,
+    CommittingKey<E>,
+) {
+    let mut file = OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open("./src/tests/circuit_result.txt")
+        .unwrap();
+    let (pk, vk, ck) = Aegis::<E>::setup(batch_size, length, BITS, rng).unwrap();
+    assert_eq!(Aegis::<E>::dimensions(&ck), Ok((batch_size, length)));
+    // [1, tau, net_issuance]
+    assert_eq!(vk.gamma_abc_g1.len(), 3);
+
+    let update = BatchUpdate::from_delta(
+        test_commitments(batch_size, length),
+        &test_delta_commitment(batch_size, length),
+    );
+    let commitment = Aegis::<E>::commit(&ck, &update, rng).unwrap();
+    let zero = E::ScalarField::zero();
+    let proof = Aegis::<E>::prove(&pk, update.clone(), zero, &commitment, rng).unwrap();
 
-    pub fn mock(batch_size: usize) -> Self {
-        Self {
-            tau: Some(C::ScalarField::zero()),
-            aggregation: Some(vec![C::ScalarField::zero(); 2]),
-            curr_commitments: Some(vec![vec![C::ScalarField::zero(); 2]; batch_size]),
-            prev_commitments: Some(vec![vec![C::ScalarField::zero(); 2]; batch_size]),
-        }
-    }
-}
+    let verify_start = Instant::now();
+    assert!(Aegis::<E>::verify(&vk, &commitment.commitments, zero, &proof).unwrap());
+    writeln!(file, "Verify: {:?}", verify_start.elapsed()).unwrap();
 
-impl<C: CurveGroup> ConstraintSynthesizer<C::ScalarField> for AegisCircuit<C> {
-    fn generate_constraints(
-        self,
-        cs: ConstraintSystemRef<C::ScalarField>,
-    ) -> ark_relations::r1cs::Result<()> {
-        let tau = FpVar::new_input(cs.clone(), || {
-            self.tau.ok_or_else(|| SynthesisError::AssignmentMissing)
-        })?;
-
-        let aggregation = Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || {
-            self.aggregation
-                .ok_or_else(|| SynthesisError::AssignmentMissing)
-        })?;
-
-        let current_commitments = self
-            .curr_commitments
-            .ok_or_else(|| SynthesisError::AssignmentMissing)?
-            .into_iter()
-            .map(|cm| Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || Ok(cm)))
-            .collect::<Result<Vec<_>, SynthesisError>>()?;
-
-        let prev_commitments = self
-            .prev_commitments
-            .ok_or_else(|| SynthesisError::AssignmentMissing)?
-            .into_iter()
-            .map(|cm| Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || Ok(cm)))
-            .collect::<Result<Vec<_>, SynthesisError>>()?;
-
-        let max_bytes: [u8; 8] = (std::u64::MAX - 1).to_le_bytes();
-        let constant_max = <C::ScalarField>::from_le_bytes_mod_order(&max_bytes);
-        let zero = FpVar::new_constant(cs.clone(), C::ScalarField::zero())?;
-        let mut sum = zero.clone();
-
-        let mut delta_commitments =
-            Vec::<Vec<FpVar<C::ScalarField>>>::with_capacity(current_commitments.len());
-        for (prev, curr) in prev_commitments.iter().zip(current_commitments.iter()) {
-            let delta = prev
-                .iter()
-                .zip(curr.iter())
-                .map(|(prev, curr)| curr - prev)
-                .collect::<Vec<_>>();
-            sum += delta[0].clone();
-            delta_commitments.push(delta);
-
-            Boolean::enforce_smaller_or_equal_than_le(
-                curr[0].to_non_unique_bits_le().unwrap().as_slice(),
-                constant_max.into_bigint(),
-            )?;
-        }
+    let mut tampered = commitment.commitments.clone();
+    tampered.swap(0, batch_size);
+    assert!(!Aegis::<E>::verify(&vk, &tampered, zero, &proof).unwrap());
+    assert_eq!(
+        Aegis::<E>::verify(&vk, &tampered[1..], zero, &proof),
+        Err(AegisError::MismatchParameterLength)
+    );
 
-        // let zero = FpVar::<C::ScalarField>::zero();
-        // sum.enforce_equal(&zero)
-        //     .expect("Summation of delta amounts must be zero.");
-
-        let commitments =
-            [current_commitments, delta_commitments].concat::<Vec<FpVar<C::ScalarField>>>();
-        PedersenGadget::<C, FpVar<C::ScalarField>>::enforce_equal(
-            aggregation,
-            commitments,
-            tau,
-            None,
-        )
-        .expect("Aggregation Check");
+    let short = BatchUpdate::new(update.prev[1..].to_vec(), update.curr[1..].to_vec());
+    assert_eq!(
+        Aegis::<E>::commit(&ck, &short, rng),
+        Err(AegisError::MismatchParameterLength)
+    );
+    assert_eq!(
+        Aegis::<E>::setup(batch_size, 0, BITS, rng).map(|_| ()),
+        Err(AegisError::InvalidDimensions)
+    );
+    assert_eq!(
+        Aegis::<E>::setup(batch_size, length, 0, rng).map(|_| ()),
+        Err(AegisError::InvalidDimensions)
+    );
 
-        Ok(())
-    }
+    (commitment.commitments, proof, vk, ck)
 }
 
-fn aegis_circuit_setup<E: Pairing, R: RngCore + CryptoRng>(
+/// Mint and burn `amount` in the first account, and reject an unbalanced batch claiming a
+/// different net issuance both in the prover and in the circuit.
+fn aegis_net_issuance<E: Pairing, R: RngCore + CryptoRng>(
     batch_size: usize,
+    length: usize,
     rng: &mut R,
-) -> (ProvingKey<E>, VerifyingKey<E>, CommittingKey<E>) {
-    let num_aggregation_variables = 2;
-    let num_committed_witness_variables = num_aggregation_variables * (1 + 2 * batch_size);
-
-    let mock = AegisCircuit::<E::G1>::mock(batch_size);
-    CCGroth16::<E>::setup(
-        mock,
-        num_aggregation_variables,
-        num_committed_witness_variables,
-        rng,
-    )
-    .unwrap()
-}
-
-fn aegis_circuit_commit<E: Pairing, R: RngCore + CryptoRng>(
-    ck: &CommittingKey<E>,
-    prev: &Vec<Vec<E::ScalarField>>,
-    delta: &Vec<Vec<E::ScalarField>>,
-    curr: &Vec<Vec<E::ScalarField>>,
-    rng: &mut R,
-) -> (Vec<E::G1Affine>, Commitment<E>, E::ScalarField) {
-    let batch_size = delta.len();
-
-    // Generage Proof Dependent Commitment
-    let commitments = [&curr[..], &prev[..]].concat();
-    let committed_witness = cfg_iter!(commitments)
-        .flat_map(|cm| cfg_iter!(cm).cloned())
-        .collect::<Vec<_>>();
-    drop(commitments);
-
-    let proof_dependent_commitment =
-        CCGroth16::<E>::commit(&ck, &committed_witness[..], rng).unwrap();
-
-    // Batch Commitment Module
-    let commitments = [&curr[..], &delta[..]].concat();
-    let slices = cfg_iter!(commitments).map(|cm| &cm[..]).collect::<Vec<_>>();
-    let commitments_g1 = Pedersen::<E::G1>::batch_commit(&ck.batch_g1, &slices);
-    let tau = Pedersen::<E::G1>::challenge(
-        &[],
-        &commitments_g1[batch_size..],
-        &proof_dependent_commitment.cm,
-    );
-    (commitments_g1, proof_dependent_commitment, tau)
+) {
+    let (pk, vk, ck) = Aegis::<E>::setup(batch_size, length, BITS, rng).unwrap();
+    let zero = E::ScalarField::zero();
+    let amount = E::ScalarField::from(5u64);
+
+    for issuance in [amount, -amount] {
+        let mut delta = test_delta_commitment(batch_size, length);
+        delta[0][0] += issuance;
+        let update = BatchUpdate::from_delta(test_commitments(batch_size, length), &delta);
+        assert_eq!(update.net_issuance(), issuance);
+
+        let commitment = Aegis::<E>::commit(&ck, &update, rng).unwrap();
+        let proof = Aegis::<E>::prove(&pk, update.clone(), issuance, &commitment, rng).unwrap();
+        assert!(Aegis::<E>::verify(&vk, &commitment.commitments, issuance, &proof).unwrap());
+        assert!(!Aegis::<E>::verify(&vk, &commitment.commitments, zero, &proof).unwrap());
+        assert!(!Aegis::<E>::verify(&vk, &commitment.commitments, -issuance, &proof).unwrap());
+
+        // an honest prover refuses to hide the issuance
+        assert_eq!(
+            Aegis::<E>::prove(&pk, update.clone(), zero, &commitment, rng),
+            Err(AegisError::Unbalanced)
+        );
+
+        // the circuit rejects the unbalanced batch claiming a zero net issuance
+        let forged = AegisCircuit::<E::G1>::new(commitment.tau, zero, BITS, update);
+        let cs = ark_relations::r1cs::ConstraintSystem::<E::ScalarField>::new_ref();
+        forged.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
 }
 
-fn batch_commit<E: Pairing>(
-    ck: &CommittingKey<E>,
-    cm: &Vec<Vec<E::ScalarField>>,
-) -> Vec<E::G1Affine> {
-    let slices = cfg_iter!(cm).map(|cm| &cm[..]).collect::<Vec<_>>();
-    let commitments_g1 = Pedersen::<E::G1>::batch_commit(&ck.batch_g1, &slices);
-    commitments_g1
+/// Accept the largest balance of `[0, 2^bits)`, and reject a balance of `2^bits` and a delta
+/// below `-2^bits` in the prover and in the circuit.
+fn aegis_range<E: Pairing, R: RngCore + CryptoRng>(batch_size: usize, bits: usize, rng: &mut R) {
+    let (pk, vk, ck) = Aegis::<E>::setup(batch_size, 2, bits, rng).unwrap();
+    let bound = E::ScalarField::from(1u64 << bits);
+    let one = E::ScalarField::from(1u64);
+
+    // mint up to the largest balance, from 100 + 1
+    let mut delta = test_delta_commitment(batch_size, 2);
+    delta[0][0] += bound - E::ScalarField::from(102u64);
+    let update = BatchUpdate::from_delta(test_commitments(batch_size, 2), &delta);
+    assert_eq!(update.curr[0][0], bound - one);
+
+    let issuance = update.net_issuance();
+    let commitment = Aegis::<E>::commit(&ck, &update, rng).unwrap();
+    let proof = Aegis::<E>::prove(&pk, update, issuance, &commitment, rng).unwrap();
+    assert!(Aegis::<E>::verify(&vk, &commitment.commitments, issuance, &proof).unwrap());
+
+    // one more overflows the balance with an in-range delta
+    delta[0][0] += one;
+    let overflow = BatchUpdate::from_delta(test_commitments(batch_size, 2), &delta);
+
+    // a burn of 2^bits + 1 from a balance the previous batch could not have produced
+    let mut prev = test_commitments(batch_size, 2);
+    prev[0][0] += bound;
+    let mut delta = test_delta_commitment(batch_size, 2);
+    delta[0][0] = -(bound + one);
+    let drain = BatchUpdate::from_delta(prev, &delta);
+
+    for update in [overflow, drain] {
+        let issuance = update.net_issuance();
+        let commitment = Aegis::<E>::commit(&ck, &update, rng).unwrap();
+        assert_eq!(
+            Aegis::<E>::prove(&pk, update.clone(), issuance, &commitment, rng),
+            Err(AegisError::OutOfRange)
+        );
+
+        let forged = AegisCircuit::<E::G1>::new(commitment.tau, issuance, bits, update);
+        let cs = ark_relations::r1cs::ConstraintSystem::<E::ScalarField>::new_ref();
+        forged.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
 }
 
-fn aegis_circuit_prove_and_verify<E: Pairing, R: RngCore + CryptoRng>(
-    pk: &ProvingKey<E>,
-    vk: &VerifyingKey<E>,
-    circuit: AegisCircuit<E::G1>,
-    commitments: &Vec<E::G1Affine>,
-    proof_dependent_commitment: &Commitment<E>,
-    rng: &mut R,
-) -> Proof<E> {
-    let mut file = OpenOptions::new()
-        .write(true)
-        .append(true)
-        .create(true)
-        .open("./src/tests/circuit_result.txt")
-        .unwrap();
-    let proof = CCGroth16::<E>::prove(&pk, circuit.clone(), &proof_dependent_commitment, rng)
-        .expect("Failed: Proof Generation");
-
-    let tau = circuit.tau.unwrap();
-    let public_inputs = [tau];
-
-    let aggregate_start = Instant::now();
-    let aggregate_timer = start_timer!(|| "Aggregate Commitments");
-    let (aggregation_g1, _) = Pedersen::<E::G1>::aggregate(commitments, tau, None);
-    end_timer!(aggregate_timer);
-    let aggregate_time = aggregate_start.elapsed();
-    writeln!(file, "Aggregate: {:?}", aggregate_time).unwrap();
-
-    let mut verify = proof.clone();
-    let aggregation = aggregation_g1.into_group();
-    let _aggregation = circuit.aggregation.clone().unwrap();
-    let _aggregation = vk.ck.batch_g1[0].into_group() * _aggregation[0]
-        + vk.ck.batch_g1[1].into_group() * _aggregation[1];
+/// Accept the widest `bits` the auditor can decrypt and whose deltas over `batch_size` accounts
+/// sum without wrapping around, and reject one more bit in the keys, the prover and the circuit.
+fn aegis_batch_bits<E: Pairing, R: RngCore + CryptoRng>(batch_size: usize, rng: &mut R) {
+    let log_batch = batch_size.next_power_of_two().trailing_zeros() as usize;
+    let widest = dlog::MAX_BOUND_BITS.min(range::max_bits::<E::ScalarField>() - log_batch - 1);
+    assert_eq!(Aegis::<E>::check_bits(widest, batch_size), Ok(()));
     assert_eq!(
-        aggregation.into_affine(),
-        _aggregation.into_affine(),
-        "Invalid Aggregation"
+        Aegis::<E>::check_bits(widest + 1, batch_size),
+        Err(AegisError::InvalidDimensions)
+    );
+    assert_eq!(
+        Aegis::<E>::setup(batch_size, 2, widest + 1, rng).map(|_| ()),
+        Err(AegisError::InvalidDimensions)
     );
-    verify.d = (aggregation + verify.d.into_group()).into_affine();
 
-    assert!(
-        CCGroth16::<E>::verify(&vk, &public_inputs, &verify).unwrap(),
-        "Invalid Proof"
+    let (mut pk, _, ck) = Aegis::<E>::setup(batch_size, 2, BITS, rng).unwrap();
+    assert_eq!(pk.bits, BITS);
+    let update = BatchUpdate::from_delta(
+        test_commitments(batch_size, 2),
+        &test_delta_commitment(batch_size, 2),
+    );
+    let issuance = update.net_issuance();
+    let commitment = Aegis::<E>::commit(&ck, &update, rng).unwrap();
+    pk.bits = widest + 1;
+    assert_eq!(
+        Aegis::<E>::prove(&pk, update.clone(), issuance, &commitment, rng),
+        Err(AegisError::InvalidDimensions)
     );
-    proof
+
+    for (bits, satisfiable) in [(widest, true), (widest + 1, false)] {
+        let circuit = AegisCircuit::<E::G1>::new(commitment.tau, issuance, bits, update.clone());
+        let cs = ark_relations::r1cs::ConstraintSystem::<E::ScalarField>::new_ref();
+        match satisfiable {
+            true => {
+                circuit.generate_constraints(cs.clone()).unwrap();
+                assert!(cs.is_satisfied().unwrap());
+            }
+            false => assert_eq!(
+                circuit.generate_constraints(cs),
+                Err(SynthesisError::Unsatisfiable)
+            ),
+        }
+    }
+}
+
+/// Number of constraints added by the range checks of a batch, which must match
+/// [`AegisCircuit::range_constraints`].
+fn range_constraints<C: CurveGroup>(batch_size: usize, bits: usize) -> usize {
+    let cs = ark_relations::r1cs::ConstraintSystem::<C::ScalarField>::new_ref();
+    for _ in 0..batch_size {
+        let amount = FpVar::new_witness(cs.clone(), || Ok(C::ScalarField::zero())).unwrap();
+        let delta = FpVar::new_witness(cs.clone(), || Ok(-C::ScalarField::from(1u64))).unwrap();
+        RangeGadget::enforce_range(&amount, bits).unwrap();
+        RangeGadget::enforce_signed_range(&delta, bits).unwrap();
+    }
+    assert!(cs.is_satisfied().unwrap());
+    cs.num_constraints()
 }
 
 fn test_commitments<F: PrimeField>(num_commitments: usize, length: usize) -> Vec<Vec<F>> {
@@ -272,11 +258,16 @@ fn aegis_circuit_solidity<E: Pairing>(
     proof: &Proof<E>,
     vk: &VerifyingKey<E>,
     prev_cm: &Vec<E::G1Affine>,
+    net_issuance: E::ScalarField,
 ) where
     E::G1Affine: Solidity,
     E::G2Affine: Solidity,
     E::ScalarField: Solidity,
 {
+    // alpha, beta, gamma, delta and gamma_abc for [1, tau, net_issuance], as Aegis.sol expects
+    assert_eq!(vk.gamma_abc_g1.len(), 3);
+    assert_eq!(vk.to_solidity().len(), 20);
+
     let mut file =
         File::create("../aegis_contract/result/dbtData.ts").expect("Unable to create file");
 
@@ -291,7 +282,17 @@ fn aegis_circuit_solidity<E: Pairing>(
     .unwrap();
     writeln!(file, "const prevCm = {:?}", prev_cm[0].to_solidity()).unwrap();
     writeln!(file, "const proof = {:?}", proof.to_solidity()).unwrap();
-    writeln!(file, "const dbt = {{ cm: cm, proof: proof }}").unwrap();
+    writeln!(
+        file,
+        "const netIssuance = {:?}",
+        net_issuance.to_solidity()[0]
+    )
+    .unwrap();
+    writeln!(
+        file,
+        "const dbt = {{ cm: cm, proof: proof, netIssuance: netIssuance }}"
+    )
+    .unwrap();
     writeln!(
         file,
         "\nconst batch{} = {{ batchSize, vk, ck, dbt, prevCm }}",
@@ -301,6 +302,38 @@ fn aegis_circuit_solidity<E: Pairing>(
     writeln!(file, "\nexport default batch{}\n", batch_size).unwrap();
 }
 
+/// Number of constraints added by the aggregation gadget `G` for the DBT aggregation
+/// of a batch (`batch_size` current and `batch_size` delta commitments of length 2).
+fn aggregation_constraints<C, G>(batch_size: usize) -> usize
+where
+    C: CurveGroup,
+    G: BatchCommitmentGadget<
+        Pedersen<C>,
+        C::ScalarField,
+        ScalarVar = FpVar<C::ScalarField>,
+        ChallengeVar = FpVar<C::ScalarField>,
+    >,
+{
+    let cs = ark_relations::r1cs::ConstraintSystem::<C::ScalarField>::new_ref();
+    let tau = FpVar::new_input(cs.clone(), || Ok(C::ScalarField::zero())).unwrap();
+    let aggregation = Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || {
+        Ok(vec![C::ScalarField::zero(); 2])
+    })
+    .unwrap();
+    let commitments = (0..2 * batch_size)
+        .map(|_| {
+            Vec::<FpVar<C::ScalarField>>::new_witness(cs.clone(), || {
+                Ok(vec![C::ScalarField::zero(); 2])
+            })
+        })
+        .collect::<Result<Vec<_>, SynthesisError>>()
+        .unwrap();
+
+    G::enforce_equal(aggregation, commitments, tau, None).unwrap();
+    assert!(cs.is_satisfied().unwrap());
+    cs.num_constraints()
+}
+
 pub mod bn254 {
     use std::fs;
 
@@ -333,7 +366,7 @@ pub mod bn254 {
     fn aegis_constraint() {
         for n in *LOG_MIN..=*LOG_MAX {
             let batch_size = 1 << n;
-            let circuit = AegisCircuit::<C>::mock(batch_size);
+            let circuit = AegisCircuit::<C>::mock(batch_size, 2, BITS);
             let cs = ark_relations::r1cs::ConstraintSystem::new_ref();
             circuit.generate_constraints(cs.clone()).unwrap();
             assert!(cs.is_satisfied().unwrap());
@@ -345,6 +378,64 @@ pub mod bn254 {
         }
     }
 
+    #[test]
+    fn aggregation_gadget_constraints() {
+        println!("| log batch | pedersen | horner | saved |");
+        println!("| --- | --- | --- | --- |");
+        for n in *LOG_MIN..=*LOG_MAX {
+            let batch_size = 1 << n;
+            let pedersen = aggregation_constraints::<C, PedersenGadget<C, FpVar<F>>>(batch_size);
+            let horner =
+                aggregation_constraints::<C, PedersenHornerGadget<C, FpVar<F>>>(batch_size);
+            assert!(horner < pedersen);
+            println!(
+                "| {} | {} | {} | {} |",
+                n,
+                pedersen,
+                horner,
+                pedersen - horner
+            );
+        }
+    }
+
+    #[test]
+    fn aegis_net_issuance_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for n in *LOG_MIN..=*LOG_MAX {
+            aegis_net_issuance::<E, R>(1 << n, 2, &mut rng);
+        }
+    }
+
+    #[test]
+    fn aegis_range_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        aegis_range::<E, R>(1 << *LOG_MIN, 8, &mut rng);
+    }
+
+    #[test]
+    fn aegis_batch_bits_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for batch_size in [2, 6, 1 << *LOG_MIN] {
+            aegis_batch_bits::<E, R>(batch_size, &mut rng);
+        }
+    }
+
+    #[test]
+    fn range_gadget_constraints() {
+        for n in *LOG_MIN..=*LOG_MAX {
+            let batch_size = 1 << n;
+            for bits in [32, BITS] {
+                // `bits + 1` for the balance and `bits + 2` for the delta
+                let expected = batch_size * (2 * bits + 3);
+                assert_eq!(range_constraints::<C>(batch_size, bits), expected);
+                assert_eq!(
+                    AegisCircuit::<C>::range_constraints(batch_size, bits),
+                    expected
+                );
+            }
+        }
+    }
+
     #[test]
     fn aegis_pk_vk_size() {
         let path = "./src/keys/";
@@ -355,7 +446,7 @@ pub mod bn254 {
 
         for n in *LOG_MIN..=*LOG_MAX {
             let batch_size = 1 << n;
-            let (pk, vk, _) = aegis_circuit_setup::<E, R>(batch_size, &mut rng);
+            let (pk, vk, _) = Aegis::<E>::setup(batch_size, 2, BITS, &mut rng).unwrap();
 
             let mut pk_bytes = Vec::new();
             pk.serialize_compressed(&mut pk_bytes).unwrap();
@@ -400,42 +491,18 @@ pub mod bn254 {
 
             let cs = ark_relations::r1cs::ConstraintSystem::<F>::new_ref();
 
-            AegisCircuit::<C>::mock(batch_size)
+            AegisCircuit::<C>::mock(batch_size, 2, BITS)
                 .generate_constraints(cs.clone())
                 .unwrap();
             writeln!(file, "number of constraints: {}", cs.num_constraints()).unwrap();
 
-            let (pk, vk, ck) = aegis_circuit_setup::<E, R>(batch_size, &mut rng);
-
-            let cm_prev = test_commitments::<F>(batch_size, 2);
-            let cm_delta = test_delta_commitment::<F>(batch_size, 2);
-            let cm_curr = cm_prev
-                .iter()
-                .zip(cm_delta.iter())
-                .map(|(prev, delta)| {
-                    prev.iter()
-                        .zip(delta.iter())
-                        .map(|(prev, delta)| *prev + *delta)
-                        .collect::<Vec<F>>()
-                })
-                .collect::<Vec<_>>();
-
-            // commit
-            let (cm_g1, d, tau) =
-                aegis_circuit_commit(&ck, &cm_prev, &cm_delta, &cm_curr, &mut rng);
-            // print!("tau: {}\n", tau.to_string());
-
-            let circuit = AegisCircuit::<C>::new(tau, cm_curr, cm_prev.clone());
-
-            let proof =
-                aegis_circuit_prove_and_verify(&pk, &vk, circuit.clone(), &cm_g1, &d, &mut rng);
+            let (cm_g1, proof, vk, ck) = aegis_prove_and_verify::<E, R>(batch_size, 2, &mut rng);
 
             // make a prev_cm_g1
             let a_cm_prev = test_commitments(1, 2);
             let prev_cm_g1 = batch_commit(&ck, &a_cm_prev);
 
-            aegis_circuit_solidity(batch_size, &cm_g1, &proof, &vk, &prev_cm_g1);
+            aegis_circuit_solidity::<E>(batch_size, &cm_g1, &proof, &vk, &prev_cm_g1, F::zero());
         }
-        // }
     }
 }
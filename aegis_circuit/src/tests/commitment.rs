@@ -0,0 +1,198 @@
+use ark_ec::CurveGroup;
+use ark_ff::{Field, PrimeField};
+use ark_r1cs_std::{alloc::AllocVar, fields::nonnative::NonNativeFieldVar, groups::CurveVar};
+use ark_relations::r1cs::{ConstraintSystem, ConstraintSystemRef};
+use ark_std::{rand::Rng, UniformRand};
+
+use crate::crypto::commitment::{
+    elgamal::{Ciphertext, ElGamal, ElGamalError},
+    pedersen::{Pedersen, PedersenCurveGadget},
+    BatchCommitmentGadget, BatchCommitmentScheme, CommitmentGadget, CommitmentScheme,
+};
+
+type ConstraintF<C> = <<C as CurveGroup>::BaseField as Field>::BasePrimeField;
+
+fn alloc_scalars<C: CurveGroup>(
+    cs: ConstraintSystemRef<ConstraintF<C>>,
+    scalars: &[C::ScalarField],
+) -> Vec<NonNativeFieldVar<C::ScalarField, ConstraintF<C>>>
+where
+    ConstraintF<C>: PrimeField,
+{
+    Vec::<NonNativeFieldVar<C::ScalarField, ConstraintF<C>>>::new_witness(cs, || Ok(scalars))
+        .unwrap()
+}
+
+/// Open a native Pedersen commitment inside the circuit.
+/// Returns whether the constraints are satisfied and the number of constraints.
+fn curve_commitment<C, GG, R>(len: usize, tamper: bool, rng: &mut R) -> (bool, usize)
+where
+    C: CurveGroup,
+    ConstraintF<C>: PrimeField,
+    GG: CurveVar<C, ConstraintF<C>>,
+    R: Rng,
+{
+    let ck = (0..len).map(|_| C::rand(rng)).collect::<Vec<_>>();
+    let ck = C::normalize_batch(&ck);
+    let scalars = (0..len)
+        .map(|_| C::ScalarField::rand(rng))
+        .collect::<Vec<_>>();
+    let cm = match tamper {
+        true => Pedersen::<C>::commit(&ck, &scalars[1..]),
+        false => Pedersen::<C>::commit(&ck, &scalars),
+    };
+
+    let cs = ConstraintSystem::<ConstraintF<C>>::new_ref();
+    let scalars_var = alloc_scalars::<C>(cs.clone(), &scalars);
+    let cm_var = GG::new_input(cs.clone(), || Ok(cm)).unwrap();
+    PedersenCurveGadget::<C, GG>::enforce_opening(&cm_var, &ck, &scalars_var).unwrap();
+
+    (cs.is_satisfied().unwrap(), cs.num_constraints())
+}
+
+/// Check the in-circuit aggregation against `Pedersen::scalar_aggregate`.
+fn curve_aggregation<C, GG, R>(num_commitments: usize, len: usize, rng: &mut R) -> bool
+where
+    C: CurveGroup,
+    ConstraintF<C>: PrimeField,
+    GG: CurveVar<C, ConstraintF<C>>,
+    R: Rng,
+{
+    let commitments = (0..num_commitments)
+        .map(|_| {
+            (0..len)
+                .map(|_| C::ScalarField::rand(rng))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let slices = commitments.iter().map(|cm| &cm[..]).collect::<Vec<_>>();
+    let tau = C::ScalarField::rand(rng);
+    let (aggregation, _) = Pedersen::<C>::scalar_aggregate(&slices, tau, None);
+
+    let cs = ConstraintSystem::<ConstraintF<C>>::new_ref();
+    let tau_var = alloc_scalars::<C>(cs.clone(), &[tau]).remove(0);
+    let aggregation_var = alloc_scalars::<C>(cs.clone(), &aggregation);
+    let commitments_var = commitments
+        .iter()
+        .map(|cm| alloc_scalars::<C>(cs.clone(), cm))
+        .collect::<Vec<_>>();
+    <PedersenCurveGadget<C, GG> as BatchCommitmentGadget<Pedersen<C>, _>>::enforce_equal(
+        aggregation_var,
+        commitments_var,
+        tau_var,
+        None,
+    )
+    .unwrap();
+
+    cs.is_satisfied().unwrap()
+}
+
+fn slices<F>(v: &[Vec<F>]) -> Vec<&[F]> {
+    v.iter().map(|x| &x[..]).collect()
+}
+
+/// Balance update and aggregation over ElGamal ciphertexts of `[amount, r]` openings.
+fn elgamal_scenario<C: CurveGroup, R: Rng>(batch_size: usize, rng: &mut R) {
+    let (sk, pk) = ElGamal::<C>::keygen(rng);
+    let g = C::rand(rng).into_affine();
+    let ck = ElGamal::<C>::committing_key(&[g], &pk);
+
+    let opening = |rng: &mut R| vec![C::ScalarField::rand(rng), C::ScalarField::rand(rng)];
+    let prev = (0..batch_size).map(|_| opening(rng)).collect::<Vec<_>>();
+    let delta = (0..batch_size).map(|_| opening(rng)).collect::<Vec<_>>();
+    let curr = prev
+        .iter()
+        .zip(delta.iter())
+        .map(|(p, d)| vec![p[0] + d[0], p[1] + d[1]])
+        .collect::<Vec<_>>();
+
+    let prev_ct = ElGamal::<C>::batch_commit(&ck, &slices(&prev));
+    let delta_ct = ElGamal::<C>::batch_commit(&ck, &slices(&delta));
+    let curr_ct = ElGamal::<C>::batch_commit(&ck, &slices(&curr));
+
+    // homomorphic balance update
+    prev_ct
+        .iter()
+        .zip(delta_ct.iter())
+        .zip(curr_ct.iter())
+        .for_each(|((p, d), c)| assert_eq!(*p + *d, *c));
+
+    // auditor recovers amount · g
+    curr_ct
+        .iter()
+        .zip(curr.iter())
+        .for_each(|(ct, m)| assert_eq!(ElGamal::<C>::decrypt(&sk, ct), (g * m[0]).into_affine()));
+
+    // aggregation of ciphertexts matches the aggregation of openings
+    let tau = ElGamal::<C>::challenge(&[], &delta_ct, &g);
+    let (aggregation_ct, _) = ElGamal::<C>::aggregate(&curr_ct, tau, None);
+    let (aggregation, _) = ElGamal::<C>::scalar_aggregate(&slices(&curr), tau, None);
+    let expected: Ciphertext<C> = ElGamal::<C>::commit(&ck, &aggregation);
+    assert_eq!(aggregation_ct, expected);
+
+    // malformed openings are rejected instead of panicking
+    assert_eq!(
+        ElGamal::<C>::encrypt(&ck, &[]),
+        Err(ElGamalError::MissingRandomness)
+    );
+    assert_eq!(
+        ElGamal::<C>::encrypt(&ck, &curr[0][..1]),
+        Err(ElGamalError::MismatchParameterLength)
+    );
+
+    // the identity ciphertext is hashed as (0, 0) coordinates
+    let with_identity = [delta_ct.clone(), vec![Ciphertext::default()]].concat();
+    assert_ne!(ElGamal::<C>::challenge(&[], &with_identity, &g), tau);
+}
+
+pub mod bn254 {
+    use super::*;
+    use ark_std::test_rng;
+
+    type C = ark_bn254::G1Projective;
+
+    #[test]
+    fn elgamal_commitment_scenario() {
+        let mut rng = test_rng();
+        for batch_size in [1, 2, 8] {
+            elgamal_scenario::<C, _>(batch_size, &mut rng);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "one scalar per committing key generator")]
+    fn elgamal_commit_length_mismatch() {
+        let mut rng = test_rng();
+        let (_, pk) = ElGamal::<C>::keygen(&mut rng);
+        let ck = ElGamal::<C>::committing_key(&[C::rand(&mut rng).into_affine()], &pk);
+        ElGamal::<C>::commit(&ck, &[]);
+    }
+}
+
+pub mod baby_jubjub {
+    use super::*;
+    use ark_ed_on_bn254::{constraints::EdwardsVar, EdwardsProjective};
+    use ark_std::test_rng;
+
+    type C = EdwardsProjective;
+    type GG = EdwardsVar;
+
+    #[test]
+    fn curve_commitment_scenario() {
+        let mut rng = test_rng();
+        for len in [1, 2, 4] {
+            let (satisfied, num_constraints) = curve_commitment::<C, GG, _>(len, false, &mut rng);
+            assert!(satisfied);
+            println!("length: {} Number of constraints: {}", len, num_constraints);
+
+            let (satisfied, _) = curve_commitment::<C, GG, _>(len, true, &mut rng);
+            assert!(!satisfied);
+        }
+    }
+
+    #[test]
+    fn curve_aggregation_scenario() {
+        let mut rng = test_rng();
+        assert!(curve_aggregation::<C, GG, _>(4, 2, &mut rng));
+    }
+}
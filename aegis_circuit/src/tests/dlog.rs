@@ -0,0 +1,113 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{rand::Rng, UniformRand};
+
+use crate::crypto::{
+    commitment::{elgamal::ElGamal, pedersen::Pedersen, CommitmentScheme},
+    dlog::{BabyStepTable, DLogError, MAX_BOUND_BITS},
+};
+
+fn bsgs_scenario<C: CurveGroup, R: Rng>(bound_bits: usize, baby_bits: usize, rng: &mut R) {
+    let g = C::rand(rng).into_affine();
+    let table = BabyStepTable::<C>::new(g, bound_bits, baby_bits).unwrap();
+
+    let max = (1u64 << bound_bits) - 1;
+    for m in [0, 1, max, rng.gen_range(0..max)] {
+        let target = (g * C::ScalarField::from(m)).into_affine();
+        assert_eq!(table.solve(&target), Some(m));
+    }
+    let out_of_range = (g * C::ScalarField::from(max + 1)).into_affine();
+    assert_eq!(table.solve(&out_of_range), None);
+
+    // the table is reusable after (de)serialization
+    let mut bytes = vec![];
+    table.serialize_compressed(&mut bytes).unwrap();
+    let table = BabyStepTable::<C>::deserialize_compressed(&bytes[..]).unwrap();
+
+    // auditor: exponential ElGamal under its key
+    let (sk, pk) = ElGamal::<C>::keygen(rng);
+    let ck = ElGamal::<C>::committing_key(&[g], &pk);
+    let amount = rng.gen_range(0..max);
+    let ct = ElGamal::<C>::commit(&ck, &[amount.into(), C::ScalarField::rand(rng)]);
+    assert_eq!(ElGamal::<C>::decrypt_amount(&sk, &ct, &table), Some(amount));
+
+    // wallet: Pedersen commitment with a known opening
+    let h = C::rand(rng).into_affine();
+    let r = C::ScalarField::rand(rng);
+    let cm = Pedersen::<C>::commit(&[g, h], &[amount.into(), r]);
+    assert_eq!(
+        table.solve(&(cm.into_group() - h * r).into_affine()),
+        Some(amount)
+    );
+}
+
+pub mod bn254 {
+    use super::*;
+    use ark_std::test_rng;
+
+    type C = ark_bn254::G1Projective;
+
+    #[test]
+    fn bsgs_decryption_scenario() {
+        let mut rng = test_rng();
+        bsgs_scenario::<C, _>(16, 8, &mut rng);
+        bsgs_scenario::<C, _>(20, 6, &mut rng);
+    }
+
+    #[test]
+    fn bsgs_bound() {
+        let g = <C as ark_ec::Group>::generator().into_affine();
+        assert_eq!(
+            BabyStepTable::<C>::new(g, MAX_BOUND_BITS + 1, 8),
+            Err(DLogError::BoundTooLarge)
+        );
+        assert_eq!(
+            BabyStepTable::<C>::new(g, 8, 16),
+            Err(DLogError::InvalidTableSize)
+        );
+        assert_eq!(
+            BabyStepTable::<C>::new(g, MAX_BOUND_BITS, 0),
+            Err(DLogError::InvalidTableSize)
+        );
+    }
+
+    #[test]
+    fn bsgs_deserialize_validation() {
+        let g = <C as ark_ec::Group>::generator().into_affine();
+        let table = BabyStepTable::<C>::new(g, 12, 4).unwrap();
+        let mut bytes = vec![];
+        table.serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(
+            BabyStepTable::<C>::deserialize_uncompressed(&bytes[..]).unwrap(),
+            table
+        );
+
+        // base, bound_bits (u32), baby_bits (u32), then the length-prefixed entries
+        let dims = g.uncompressed_size();
+        let entries = dims + 8 + 8;
+        let tamper = |offset: usize, value: &[u8]| {
+            let mut bytes = bytes.clone();
+            bytes[offset..offset + value.len()].copy_from_slice(value);
+            BabyStepTable::<C>::deserialize_uncompressed(&bytes[..])
+        };
+
+        // baby steps larger than the bound
+        assert!(tamper(dims, &2u32.to_le_bytes()).is_err());
+        assert!(tamper(dims, &(MAX_BOUND_BITS as u32 + 1).to_le_bytes()).is_err());
+        // a table size that does not match the baby steps
+        assert!(tamper(dims + 4, &5u32.to_le_bytes()).is_err());
+        // unsorted fingerprints
+        let mut first = [0u8; 12];
+        first.copy_from_slice(&bytes[entries..entries + 12]);
+        assert!(tamper(entries, &[0xff; 8]).is_err());
+        // a baby step outside the table, and a repeated one
+        assert!(tamper(entries + 8, &16u32.to_le_bytes()).is_err());
+        let second = &bytes[entries + 12..entries + 24];
+        let mut repeated = second.to_vec();
+        repeated[8..].copy_from_slice(&first[8..]);
+        assert!(tamper(entries + 12, &repeated).is_err());
+        // a sorted table of unique baby steps with a wrong fingerprint
+        let last = entries + 12 * ((1 << 4) - 1);
+        assert!(tamper(last, &[0xff; 8]).is_err());
+    }
+}
@@ -1,12 +1,13 @@
-use std::time::Instant;
+use std::{fs, time::Instant};
 
 use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
 use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar};
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_serialize::CanonicalSerialize;
 use ark_std::{
     rand::{CryptoRng, RngCore},
     vec::Vec,
-    One, UniformRand,
+    One, UniformRand, Zero,
 };
 
 #[cfg(feature = "parallel")]
@@ -14,22 +15,34 @@ use rayon::prelude::*;
 
 use crate::{
     crypto::{
-        commitment::{pedersen::Pedersen, CommitmentScheme},
+        commitment::{elgamal::ElGamal, pedersen::Pedersen, CommitmentScheme},
         protocol::{
+            interactive::{self, decode, encode, InteractiveProver, InteractiveVerifier, Move},
             sigma::SigmaProtocol,
-            transcript::{sha3::SHA3Base, TranscriptProtocol},
+            transcript::{
+                merlin::MerlinBase, poseidon::PoseidonBase, sha3::SHA3Base, TranscriptProtocol,
+            },
         },
     },
-    gro::{CCGroth16, Commitment, ProvingKey},
+    gro::{CCGroth16, ProvingKey},
     linker::{
         am_com_eq::{
+            self,
             data_structure::{CommittingKey, Instance, PublicParameters, Witness},
-            AmComEq,
+            AmComEq, AmComEqError,
         },
-        comp_am_com_eq::CompAmComEq,
+        com_enc_eq::{self, ComEncEq},
+        com_pok::{self, ComPoK},
+        comp_am_com_eq::{self, CompAmComEq},
+        comp_com_pok::CompComPoK,
+        comp_dl_eq::{self, CompDLEq},
+        comp_multi_am_com_eq::{CompMultiAmComEq, CompMultiAmComEqError},
+        comp_sum_eq::{self, CompSumEq},
+        cp_link::{self, CPLink},
+        multi_am_com_eq::{self, MultiAmComEq},
     },
-    snark::{CircuitSpecificSetupCCSNARK, CCSNARK},
-    solidity::Solidity,
+    snark::CircuitSpecificSetupCCSNARK,
+    solidity::{ComEncEqVerifier, CompAmComEqVerifier, Solidity},
 };
 
 use super::utils::Average;
@@ -86,24 +99,41 @@ fn linker_setup<C: CurveGroup, R: RngCore + CryptoRng>(
     let g_hat = vec![C::Affine::rand(rng); d0];
     let h_hat = vec![C::Affine::rand(rng); d2];
 
+    let pp = PublicParameters {
+        poly_ck: CommittingKey { g, h },
+        coeff_ck: CommittingKey { g: g_hat, h: h_hat },
+        threshold: comp_dl_eq::DEFAULT_THRESHOLD,
+    };
+    let (instance, witness) = linker_instance(&pp, rng);
+    (pp, instance, witness)
+}
+
+/// Fresh commitments to random openings under the public parameters.
+fn linker_instance<C: CurveGroup, R: RngCore + CryptoRng>(
+    pp: &PublicParameters<C>,
+    rng: &mut R,
+) -> (Instance<C>, Witness<C>) {
+    let d0 = pp.coeff_ck.g.len();
+    let l = pp.poly_ck.g.len() / d0;
+    let d1 = pp.poly_ck.h.len();
+    let d2 = pp.coeff_ck.h.len();
+
     let w = vec![vec![C::ScalarField::rand(rng); d0]; l];
     let alpha = vec![C::ScalarField::rand(rng); d1];
     let beta = vec![vec![C::ScalarField::rand(rng); d2]; l];
 
     let w_flat = cfg_iter!(w).flat_map(|w_i| w_i.clone()).collect::<Vec<_>>();
-    let c = Pedersen::<C>::commit(&g, &w_flat) + Pedersen::<C>::commit(&h, &alpha);
+    let c = Pedersen::<C>::commit(&pp.poly_ck.g, &w_flat)
+        + Pedersen::<C>::commit(&pp.poly_ck.h, &alpha);
     let c_hat = cfg_iter!(w)
         .zip(&beta)
         .map(|(w_i, beta_i)| {
-            Pedersen::<C>::commit(&g_hat, w_i) + Pedersen::<C>::commit(&h_hat, beta_i)
+            Pedersen::<C>::commit(&pp.coeff_ck.g, w_i)
+                + Pedersen::<C>::commit(&pp.coeff_ck.h, beta_i)
         })
         .collect::<Vec<_>>();
 
     (
-        PublicParameters {
-            poly_ck: CommittingKey { g, h },
-            coeff_ck: CommittingKey { g: g_hat, h: h_hat },
-        },
         Instance {
             c: c.into_affine(),
             c_hat: C::normalize_batch(&c_hat),
@@ -112,6 +142,99 @@ fn linker_setup<C: CurveGroup, R: RngCore + CryptoRng>(
     )
 }
 
+/// Batch verify `batch_size` proofs of both linkers, then locate a tampered one.
+fn batch_verification<C: CurveGroup, R: RngCore + CryptoRng>(
+    batch_size: usize,
+    l: usize,
+    d0: usize,
+    rng: &mut R,
+) {
+    let (pp, _, _) = linker_setup::<C, _>(l, d0, 1, 1, rng);
+    let statements = (0..batch_size)
+        .map(|_| linker_instance(&pp, rng))
+        .collect::<Vec<_>>();
+
+    let proofs = statements
+        .iter()
+        .map(|(instance, witness)| {
+            AmComEq::<C>::prove(&pp, instance, witness, &mut SHA3Base::new(true), rng).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut tampered = proofs.clone();
+    tampered[batch_size / 2].omega[0] += C::ScalarField::one();
+    for (proofs, expected) in [(&proofs, vec![]), (&tampered, vec![batch_size / 2])] {
+        let mut batch = statements
+            .iter()
+            .zip(proofs)
+            .map(|((instance, _), proof)| (instance, proof, SHA3Base::new(true)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            AmComEq::<C>::batch_verify(&pp, &mut batch, rng),
+            Ok(expected)
+        );
+    }
+
+    // a malformed instance rejects its own proof only: a missing commitment fails the
+    // dimension check, the identity cannot be appended to the transcript
+    let mut instances = statements
+        .iter()
+        .map(|(instance, _)| instance.clone())
+        .collect::<Vec<_>>();
+    instances[1].c_hat.pop();
+    instances[2].c_hat[0] = C::Affine::zero();
+    let malformed = |tampered: &[usize]| {
+        let mut rejected = [&[1, 2], tampered].concat();
+        rejected.sort_unstable();
+        rejected.dedup();
+        rejected
+    };
+    let mixed = |proofs| {
+        instances
+            .iter()
+            .zip(proofs)
+            .map(|(instance, proof)| (instance, proof, SHA3Base::new(true)))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        AmComEq::<C>::batch_verify(&pp, &mut mixed(&proofs), rng),
+        Ok(malformed(&[]))
+    );
+    assert_eq!(
+        AmComEq::<C>::batch_verify(&pp, &mut mixed(&tampered), rng),
+        Ok(malformed(&[batch_size / 2]))
+    );
+
+    let proofs = statements
+        .iter()
+        .map(|(instance, witness)| {
+            CompAmComEq::<C>::prove(&pp, instance, witness, &mut SHA3Base::new(true), rng).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut tampered = proofs.clone();
+    tampered[0].commitments[0].right_hat = tampered[0].commitments[0].left_hat;
+    tampered[batch_size - 1].ace.z.pop();
+    for (proofs, expected) in [(&proofs, vec![]), (&tampered, vec![0, batch_size - 1])] {
+        let mut batch = statements
+            .iter()
+            .zip(proofs)
+            .map(|((instance, _), proof)| (instance, proof, SHA3Base::new(true)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            CompAmComEq::<C>::batch_verify(&pp, &mut batch, rng),
+            Ok(expected)
+        );
+    }
+    let mut batch = instances
+        .iter()
+        .zip(&tampered)
+        .map(|(instance, proof)| (instance, proof, SHA3Base::new(true)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        CompAmComEq::<C>::batch_verify(&pp, &mut batch, rng),
+        Ok(malformed(&[0, batch_size - 1]))
+    );
+}
+
 fn process_linker<C: CurveGroup, R: RngCore + CryptoRng>(
     repeat: usize,
     l: usize,
@@ -142,15 +265,890 @@ fn process_linker<C: CurveGroup, R: RngCore + CryptoRng>(
     (prover.average(), verifier.average())
 }
 
+fn comp_dl_eq_setup<C: CurveGroup, R: RngCore + CryptoRng>(
+    n: usize,
+    threshold: usize,
+    rng: &mut R,
+) -> (
+    comp_dl_eq::PublicParameters<C>,
+    comp_dl_eq::Instance<C>,
+    comp_dl_eq::Witness<C>,
+) {
+    let g = (0..n).map(|_| C::Affine::rand(rng)).collect::<Vec<_>>();
+    let g_hat = (0..n).map(|_| C::Affine::rand(rng)).collect::<Vec<_>>();
+    let z = (0..n)
+        .map(|_| C::ScalarField::rand(rng))
+        .collect::<Vec<_>>();
+
+    let y = Pedersen::<C>::commit(&g, &z);
+    let y_hat = Pedersen::<C>::commit(&g_hat, &z);
+
+    (
+        comp_dl_eq::PublicParameters {
+            g: g.into(),
+            g_hat: g_hat.into(),
+            threshold,
+        },
+        comp_dl_eq::Instance { y, y_hat },
+        comp_dl_eq::Witness { z },
+    )
+}
+
+/// Prove and verify every linker protocol over a fresh copy of `transcript`.
+fn linker_with_transcript<C: CurveGroup, T: TranscriptProtocol, R: RngCore + CryptoRng>(
+    l: usize,
+    d0: usize,
+    d1: usize,
+    d2: usize,
+    transcript: &T,
+    rng: &mut R,
+) {
+    let (pp, instance, witness) = linker_setup::<C, _>(l, d0, d1, d2, rng);
+
+    let proof = AmComEq::<C>::prove(&pp, &instance, &witness, &mut transcript.clone(), rng)
+        .expect("proof failed");
+    assert!(AmComEq::<C>::verify(&pp, &instance, &proof, &mut transcript.clone()).unwrap());
+
+    let proof = CompAmComEq::<C>::prove(&pp, &instance, &witness, &mut transcript.clone(), rng)
+        .expect("proof failed");
+    assert!(CompAmComEq::<C>::verify(&pp, &instance, &proof, &mut transcript.clone()).unwrap());
+
+    let (pp, instance, witness) =
+        comp_dl_eq_setup::<C, _>(l * d0, comp_dl_eq::DEFAULT_THRESHOLD, rng);
+    let pp = CompDLEq::<C>::setup(&pp).unwrap();
+    let proof = CompDLEq::<C>::prove(&pp, &instance, &witness, &mut transcript.clone(), rng)
+        .expect("proof failed");
+    assert!(CompDLEq::<C>::verify(&pp, &instance, &proof, &mut transcript.clone()).unwrap());
+}
+
+/// Malformed or tampered proofs are rejected with `Ok(false)` instead of panicking.
+fn invalid_proofs<C: CurveGroup, R: RngCore + CryptoRng>(l: usize, d0: usize, rng: &mut R) {
+    let verify_ace = |pp: &PublicParameters<C>, instance: &Instance<C>, proof: &_| {
+        AmComEq::<C>::verify(pp, instance, proof, &mut SHA3Base::new(true))
+    };
+
+    let (pp, instance, witness) = linker_setup::<C, _>(l, d0, 1, 1, rng);
+    let proof = AmComEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert_eq!(verify_ace(&pp, &instance, &proof), Ok(true));
+
+    let mut tampered = proof.clone();
+    tampered.z[0] += C::ScalarField::one();
+    assert_eq!(verify_ace(&pp, &instance, &tampered), Ok(false));
+
+    let mut tampered = proof.clone();
+    tampered.z.pop();
+    assert_eq!(verify_ace(&pp, &instance, &tampered), Ok(false));
+
+    let mut tampered = proof.clone();
+    tampered.commitment.a = C::Affine::zero();
+    assert_eq!(verify_ace(&pp, &instance, &tampered), Ok(false));
+
+    let mut mismatched = instance.clone();
+    mismatched.c_hat.pop();
+    assert_eq!(
+        verify_ace(&pp, &mismatched, &proof),
+        Err(AmComEqError::MismatchParameterLength)
+    );
+
+    let proof = CompAmComEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    let mut tampered = proof.clone();
+    tampered.ace.z[1] += C::ScalarField::one();
+    assert_eq!(
+        CompAmComEq::<C>::verify(&pp, &instance, &tampered, &mut SHA3Base::new(true)),
+        Ok(false)
+    );
+
+    let (pp, instance, witness) =
+        comp_dl_eq_setup::<C, _>(l * d0, comp_dl_eq::DEFAULT_THRESHOLD, rng);
+    let pp = CompDLEq::<C>::setup(&pp).unwrap();
+    let proof = CompDLEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    let verify_cde = |proof: &comp_dl_eq::Proof<C>| {
+        CompDLEq::<C>::verify(&pp, &instance, proof, &mut SHA3Base::new(true))
+    };
+    assert_eq!(verify_cde(&proof), Ok(true));
+
+    let mut tampered = proof.clone();
+    tampered.commitments.pop();
+    assert_eq!(verify_cde(&tampered), Ok(false));
+
+    let mut tampered = proof.clone();
+    tampered.commitments[0].left = C::Affine::zero();
+    assert_eq!(verify_cde(&tampered), Ok(false));
+
+    // the single final MSM still checks both bases
+    let mut tampered = proof.clone();
+    tampered.commitments[0].right_hat = tampered.commitments[0].left_hat;
+    assert_eq!(verify_cde(&tampered), Ok(false));
+
+    let mut tampered = proof.clone();
+    tampered.z[0] += C::ScalarField::one();
+    assert_eq!(verify_cde(&tampered), Ok(false));
+
+    let mut tampered = proof;
+    tampered.z.push(C::ScalarField::one());
+    assert_eq!(verify_cde(&tampered), Ok(false));
+}
+
+/// Fold `n` generators down to `threshold` without padding, and reject a proof checked
+/// against another threshold.
+fn comp_dl_eq_threshold<C: CurveGroup, R: RngCore + CryptoRng>(
+    n: usize,
+    threshold: usize,
+    rng: &mut R,
+) {
+    let (pp, instance, witness) = comp_dl_eq_setup::<C, _>(n, threshold, rng);
+    let pp = CompDLEq::<C>::setup(&pp).unwrap();
+    let proof = CompDLEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert_eq!(
+        proof.commitments.len(),
+        CompDLEq::<C>::num_rounds(n, threshold)
+    );
+    assert_eq!(proof.z.len(), CompDLEq::<C>::final_len(n, threshold));
+    assert_eq!(
+        CompDLEq::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)),
+        Ok(true)
+    );
+
+    let other = comp_dl_eq::PublicParameters {
+        threshold: threshold + 1,
+        ..pp
+    };
+    if CompDLEq::<C>::num_rounds(n, threshold) != CompDLEq::<C>::num_rounds(n, threshold + 1) {
+        assert_eq!(
+            CompDLEq::<C>::verify(&other, &instance, &proof, &mut SHA3Base::new(true)),
+            Ok(false)
+        );
+    }
+}
+
+/// Compress AmComEq and ComPoK proofs under the fold threshold of the public parameters, and
+/// reject a zero threshold.
+fn comp_threshold<C: CurveGroup, R: RngCore + CryptoRng>(
+    l: usize,
+    d0: usize,
+    threshold: usize,
+    rng: &mut R,
+) {
+    let (pp, instance, witness) = linker_setup::<C, _>(l, d0, 1, 1, rng);
+    let pp = PublicParameters { threshold, ..pp };
+    let n = l * d0;
+
+    let proof = CompAmComEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert_eq!(
+        proof.commitments.len(),
+        CompDLEq::<C>::num_rounds(n, threshold)
+    );
+    assert_eq!(proof.ace.z.len(), CompDLEq::<C>::final_len(n, threshold));
+    assert_eq!(
+        CompAmComEq::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)),
+        Ok(true)
+    );
+    let mut batch = vec![(&instance, &proof, SHA3Base::new(true))];
+    assert_eq!(
+        CompAmComEq::<C>::batch_verify(&pp, &mut batch, rng),
+        Ok(vec![])
+    );
+
+    // the verifier folds down to its own threshold
+    let other = PublicParameters {
+        threshold: threshold + 1,
+        ..pp.clone()
+    };
+    if CompDLEq::<C>::final_len(n, threshold) != CompDLEq::<C>::final_len(n, threshold + 1) {
+        assert_eq!(
+            CompAmComEq::<C>::verify(&other, &instance, &proof, &mut SHA3Base::new(true)),
+            Ok(false)
+        );
+    }
+
+    // a zero threshold never stops folding
+    let zero = PublicParameters { threshold: 0, ..pp };
+    let invalid =
+        || comp_am_com_eq::CompAmComEqError::CompDLEq(comp_dl_eq::CompDLEqError::InvalidThreshold);
+    assert!(matches!(
+        CompAmComEq::<C>::prove(&zero, &instance, &witness, &mut SHA3Base::new(true), rng),
+        Err(e) if e == invalid()
+    ));
+    assert_eq!(
+        CompAmComEq::<C>::verify(&zero, &instance, &proof, &mut SHA3Base::new(true)),
+        Err(invalid())
+    );
+    let mut batch = vec![(&instance, &proof, SHA3Base::new(true))];
+    assert_eq!(
+        CompAmComEq::<C>::batch_verify(&zero, &mut batch, rng),
+        Err(invalid())
+    );
+
+    let (pp, instance, witness) = com_pok_setup::<C, _>(l, d0, rng);
+    let pp = CompComPoK::<C>::setup(&com_pok::PublicParameters { threshold, ..pp }).unwrap();
+    let proof = CompComPoK::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert_eq!(proof.z.len(), CompDLEq::<C>::final_len(d0, threshold));
+    assert_eq!(
+        proof.commitments.len(),
+        CompDLEq::<C>::num_rounds(d0, threshold)
+    );
+    assert_eq!(
+        CompComPoK::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)),
+        Ok(true)
+    );
+}
+
+fn com_pok_setup<C: CurveGroup, R: RngCore + CryptoRng>(
+    num_accounts: usize,
+    d: usize,
+    rng: &mut R,
+) -> (
+    com_pok::PublicParameters<C>,
+    com_pok::Instance<C>,
+    com_pok::Witness<C>,
+) {
+    let g = (0..d).map(|_| C::Affine::rand(rng)).collect::<Vec<_>>();
+    // openings may be shorter than the committing key
+    let m = (0..num_accounts)
+        .map(|i| {
+            (0..d - i % d)
+                .map(|_| C::ScalarField::rand(rng))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let c = m
+        .iter()
+        .map(|m_i| Pedersen::<C>::commit(&g, m_i))
+        .collect::<Vec<_>>();
+
+    (
+        com_pok::PublicParameters {
+            g,
+            threshold: comp_dl_eq::DEFAULT_THRESHOLD,
+        },
+        com_pok::Instance { c },
+        com_pok::Witness { m },
+    )
+}
+
+/// Prove knowledge of the openings of `num_accounts` commitments, plain and compressed.
+fn com_pok<C: CurveGroup, R: RngCore + CryptoRng>(num_accounts: usize, d: usize, rng: &mut R)
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    let (pp, instance, witness) = com_pok_setup::<C, _>(num_accounts, d, rng);
+
+    let proof = ComPoK::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert_eq!(proof.to_solidity().len(), d + 2);
+    assert!(ComPoK::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.z[0] += C::ScalarField::one();
+    assert!(!ComPoK::<C>::verify(&pp, &instance, &tampered, &mut SHA3Base::new(true)).unwrap());
+
+    let mut other = instance.clone();
+    other.c.swap(0, num_accounts - 1);
+    assert!(!ComPoK::<C>::verify(&pp, &other, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let pp = CompComPoK::<C>::setup(&pp).unwrap();
+    let proof = CompComPoK::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert!(proof.z.len() <= 2);
+    assert!(CompComPoK::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.z[0] += C::ScalarField::one();
+    assert!(!CompComPoK::<C>::verify(&pp, &instance, &tampered, &mut SHA3Base::new(true)).unwrap());
+
+    let mut tampered = proof;
+    let round = &mut tampered.commitments[0];
+    (round.left, round.right) = (round.right, round.left);
+    assert!(!CompComPoK::<C>::verify(&pp, &instance, &tampered, &mut SHA3Base::new(true)).unwrap());
+}
+
+/// Generate the Solidity verifier and its fixture for a compressed AmComEq proof folded down to
+/// `threshold`, after checking that the contract's transcript replays `SHA3Base` and that
+/// parameters without rows or with a zero threshold have no verifier.
+fn comp_am_com_eq_solidity<C: CurveGroup, R: RngCore + CryptoRng>(
+    l: usize,
+    d0: usize,
+    d1: usize,
+    d2: usize,
+    threshold: usize,
+    rng: &mut R,
+) where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    let (pp, instance, witness) = linker_setup::<C, _>(l, d0, d1, d2, rng);
+    let pp = PublicParameters { threshold, ..pp };
+    let proof = CompAmComEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(false), rng)
+        .expect("proof failed");
+    assert!(CompAmComEq::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(false)).unwrap());
+
+    // the verifier's challenges, in the order of the contract
+    let mut transcript = SHA3Base::new(false);
+    let mut expected = AmComEq::compute_coefficients(&instance, &mut transcript).unwrap();
+    expected.push(AmComEq::compute_e(&proof.ace.commitment, &mut transcript).unwrap());
+    for commitment in proof.commitments.iter() {
+        expected.push(CompDLEq::compute_challenge(commitment, &mut transcript).unwrap());
+    }
+    let mut fork = transcript.fork(b"CompDLEq::final");
+    let mut bytes = vec![];
+    proof.ace.z.serialize_uncompressed(&mut bytes).unwrap();
+    fork.append(b"response", &bytes);
+    expected.push(fork.challenge_scalar(b"weight"));
+
+    let mismatch =
+        || comp_am_com_eq::CompAmComEqError::AmComEq(AmComEqError::MismatchParameterLength);
+    let empty = PublicParameters {
+        coeff_ck: CommittingKey {
+            g: vec![],
+            h: pp.coeff_ck.h.clone(),
+        },
+        ..pp.clone()
+    };
+    assert_eq!(
+        CompAmComEqVerifier::<C>::from_parameters(&empty).err(),
+        Some(mismatch())
+    );
+    let zero = PublicParameters {
+        threshold: 0,
+        ..pp.clone()
+    };
+    assert_eq!(
+        CompAmComEqVerifier::<C>::from_parameters(&zero).err(),
+        Some(comp_am_com_eq::CompAmComEqError::CompDLEq(
+            comp_dl_eq::CompDLEqError::InvalidThreshold
+        ))
+    );
+    assert_eq!(
+        CompAmComEqVerifier::<C>::new(l, 0, d1, d2, threshold).err(),
+        Some(mismatch())
+    );
+
+    let verifier = CompAmComEqVerifier::<C>::from_parameters(&pp).unwrap();
+    assert_eq!(verifier.threshold, threshold);
+    assert_eq!(verifier.challenges(&instance, &proof), Some(expected));
+
+    fs::create_dir_all("../aegis_contract/contracts/linker").unwrap();
+    fs::write(
+        "../aegis_contract/contracts/linker/CompAmComEqVerifier.sol",
+        verifier.contract(),
+    )
+    .expect("Unable to write contract");
+    fs::write(
+        "../aegis_contract/result/compAmComEqData.ts",
+        verifier.fixture(&pp, &instance, &proof),
+    )
+    .expect("Unable to write fixture");
+}
+
+/// `rows[t]` rows of length `d0` committed by the single commitment `c_t`, and a shared or
+/// per-index coefficient key.
+fn multi_linker_setup<C: CurveGroup, R: RngCore + CryptoRng>(
+    rows: &[usize],
+    d0: usize,
+    d1: usize,
+    d2: usize,
+    per_index: bool,
+    rng: &mut R,
+) -> (
+    multi_am_com_eq::PublicParameters<C>,
+    multi_am_com_eq::Instance<C>,
+    multi_am_com_eq::Witness<C>,
+) {
+    let l = rows.iter().sum::<usize>();
+    let mut points = |n: usize| (0..n).map(|_| C::Affine::rand(rng)).collect::<Vec<_>>();
+    let poly_cks = rows
+        .iter()
+        .map(|rows_t| CommittingKey {
+            g: points(rows_t * d0),
+            h: points(d1),
+        })
+        .collect::<Vec<_>>();
+    let coeff_cks = (0..if per_index { l } else { 1 })
+        .map(|_| CommittingKey {
+            g: points(d0),
+            h: points(d2),
+        })
+        .collect::<Vec<_>>();
+    let pp = multi_am_com_eq::PublicParameters {
+        poly_cks,
+        coeff_cks,
+        threshold: comp_dl_eq::DEFAULT_THRESHOLD,
+    };
+
+    let mut scalars = |n: usize| {
+        (0..n)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>()
+    };
+    let w = (0..l).map(|_| scalars(d0)).collect::<Vec<_>>();
+    let alpha = rows.iter().map(|_| scalars(d1)).collect::<Vec<_>>();
+    let beta = (0..l).map(|_| scalars(d2)).collect::<Vec<_>>();
+
+    let w_flat = w.concat();
+    let c = MultiAmComEq::segments(&pp)
+        .into_iter()
+        .zip(&pp.poly_cks)
+        .zip(&alpha)
+        .map(|((range, ck), alpha_t)| {
+            Pedersen::<C>::commit(&ck.g, &w_flat[range]) + Pedersen::<C>::commit(&ck.h, alpha_t)
+        })
+        .collect::<Vec<_>>();
+    let c_hat = (w.iter().zip(&beta))
+        .enumerate()
+        .map(|(i, (w_i, beta_i))| {
+            let ck = pp.coeff_ck(i);
+            Pedersen::<C>::commit(&ck.g, w_i) + Pedersen::<C>::commit(&ck.h, beta_i)
+        })
+        .collect::<Vec<_>>();
+
+    (
+        pp,
+        multi_am_com_eq::Instance {
+            c: C::normalize_batch(&c),
+            c_hat: C::normalize_batch(&c_hat),
+        },
+        multi_am_com_eq::Witness { w, alpha, beta },
+    )
+}
+
+/// Direct and compressed MultiAmComEq folding down to `threshold`, rejecting swapped single
+/// commitments, a commitment under the wrong coefficient key and a zero threshold.
+fn multi_am_com_eq<C: CurveGroup, R: RngCore + CryptoRng>(
+    rows: &[usize],
+    d0: usize,
+    per_index: bool,
+    threshold: usize,
+    rng: &mut R,
+) {
+    let (pp, instance, witness) = multi_linker_setup::<C, _>(rows, d0, 1, 2, per_index, rng);
+    let pp = multi_am_com_eq::PublicParameters { threshold, ..pp };
+
+    let proof =
+        MultiAmComEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng).unwrap();
+    assert!(MultiAmComEq::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let compressed =
+        CompMultiAmComEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+            .unwrap();
+    let n = pp.poly_cks.iter().map(|ck| ck.g.len()).sum::<usize>();
+    assert_eq!(
+        compressed.commitments.len(),
+        CompDLEq::<C>::num_rounds(n, threshold)
+    );
+    assert_eq!(
+        compressed.mace.z.len(),
+        CompDLEq::<C>::final_len(n, threshold)
+    );
+    assert!(
+        CompMultiAmComEq::<C>::verify(&pp, &instance, &compressed, &mut SHA3Base::new(true))
+            .unwrap()
+    );
+
+    let zero = multi_am_com_eq::PublicParameters {
+        threshold: 0,
+        ..pp.clone()
+    };
+    assert_eq!(
+        CompMultiAmComEq::<C>::verify(&zero, &instance, &compressed, &mut SHA3Base::new(true)),
+        Err(CompMultiAmComEqError::CompDLEq(
+            comp_dl_eq::CompDLEqError::InvalidThreshold
+        ))
+    );
+
+    let mut tampered = instance.clone();
+    if rows.len() > 1 {
+        tampered.c.swap(0, 1);
+    } else {
+        tampered.c[0] = (tampered.c[0] + pp.poly_cks[0].h[0]).into_affine();
+    }
+    assert!(!MultiAmComEq::<C>::verify(&pp, &tampered, &proof, &mut SHA3Base::new(true)).unwrap());
+    assert!(
+        !CompMultiAmComEq::<C>::verify(&pp, &tampered, &compressed, &mut SHA3Base::new(true))
+            .unwrap()
+    );
+
+    // the last row committed under another index's key
+    let mut tampered = instance.clone();
+    let (l, w_last, beta_last) = (
+        tampered.c_hat.len(),
+        witness.w.last().unwrap(),
+        witness.beta.last().unwrap(),
+    );
+    let ck = pp.coeff_ck(0);
+    tampered.c_hat[l - 1] = (Pedersen::<C>::commit(&ck.g, w_last)
+        + Pedersen::<C>::commit(&ck.h, beta_last))
+    .into_affine();
+    if per_index && l > 1 {
+        let proof =
+            MultiAmComEq::<C>::prove(&pp, &tampered, &witness, &mut SHA3Base::new(true), rng)
+                .unwrap();
+        assert_eq!(
+            MultiAmComEq::<C>::verify(&pp, &tampered, &proof, &mut SHA3Base::new(true)),
+            Ok(false)
+        );
+        let compressed =
+            CompMultiAmComEq::<C>::prove(&pp, &tampered, &witness, &mut SHA3Base::new(true), rng)
+                .unwrap();
+        assert_eq!(
+            CompMultiAmComEq::<C>::verify(&pp, &tampered, &compressed, &mut SHA3Base::new(true)),
+            Ok(false)
+        );
+    }
+}
+
+/// Compressed sum-equality of `n` committed values folded down to `threshold`, against a public
+/// total with unit weights or a committed total with random weights, accepting a zero total and
+/// rejecting a total off by one.
+fn comp_sum_eq<C: CurveGroup, R: RngCore + CryptoRng>(
+    n: usize,
+    committed: bool,
+    threshold: usize,
+    rng: &mut R,
+) {
+    let mut rand_points = |len: usize| (0..len).map(|_| C::Affine::rand(rng)).collect::<Vec<_>>();
+    let ck = CommittingKey {
+        g: rand_points(n),
+        h: rand_points(1),
+    };
+    let total_ck = CommittingKey {
+        g: rand_points(1),
+        h: rand_points(committed as usize),
+    };
+    let weights = match committed {
+        true => (0..n).map(|_| C::ScalarField::rand(rng)).collect(),
+        false => vec![C::ScalarField::one(); n],
+    };
+    let pp = comp_sum_eq::PublicParameters {
+        ck,
+        total_ck,
+        weights,
+        threshold,
+    };
+    assert_eq!(
+        CompSumEq::<C>::setup(&comp_sum_eq::PublicParameters {
+            threshold: 0,
+            ..pp.clone()
+        }),
+        Err(comp_sum_eq::CompSumEqError::CompDLEq(
+            comp_dl_eq::CompDLEqError::InvalidThreshold
+        ))
+    );
+    let pp = CompSumEq::<C>::setup(&pp).unwrap();
+
+    let mut rand_scalars = |len: usize| {
+        (0..len)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>()
+    };
+    let witness = comp_sum_eq::Witness {
+        w: rand_scalars(n),
+        alpha: rand_scalars(1),
+        tau: rand_scalars(pp.total_ck.h.len()),
+    };
+    let t = CompSumEq::<C>::weighted_sum(&pp, &witness.w);
+    let total = |t: C::ScalarField| {
+        (pp.total_ck.g[0] * t + Pedersen::<C>::commit(&pp.total_ck.h, &witness.tau)).into_affine()
+    };
+    let instance = comp_sum_eq::Instance {
+        c: (Pedersen::<C>::commit(&pp.ck.g, &witness.w)
+            + Pedersen::<C>::commit(&pp.ck.h, &witness.alpha))
+        .into_affine(),
+        total: total(t),
+    };
+    if !committed {
+        assert_eq!(instance.total, CompSumEq::<C>::public_total(&pp, t));
+    }
+
+    let proof =
+        CompSumEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng).unwrap();
+    assert_eq!(
+        proof.commitments.len(),
+        CompDLEq::<C>::num_rounds(n, threshold)
+    );
+    assert_eq!(proof.z.len(), CompDLEq::<C>::final_len(n, threshold));
+    assert!(CompSumEq::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    // a zero public total is the identity
+    let mut balanced = witness.clone();
+    balanced.w[n - 1] -= t / pp.weights[n - 1];
+    let zero = comp_sum_eq::Instance {
+        c: (Pedersen::<C>::commit(&pp.ck.g, &balanced.w)
+            + Pedersen::<C>::commit(&pp.ck.h, &balanced.alpha))
+        .into_affine(),
+        total: total(C::ScalarField::zero()),
+    };
+    assert_eq!(AffineRepr::is_zero(&zero.total), !committed);
+    let proof =
+        CompSumEq::<C>::prove(&pp, &zero, &balanced, &mut SHA3Base::new(true), rng).unwrap();
+    assert!(CompSumEq::<C>::verify(&pp, &zero, &proof, &mut SHA3Base::new(true)).unwrap());
+    let shifted = comp_sum_eq::Instance {
+        total: total(C::ScalarField::one()),
+        ..zero
+    };
+    assert!(!CompSumEq::<C>::verify(&pp, &shifted, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let wrong = comp_sum_eq::Instance {
+        total: total(t + C::ScalarField::one()),
+        ..instance.clone()
+    };
+    let proof =
+        CompSumEq::<C>::prove(&pp, &wrong, &witness, &mut SHA3Base::new(true), rng).unwrap();
+    assert!(!CompSumEq::<C>::verify(&pp, &wrong, &proof, &mut SHA3Base::new(true)).unwrap());
+}
+
+/// `l` commitments and encryptions of random values.
+fn com_enc_eq_statement<C: CurveGroup, R: RngCore + CryptoRng>(
+    pp: &com_enc_eq::PublicParameters<C>,
+    l: usize,
+    rng: &mut R,
+) -> (com_enc_eq::Instance<C>, com_enc_eq::Witness<C>) {
+    let mut rand_scalars = || {
+        (0..l)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>()
+    };
+    let witness = com_enc_eq::Witness {
+        m: rand_scalars(),
+        r: rand_scalars(),
+        k: rand_scalars(),
+    };
+    let instance = com_enc_eq::Instance {
+        c: (0..l)
+            .map(|i| ComEncEq::<C>::commit(pp, witness.m[i], witness.r[i]))
+            .collect(),
+        ciphertexts: (0..l)
+            .map(|i| ComEncEq::<C>::encrypt(pp, witness.m[i], witness.k[i]))
+            .collect(),
+    };
+    (instance, witness)
+}
+
+/// Prove that `l` ciphertexts encrypt the values of `l` commitments, reject a ciphertext of
+/// another value, and batch verify `batch_size` proofs with one tampered response.
+fn com_enc_eq<C: CurveGroup, R: RngCore + CryptoRng>(l: usize, batch_size: usize, rng: &mut R)
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    let (sk, pk) = ElGamal::<C>::keygen(rng);
+    let pp = ComEncEq::<C>::setup(&com_enc_eq::PublicParameters {
+        g: C::Affine::rand(rng),
+        h: C::Affine::rand(rng),
+        pk,
+    })
+    .unwrap();
+    let statement = |rng: &mut R| com_enc_eq_statement(&pp, l, rng);
+
+    let (instance, witness) = statement(rng);
+    // the auditor decrypts m · g
+    assert_eq!(
+        ElGamal::<C>::decrypt(&sk, &instance.ciphertexts[0]),
+        Pedersen::<C>::commit(&[pp.g], &witness.m[..1])
+    );
+
+    let proof =
+        ComEncEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng).unwrap();
+    assert_eq!(proof.to_solidity().len(), 9);
+    assert_eq!(instance.to_solidity().len(), 1 + 6 * l);
+    assert!(ComEncEq::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let mut other = instance.clone();
+    other.ciphertexts[l - 1] = ComEncEq::<C>::encrypt(
+        &pp,
+        witness.m[l - 1] + C::ScalarField::one(),
+        witness.k[l - 1],
+    );
+    let proof = ComEncEq::<C>::prove(&pp, &other, &witness, &mut SHA3Base::new(true), rng).unwrap();
+    assert!(!ComEncEq::<C>::verify(&pp, &other, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let statements = (0..batch_size).map(|_| statement(rng)).collect::<Vec<_>>();
+    let proofs = statements
+        .iter()
+        .map(|(instance, witness)| {
+            ComEncEq::<C>::prove(&pp, instance, witness, &mut SHA3Base::new(true), rng).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let mut tampered = proofs.clone();
+    tampered[batch_size / 2].z_k += C::ScalarField::one();
+    for (proofs, expected) in [(&proofs, vec![]), (&tampered, vec![batch_size / 2])] {
+        let mut batch = statements
+            .iter()
+            .zip(proofs)
+            .map(|((instance, _), proof)| (instance, proof, SHA3Base::new(true)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ComEncEq::<C>::batch_verify(&pp, &mut batch, rng),
+            Ok(expected)
+        );
+    }
+
+    // a malformed instance rejects its own proof only: a missing ciphertext fails the
+    // dimension check, the identity cannot be appended to the transcript
+    let mut instances = statements
+        .iter()
+        .map(|(instance, _)| instance.clone())
+        .collect::<Vec<_>>();
+    instances[0].ciphertexts.pop();
+    instances[batch_size - 1].c[0] = C::Affine::zero();
+    let mut batch = instances
+        .iter()
+        .zip(&proofs)
+        .map(|(instance, proof)| (instance, proof, SHA3Base::new(true)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ComEncEq::<C>::batch_verify(&pp, &mut batch, rng),
+        Ok(vec![0, batch_size - 1])
+    );
+}
+
+/// Generate the Solidity verifier and its fixture for a ComEncEq proof over `l` commitments,
+/// after checking that the contract's transcript replays `SHA3Base` and that malformed
+/// encodings have no challenges.
+fn com_enc_eq_solidity<C: CurveGroup, R: RngCore + CryptoRng>(l: usize, rng: &mut R)
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    let (_, pk) = ElGamal::<C>::keygen(rng);
+    let pp = com_enc_eq::PublicParameters {
+        g: C::Affine::rand(rng),
+        h: C::Affine::rand(rng),
+        pk,
+    };
+    let (instance, witness) = com_enc_eq_statement(&pp, l, rng);
+    let proof =
+        ComEncEq::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(false), rng).unwrap();
+    assert!(ComEncEq::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(false)).unwrap());
+
+    // the verifier's challenges, in the order of the contract
+    let mut transcript = SHA3Base::new(false);
+    let mut expected = ComEncEq::compute_coefficients(&instance, &mut transcript).unwrap();
+    expected.push(ComEncEq::compute_e(&proof.commitment, &mut transcript).unwrap());
+    assert_eq!(
+        ComEncEqVerifier::<C>::challenges(&instance, &proof),
+        Some(expected)
+    );
+
+    let mut missing = instance.clone();
+    missing.ciphertexts.pop();
+    assert_eq!(ComEncEqVerifier::<C>::challenges(&missing, &proof), None);
+
+    fs::create_dir_all("../aegis_contract/contracts/linker").unwrap();
+    fs::write(
+        "../aegis_contract/contracts/linker/ComEncEqVerifier.sol",
+        ComEncEqVerifier::<C>::contract(),
+    )
+    .expect("Unable to write contract");
+    fs::write(
+        "../aegis_contract/result/comEncEqData.ts",
+        ComEncEqVerifier::<C>::fixture(&pp, &instance, &proof),
+    )
+    .expect("Unable to write fixture");
+}
+
+/// Run AmComEq, CompDLEq and CompAmComEq interactively, audit the exchanged rounds, extract
+/// the AmComEq witness by rewinding the prover, and reject a tampered response and a wrong
+/// instance.
+fn interactive_linkers<C: CurveGroup, R: RngCore + CryptoRng>(l: usize, d0: usize, rng: &mut R) {
+    let (pp, instance, witness) = linker_setup::<C, _>(l, d0, 1, 1, rng);
+
+    let mut prover = am_com_eq::interactive::Prover::new(&pp, &instance, &witness).unwrap();
+    let mut verifier = am_com_eq::interactive::Verifier::new(&pp, &instance).unwrap();
+    let (accepted, rounds) = interactive::run(&mut prover, &mut verifier, rng).unwrap();
+    assert!(accepted);
+    assert_eq!(rounds.len(), 2);
+    let coefficients = decode::<Vec<C::ScalarField>>(&rounds[0].challenge).unwrap();
+    let commitment = decode::<am_com_eq::Commitment<C>>(&rounds[0].message).unwrap();
+    let response = decode::<am_com_eq::interactive::Response<C>>(&rounds[1].message).unwrap();
+    let proof = am_com_eq::Proof {
+        commitment,
+        z: response.z,
+        omega: response.omega,
+        omega_hat: response.omega_hat,
+    };
+    let challenge = decode(&rounds[1].challenge).unwrap();
+    assert!(AmComEq::check_responses(
+        &pp,
+        &instance,
+        &proof,
+        &coefficients,
+        challenge
+    ));
+    assert_eq!(
+        prover.next_message(&rounds[1].challenge, rng),
+        Err(interactive::InteractiveError::OutOfOrder)
+    );
+
+    // special soundness: rewinding the prover after its commitment to answer two challenges
+    // opens the witness
+    let mut prover = am_com_eq::interactive::Prover::new(&pp, &instance, &witness).unwrap();
+    prover.next_message(&encode(&coefficients), rng).unwrap();
+    let mut rewound = prover.snapshot();
+    let (e1, e2) = (C::ScalarField::rand(rng), C::ScalarField::rand(rng));
+    let respond = |prover: &mut am_com_eq::interactive::Prover<C>, e, rng: &mut R| {
+        let message = prover.next_message(&encode(&e), rng).unwrap();
+        decode::<am_com_eq::interactive::Response<C>>(&message).unwrap()
+    };
+    let r1 = respond(&mut prover, e1, rng);
+    let r2 = respond(&mut rewound, e2, rng);
+    let extracted =
+        r1.z.iter()
+            .zip(&r2.z)
+            .map(|(z1, z2)| (*z1 - z2) / (e1 - e2))
+            .collect::<Vec<_>>();
+    assert_eq!(extracted, witness.w.concat());
+
+    // a response tampered in transit
+    let mut prover = am_com_eq::interactive::Prover::new(&pp, &instance, &witness).unwrap();
+    let mut verifier = am_com_eq::interactive::Verifier::new(&pp, &instance).unwrap();
+    let mut step = verifier.next_move(None, rng).unwrap();
+    while let Move::Challenge(challenge) = step {
+        let mut message = prover.next_message(&challenge, rng).unwrap();
+        if let Some(mut response) = decode::<am_com_eq::interactive::Response<C>>(&message) {
+            response.z[0] += C::ScalarField::one();
+            message = encode(&response);
+        }
+        step = verifier.next_move(Some(&message), rng).unwrap();
+    }
+    assert_eq!(step, Move::Decision(false));
+
+    let (cde_pp, cde_instance, cde_witness) = comp_dl_eq_setup::<C, _>(l * d0, 2, rng);
+    let mut prover =
+        comp_dl_eq::interactive::Prover::new(&cde_pp, cde_instance.clone(), cde_witness).unwrap();
+    let mut verifier = comp_dl_eq::interactive::Verifier::new(cde_pp, cde_instance).unwrap();
+    let (accepted, rounds) = interactive::run(&mut prover, &mut verifier, rng).unwrap();
+    assert!(accepted);
+    let num_rounds = CompDLEq::<C>::num_rounds(l * d0, 2);
+    assert_eq!(rounds.len(), num_rounds + 1);
+
+    let mut prover = comp_am_com_eq::interactive::Prover::new(&pp, &instance, &witness).unwrap();
+    let mut verifier = comp_am_com_eq::interactive::Verifier::new(&pp, &instance).unwrap();
+    let (accepted, rounds) = interactive::run(&mut prover, &mut verifier, rng).unwrap();
+    assert!(accepted);
+    assert_eq!(rounds.len(), num_rounds + 3);
+
+    let (other, _) = linker_instance(&pp, rng);
+    let mut prover = comp_am_com_eq::interactive::Prover::new(&pp, &other, &witness).unwrap();
+    let mut verifier = comp_am_com_eq::interactive::Verifier::new(&pp, &other).unwrap();
+    let (accepted, _) = interactive::run(&mut prover, &mut verifier, rng).unwrap();
+    assert!(!accepted);
+}
+
 fn cp_link_setup<E: Pairing, R: RngCore + CryptoRng>(
     l: usize,
     rng: &mut R,
 ) -> (
-    PublicParameters<E::G1>,
-    Instance<E::G1>,
-    Witness<E::G1>,
+    cp_link::PublicParameters<E>,
+    cp_link::Instance<E>,
+    cp_link::Witness<E>,
     ProvingKey<E>,
-    Commitment<E>,
 ) {
     let (d0, d2) = (1, 1);
     let g_hat = (0..d0).map(|_| E::G1Affine::rand(rng)).collect::<Vec<_>>();
@@ -172,11 +1170,8 @@ fn cp_link_setup<E: Pairing, R: RngCore + CryptoRng>(
         .collect::<Vec<_>>();
 
     let mock = LinkerCircuit::<E::G1>::mock(l);
-    let (pk, _, ck) = CCGroth16::<E>::setup(mock, 0, l, rng).unwrap();
+    let (pk, _, _) = CCGroth16::<E>::setup(mock, 0, l, rng).unwrap();
 
-    let w_flat = cfg_iter!(w).map(|w_i| w_i[0].clone()).collect::<Vec<_>>();
-    let c = CCGroth16::<E>::commit(&ck, &w_flat, rng).unwrap();
-    let alpha = vec![c.opening];
     let c_hat = cfg_iter!(w)
         .zip(&beta)
         .map(|(w_i, beta_i)| {
@@ -184,21 +1179,20 @@ fn cp_link_setup<E: Pairing, R: RngCore + CryptoRng>(
         })
         .collect::<Vec<_>>();
 
+    let pp = CPLink::<E>::setup(
+        &pk.vk,
+        CommittingKey { g: g_hat, h: h_hat },
+        comp_dl_eq::DEFAULT_THRESHOLD,
+    )
+    .unwrap();
     (
-        PublicParameters {
-            poly_ck: CommittingKey {
-                g: ck.proof_dependent_g1.clone(),
-                h: vec![ck.gamma_eta_g1.clone()],
-            },
-            coeff_ck: CommittingKey { g: g_hat, h: h_hat },
-        },
-        Instance {
-            c: c.cm,
+        pp,
+        cp_link::Instance {
+            public_inputs: vec![],
             c_hat: E::G1::normalize_batch(&c_hat),
         },
-        Witness { w, alpha, beta },
+        cp_link::Witness { w, beta },
         pk,
-        c,
     )
 }
 
@@ -215,18 +1209,15 @@ where
     let mut prover = vec![];
     let mut verifier = vec![];
     for _ in 0..repeat {
-        let (pp, instance, witness, pk, commitment) = cp_link_setup::<E, _>(l, rng);
+        let (pp, instance, witness, pk) = cp_link_setup::<E, _>(l, rng);
 
         let circuit = LinkerCircuit::<E::G1>::new(witness.w.clone());
 
         // prove
         let prv_instant = Instant::now();
         let mut transcript = SHA3Base::new(false);
-        let lego_proof =
-            CCGroth16::<E>::prove(&pk, circuit.clone(), &commitment, rng).expect("proof failed");
-
-        let eclipse_proof =
-            CompAmComEq::<E::G1>::prove(&pp, &instance, &witness, &mut transcript, rng)
+        let proof =
+            CPLink::<E>::prove(&pk, &pp, circuit, &instance, &witness, &mut transcript, rng)
                 .expect("proof failed");
         prover.push(prv_instant.elapsed().as_micros());
         drop(witness);
@@ -235,24 +1226,23 @@ where
         let vry_instant = Instant::now();
         let mut transcript = SHA3Base::new(false);
         assert!(
-            CCGroth16::<E>::verify(&pk.vk, &[], &lego_proof).unwrap(),
-            "lego proof failed"
-        );
-
-        assert!(
-            CompAmComEq::<E::G1>::verify(&pp, &instance, &eclipse_proof, &mut transcript).unwrap(),
-            "eclipse proof failed"
+            CPLink::<E>::verify(&pp, &instance, &proof, &mut transcript).unwrap(),
+            "cp-link proof failed"
         );
         verifier.push(vry_instant.elapsed().as_micros());
 
+        let mut tampered = instance.clone();
+        tampered.c_hat[0] = (tampered.c_hat[0] + pp.linker.coeff_ck.g[0]).into_affine();
+        assert!(!CPLink::<E>::verify(&pp, &tampered, &proof, &mut SHA3Base::new(false)).unwrap());
+
         if repeat == 1 {
-            println!("const vk = {:?}", pk.vk.to_solidity());
-            println!("const pp = {:?}", pp.to_solidity());
+            println!("const vk = {:?}", pp.vk.to_solidity());
+            println!("const pp = {:?}", pp.linker.to_solidity());
 
-            println!("const lego_proof = {:?}", lego_proof.to_solidity());
-            println!("const eclipse_proof = {:?}", eclipse_proof.to_solidity());
+            println!("const lego_proof = {:?}", proof.snark.to_solidity());
+            println!("const eclipse_proof = {:?}", proof.link.to_solidity());
 
-            println!("const single = {:?}", instance.c.to_solidity());
+            println!("const single = {:?}", proof.snark.d.to_solidity());
             println!("const multi = {:?}", instance.c_hat.to_solidity());
 
             println!(
@@ -305,6 +1295,62 @@ pub mod bn254 {
         assert!(AmComEq::<C>::verify(&pp, &instance, &proof, &mut transcript).unwrap());
     }
 
+    #[test]
+    fn invalid_proof_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        invalid_proofs::<C, _>(4, 2, &mut rng);
+    }
+
+    #[test]
+    fn batch_verification_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        batch_verification::<C, _>(4, 1 << *LOG_MIN, *D0, &mut rng);
+        // odd lengths fold without padding
+        batch_verification::<C, _>(3, 3, 3, &mut rng);
+    }
+
+    #[test]
+    fn comp_dl_eq_threshold_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for n in [1, 5, 6, 7, 12] {
+            for threshold in [1, 2, 3] {
+                comp_dl_eq_threshold::<C, _>(n, threshold, &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn comp_threshold_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for threshold in [1, 3, 5] {
+            comp_threshold::<C, _>(3, 4, threshold, &mut rng);
+        }
+    }
+
+    #[test]
+    fn com_pok_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for n in *LOG_MIN..=*LOG_MAX {
+            com_pok::<C, _>(1 << n, 5, &mut rng);
+        }
+    }
+
+    #[test]
+    fn merlin_transcript_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for n in *LOG_MIN..=*LOG_MAX {
+            let transcript = MerlinBase::with_protocol(b"Aegis linker");
+            linker_with_transcript::<C, _, _>(1 << n, *D0, *D1, *D2, &transcript, &mut rng);
+        }
+    }
+
+    #[test]
+    fn poseidon_transcript_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        let transcript = PoseidonBase::<ark_bn254::Fr>::new(true);
+        linker_with_transcript::<C, _, _>(1 << *LOG_MIN, *D0, *D1, *D2, &transcript, &mut rng);
+    }
+
     #[test]
     fn comp_am_com_eq_scenario() {
         let mut rng = R::seed_from_u64(test_rng().next_u64());
@@ -321,6 +1367,55 @@ pub mod bn254 {
         }
     }
 
+    #[test]
+    fn comp_am_com_eq_solidity_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        // a threshold above the default leaves a longer final response for the contract
+        comp_am_com_eq_solidity::<C, _>(4, 2, 1, 1, 5, &mut rng);
+    }
+
+    #[test]
+    fn multi_am_com_eq_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        let threshold = comp_dl_eq::DEFAULT_THRESHOLD;
+        multi_am_com_eq::<C, _>(&[4], 2, false, threshold, &mut rng);
+        multi_am_com_eq::<C, _>(&[2, 1, 3], 1, true, threshold, &mut rng);
+        multi_am_com_eq::<C, _>(&[3, 2], 2, false, 3, &mut rng);
+        multi_am_com_eq::<C, _>(&[1, 2], 3, true, 1, &mut rng);
+        multi_am_com_eq::<C, _>(&[2, 3], 2, true, 4, &mut rng);
+    }
+
+    #[test]
+    fn comp_sum_eq_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for n in [1, 5, 8] {
+            for threshold in [comp_dl_eq::DEFAULT_THRESHOLD, 3] {
+                comp_sum_eq::<C, _>(n, false, threshold, &mut rng);
+                comp_sum_eq::<C, _>(n, true, threshold, &mut rng);
+            }
+        }
+    }
+
+    #[test]
+    fn com_enc_eq_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        com_enc_eq::<C, _>(1, 3, &mut rng);
+        com_enc_eq::<C, _>(1 << *LOG_MIN, 4, &mut rng);
+    }
+
+    #[test]
+    fn com_enc_eq_solidity_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        com_enc_eq_solidity::<C, _>(4, &mut rng);
+    }
+
+    #[test]
+    fn interactive_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        interactive_linkers::<C, _>(1 << *LOG_MIN, *D0, &mut rng);
+        interactive_linkers::<C, _>(3, 3, &mut rng);
+    }
+
     #[test]
     fn cp_link_scenario() {
         let mut rng = R::seed_from_u64(test_rng().next_u64());
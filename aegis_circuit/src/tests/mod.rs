@@ -1,5 +1,11 @@
 mod aegis_circuit;
+mod commitment;
+mod dlog;
 mod linker;
+mod pol;
+mod range_proof;
+mod sigma;
+mod transcript;
 mod utils;
 
 use lazy_static::lazy_static;
@@ -0,0 +1,159 @@
+use ark_ec::pairing::Pairing;
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    UniformRand, Zero,
+};
+
+use crate::{
+    aegis::{Aegis, BatchProvingKey, BatchUpdate},
+    crypto::commitment::{pedersen::Pedersen, BatchCommitmentScheme},
+    gro::CommittingKey,
+    pol::{AppliedBatch, Epoch, Pol, PolError, Witness},
+};
+
+/// Range-check width of the balances, in the DBT circuit and in the range proof.
+const BITS: usize = 64;
+
+fn commit_accounts<E: Pairing>(
+    ck: &CommittingKey<E>,
+    accounts: &[Vec<E::ScalarField>],
+) -> Vec<E::G1Affine> {
+    let slices = accounts.iter().map(|a| &a[..]).collect::<Vec<_>>();
+    Pedersen::<E::G1>::batch_commit(&ck.batch_g1, &slices)
+}
+
+/// Apply `delta` to the accounts at `indices` with a DBT batch, and return it as published.
+fn apply_batch<E: Pairing, R: RngCore + CryptoRng>(
+    pk: &BatchProvingKey<E>,
+    accounts: &mut [Vec<E::ScalarField>],
+    indices: &[usize],
+    delta: &[Vec<E::ScalarField>],
+    rng: &mut R,
+) -> AppliedBatch<E> {
+    let prev = indices.iter().map(|i| accounts[*i].clone()).collect();
+    let update = BatchUpdate::from_delta(prev, delta);
+    let net_issuance = update.net_issuance();
+
+    let commitment = Aegis::<E>::commit(&pk.pk.vk.ck, &update, rng).unwrap();
+    let proof = Aegis::<E>::prove(pk, update.clone(), net_issuance, &commitment, rng).unwrap();
+    for (i, curr) in indices.iter().zip(update.curr) {
+        accounts[*i] = curr;
+    }
+
+    AppliedBatch {
+        accounts: indices.iter().map(|i| *i as u64).collect(),
+        commitments: commitment.commitments,
+        net_issuance,
+        proof,
+    }
+}
+
+/// Prove the liabilities of a genesis epoch, move to the next epoch through a transfer and a
+/// mint, and reject proofs and chains that do not match the published figures.
+fn pol<E: Pairing, R: RngCore + CryptoRng>(rng: &mut R) {
+    let (pk, vk, ck) = Aegis::<E>::setup(2, 2, BITS, rng).unwrap();
+    let pp = Pol::<E>::setup(&ck, BITS, 4, rng).unwrap();
+    let f = |x: u64| E::ScalarField::from(x);
+
+    // genesis: [balance, blinding] of four accounts
+    let mut accounts = [100, 200, 300, 400]
+        .into_iter()
+        .map(|balance| vec![f(balance), E::ScalarField::rand(rng)])
+        .collect::<Vec<_>>();
+    let genesis = Epoch::<E>::genesis(f(1000), commit_accounts(&ck, &accounts));
+    let witness = Witness::from_accounts(&accounts).unwrap();
+    let proof = Pol::<E>::prove(&pp, &genesis, &witness, rng).unwrap();
+    assert!(Pol::<E>::verify(&pp, &genesis, &proof).unwrap());
+
+    // the figure is bound to the proof
+    let mut understated = genesis.clone();
+    understated.liabilities = f(900);
+    assert!(!Pol::<E>::verify(&pp, &understated, &proof).unwrap());
+    assert_eq!(
+        Pol::<E>::prove(&pp, &understated, &witness, rng),
+        Err(PolError::LiabilitiesMismatch)
+    );
+
+    // a negative balance has no 64-bit opening
+    let mut negative = accounts.clone();
+    negative[0][0] = -f(5);
+    assert_eq!(Witness::from_accounts(&negative), Err(PolError::OutOfRange));
+
+    // transfer 30 from account 0 to account 2, then mint 50 into account 1
+    let transfer = apply_batch(
+        &pk,
+        &mut accounts,
+        &[0, 2],
+        &[
+            vec![-f(30), E::ScalarField::rand(rng)],
+            vec![f(30), E::ScalarField::rand(rng)],
+        ],
+        rng,
+    );
+    let mint = apply_batch(
+        &pk,
+        &mut accounts,
+        &[1, 3],
+        &[
+            vec![f(50), E::ScalarField::rand(rng)],
+            vec![E::ScalarField::zero(), E::ScalarField::rand(rng)],
+        ],
+        rng,
+    );
+    assert_eq!(mint.net_issuance, f(50));
+    let batches = vec![transfer, mint];
+
+    let (commitments, liabilities) = Pol::<E>::apply(&vk, &genesis, &batches).unwrap().unwrap();
+    assert_eq!(commitments, commit_accounts(&ck, &accounts));
+    assert_eq!(liabilities, f(1050));
+
+    let epoch = genesis.next(&batches, liabilities, commitments);
+    let witness = Witness::from_accounts(&accounts).unwrap();
+    let proof = Pol::<E>::prove(&pp, &epoch, &witness, rng).unwrap();
+    assert!(Pol::<E>::verify_chain(&pp, &vk, &genesis, &batches, &epoch, &proof).unwrap());
+
+    // the epoch must follow the previous one through exactly the published batches
+    assert!(!Pol::<E>::verify_chain(&pp, &vk, &epoch, &batches, &epoch, &proof).unwrap());
+    assert!(!Pol::<E>::verify_chain(&pp, &vk, &genesis, &batches[..1], &epoch, &proof).unwrap());
+
+    // hiding the mint leaves the commitments and the liabilities unexplained
+    let hidden = genesis.next(&batches[..1], f(1000), epoch.commitments.clone());
+    assert!(!Pol::<E>::verify_chain(&pp, &vk, &genesis, &batches[..1], &hidden, &proof).unwrap());
+
+    // a batch whose DBT proof does not verify does not apply
+    let mut forged = batches.clone();
+    forged[1].net_issuance = f(0);
+    assert_eq!(Pol::<E>::apply(&vk, &genesis, &forged), Ok(None));
+
+    // a batch whose current commitment does not extend the account does not apply
+    let mut swapped = batches.clone();
+    swapped[0].accounts.swap(0, 1);
+    assert_eq!(Pol::<E>::apply(&vk, &genesis, &swapped), Ok(None));
+
+    // malformed batches do not apply either
+    let mut unknown = batches.clone();
+    unknown[0].accounts[0] = 4;
+    assert_eq!(Pol::<E>::apply(&vk, &genesis, &unknown), Ok(None));
+
+    let mut truncated = batches.clone();
+    truncated[1].accounts.pop();
+    assert_eq!(Pol::<E>::apply(&vk, &genesis, &truncated), Ok(None));
+    assert!(!Pol::<E>::verify_chain(&pp, &vk, &genesis, &truncated, &epoch, &proof).unwrap());
+}
+
+pub mod bn254 {
+    use super::*;
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        test_rng,
+    };
+
+    type E = ark_bn254::Bn254;
+    type R = StdRng;
+
+    #[test]
+    fn pol_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        pol::<E, _>(&mut rng);
+    }
+}
@@ -0,0 +1,221 @@
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    fields::{fp::FpVar, FieldVar},
+};
+use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+use ark_std::{
+    rand::{CryptoRng, Rng},
+    One, UniformRand, Zero,
+};
+
+use crate::{
+    bulletproofs::{Instance, RangeProof, RangeProofError, Witness},
+    crypto::{
+        protocol::{
+            sigma::SigmaProtocol,
+            transcript::{sha3::SHA3Base, TranscriptProtocol},
+        },
+        range::{self, RangeGadget},
+    },
+    solidity::Solidity,
+};
+
+/// Prove that `num_values` random values fit in `bits` bits, and reject tampered proofs.
+/// Returns the number of Solidity words of the proof.
+fn range_proof<C: CurveGroup, R: Rng + CryptoRng>(
+    bits: usize,
+    num_values: usize,
+    rng: &mut R,
+) -> usize
+where
+    C::Affine: Solidity,
+    C::ScalarField: Solidity,
+{
+    let pp = RangeProof::<C>::generate_parameters(bits, num_values, rng).unwrap();
+    let pp = RangeProof::<C>::setup(&pp).unwrap();
+
+    let v = (0..num_values)
+        .map(|_| match bits {
+            64 => rng.gen::<u64>(),
+            _ => rng.gen_range(0..1u64 << bits),
+        })
+        .collect::<Vec<_>>();
+    let gamma = (0..num_values)
+        .map(|_| C::ScalarField::rand(rng))
+        .collect::<Vec<_>>();
+    let commitments = v
+        .iter()
+        .zip(&gamma)
+        .map(|(v, gamma)| pp.g * C::ScalarField::from(*v) + pp.h * gamma)
+        .collect::<Vec<_>>();
+    let instance = Instance {
+        v: C::normalize_batch(&commitments),
+    };
+    let witness = Witness { v, gamma };
+
+    let proof = RangeProof::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert!(RangeProof::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.t_hat += C::ScalarField::one();
+    assert!(!RangeProof::<C>::verify(&pp, &instance, &tampered, &mut SHA3Base::new(true)).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.ipp.a += C::ScalarField::one();
+    assert!(!RangeProof::<C>::verify(&pp, &instance, &tampered, &mut SHA3Base::new(true)).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.ipp.l_vec.pop();
+    assert!(!RangeProof::<C>::verify(&pp, &instance, &tampered, &mut SHA3Base::new(true)).unwrap());
+
+    let mut other = instance.clone();
+    other.v[0] = (other.v[0] + pp.g).into_affine();
+    assert!(!RangeProof::<C>::verify(&pp, &other, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    proof.to_solidity().len()
+}
+
+/// A proof with 64 inner-product rounds, which would shift `1 << 64`, is rejected for a single
+/// 1-bit value.
+fn oversized_inner_product<C: CurveGroup, R: Rng + CryptoRng>(rng: &mut R) {
+    let pp = RangeProof::<C>::generate_parameters(1, 1, rng).unwrap();
+    let gamma = C::ScalarField::rand(rng);
+    let instance = Instance {
+        v: vec![(pp.g + pp.h * gamma).into_affine()],
+    };
+    let witness = Witness {
+        v: vec![1],
+        gamma: vec![gamma],
+    };
+    let mut proof = RangeProof::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng)
+        .expect("proof failed");
+    assert!(proof.ipp.l_vec.is_empty());
+
+    proof.ipp.l_vec = vec![pp.g; 64];
+    proof.ipp.r_vec = vec![pp.h; 64];
+    assert_eq!(
+        RangeProof::<C>::verify(&pp, &instance, &proof, &mut SHA3Base::new(true)),
+        Ok(false)
+    );
+}
+
+/// The prover refuses values outside of the range.
+fn out_of_range<C: CurveGroup, R: Rng + CryptoRng>(bits: usize, rng: &mut R) {
+    let pp = RangeProof::<C>::generate_parameters(bits, 1, rng).unwrap();
+    let gamma = C::ScalarField::rand(rng);
+    let v = 1u64 << bits;
+    let instance = Instance {
+        v: vec![(pp.g * C::ScalarField::from(v) + pp.h * gamma).into_affine()],
+    };
+    let witness = Witness {
+        v: vec![v],
+        gamma: vec![gamma],
+    };
+    let result = RangeProof::<C>::prove(&pp, &instance, &witness, &mut SHA3Base::new(true), rng);
+    assert_eq!(result, Err(RangeProofError::ValueOutOfRange));
+}
+
+/// Whether `value` passes the range check of `bits` bits, signed or not, in a fresh circuit.
+/// The native checks must agree and the gadget must add the announced number of constraints.
+fn range_gadget_accepts<F: PrimeField>(value: F, bits: usize, signed: bool) -> bool {
+    let cs = ConstraintSystem::<F>::new_ref();
+    let var = FpVar::new_witness(cs.clone(), || Ok(value)).unwrap();
+    let (decomposition, expected, native) = if signed {
+        (
+            RangeGadget::enforce_signed_range(&var, bits).unwrap(),
+            RangeGadget::<F>::num_signed_constraints(bits),
+            range::in_signed_range(&value, bits),
+        )
+    } else {
+        (
+            RangeGadget::enforce_range(&var, bits).unwrap(),
+            RangeGadget::<F>::num_constraints(bits),
+            range::in_range(&value, bits),
+        )
+    };
+    assert_eq!(decomposition.len(), bits + usize::from(signed));
+    assert_eq!(cs.num_constraints(), expected);
+
+    let accepted = cs.is_satisfied().unwrap();
+    assert_eq!(accepted, native);
+    accepted
+}
+
+/// The bounds of `[0, 2^bits)` and `[-2^bits, 2^bits)` are exact, and a value wrapping around
+/// the modulus has no decomposition.
+fn range_gadget<F: PrimeField>(bits: usize) {
+    let bound = F::from(2u64).pow([bits as u64]);
+    let one = F::one();
+
+    assert!(range_gadget_accepts(F::zero(), bits, false));
+    assert!(range_gadget_accepts(bound - one, bits, false));
+    assert!(!range_gadget_accepts(bound, bits, false));
+    assert!(!range_gadget_accepts(-one, bits, false));
+
+    assert!(range_gadget_accepts(-bound, bits, true));
+    assert!(range_gadget_accepts(bound - one, bits, true));
+    assert!(!range_gadget_accepts(bound, bits, true));
+    assert!(!range_gadget_accepts(-bound - one, bits, true));
+
+    // the constant path checks the value without constraints
+    assert!(RangeGadget::enforce_range(&FpVar::constant(bound - one), bits).is_ok());
+    assert!(RangeGadget::enforce_range(&FpVar::constant(bound), bits).is_err());
+}
+
+pub mod bn254 {
+    use super::*;
+    use ark_std::{
+        rand::{rngs::StdRng, RngCore, SeedableRng},
+        test_rng,
+    };
+
+    type C = ark_bn254::G1Projective;
+    type F = ark_bn254::Fr;
+    type R = StdRng;
+
+    #[test]
+    fn range_proof_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        for (bits, num_values) in [(8, 1), (32, 3), (64, 4)] {
+            let words = range_proof::<C, _>(bits, num_values, &mut rng);
+            println!(
+                "bits: {} values: {} proof size (words): {}",
+                bits, num_values, words
+            );
+        }
+    }
+
+    #[test]
+    fn range_gadget_bounds() {
+        for bits in [1, 8, 64, range::max_bits::<F>()] {
+            range_gadget::<F>(bits);
+        }
+        let cs = ConstraintSystem::<F>::new_ref();
+        let var = FpVar::new_witness(cs, || Ok(F::zero())).unwrap();
+        for bits in [0, range::max_bits::<F>() + 1] {
+            assert_eq!(
+                RangeGadget::enforce_range(&var, bits),
+                Err(SynthesisError::Unsatisfiable)
+            );
+        }
+    }
+
+    #[test]
+    fn oversized_inner_product_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        oversized_inner_product::<C, _>(&mut rng);
+    }
+
+    #[test]
+    fn range_proof_out_of_range() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        out_of_range::<C, _>(16, &mut rng);
+        assert_eq!(
+            RangeProof::<C>::generate_parameters(12, 1, &mut rng),
+            Err(RangeProofError::InvalidBitWidth)
+        );
+    }
+}
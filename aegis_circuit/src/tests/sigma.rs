@@ -0,0 +1,224 @@
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_std::{
+    rand::{CryptoRng, RngCore},
+    UniformRand,
+};
+
+use crate::{
+    crypto::{
+        commitment::{pedersen::Pedersen, CommitmentScheme},
+        protocol::{
+            sigma::{
+                and::And,
+                or::{Or, OrWitness},
+                ComposableSigma, CompositionError, MovesProof, SigmaProtocol,
+            },
+            transcript::{sha3::SHA3Base, TranscriptProtocol},
+        },
+    },
+    linker::{
+        am_com_eq::{
+            data_structure::{CommittingKey, Instance, PublicParameters, Witness},
+            interactive::Response,
+            AmComEq,
+        },
+        comp_am_com_eq::CompAmComEq,
+        comp_dl_eq::{CompDLEq, DEFAULT_THRESHOLD},
+    },
+};
+
+type Statement<C> = (PublicParameters<C>, Instance<C>, Witness<C>);
+
+/// Linker statement over `l` commitments of length `d0`, under fresh generators.
+fn statement<C: CurveGroup, R: RngCore + CryptoRng>(
+    l: usize,
+    d0: usize,
+    rng: &mut R,
+) -> Statement<C> {
+    let (d1, d2) = (2, 2);
+    let mut points = |n: usize| (0..n).map(|_| C::Affine::rand(rng)).collect::<Vec<_>>();
+    let pp = PublicParameters {
+        poly_ck: CommittingKey {
+            g: points(l * d0),
+            h: points(d1),
+        },
+        coeff_ck: CommittingKey {
+            g: points(d0),
+            h: points(d2),
+        },
+        threshold: DEFAULT_THRESHOLD,
+    };
+
+    let mut scalars = |n: usize| {
+        (0..n)
+            .map(|_| C::ScalarField::rand(rng))
+            .collect::<Vec<_>>()
+    };
+    let w = (0..l).map(|_| scalars(d0)).collect::<Vec<_>>();
+    let alpha = scalars(d1);
+    let beta = (0..l).map(|_| scalars(d2)).collect::<Vec<_>>();
+
+    let c = Pedersen::<C>::commit(&pp.poly_ck.g, &w.concat()).into_group()
+        + Pedersen::<C>::commit(&pp.poly_ck.h, &alpha);
+    let c_hat = w
+        .iter()
+        .zip(&beta)
+        .map(|(w_i, beta_i)| {
+            Pedersen::<C>::commit(&pp.coeff_ck.g, w_i).into_group()
+                + Pedersen::<C>::commit(&pp.coeff_ck.h, beta_i)
+        })
+        .collect::<Vec<_>>();
+    let instance = Instance {
+        c: c.into_affine(),
+        c_hat: C::normalize_batch(&c_hat),
+    };
+    (pp, instance, Witness { w, alpha, beta })
+}
+
+/// The same commitments with `c` shifted, so no witness exists.
+fn unlinked<C: CurveGroup>(pp: &PublicParameters<C>, instance: &Instance<C>) -> Instance<C> {
+    Instance {
+        c: (instance.c + pp.poly_ck.h[0]).into_affine(),
+        c_hat: instance.c_hat.clone(),
+    }
+}
+
+/// Compose AmComEq and CompAmComEq statements under AND and OR, nested, and reject proofs for
+/// the false branch, moved challenges and mismatched witnesses.
+fn composition<C: CurveGroup, R: RngCore + CryptoRng>(l: usize, d0: usize, rng: &mut R) {
+    type Either<C> = Or<AmComEq<C>, CompAmComEq<C>>;
+    type Both<C> = And<CompAmComEq<C>, AmComEq<C>>;
+
+    let (pp_a, instance_a, witness_a) = statement::<C, _>(l, d0, rng);
+    let (pp_b, instance_b, witness_b) = statement::<C, _>(l, d0, rng);
+    let (false_a, false_b) = (unlinked(&pp_a, &instance_a), unlinked(&pp_b, &instance_b));
+
+    // the moves reproduce the plain protocol
+    let proof = AmComEq::<C>::prove(
+        &pp_a,
+        &instance_a,
+        &witness_a,
+        &mut SHA3Base::new(true),
+        rng,
+    )
+    .unwrap();
+    let moves = MovesProof {
+        commitment: proof.commitment,
+        response: Response {
+            z: proof.z,
+            omega: proof.omega,
+            omega_hat: proof.omega_hat,
+        },
+    };
+    assert!(
+        AmComEq::<C>::verify_moves(&pp_a, &instance_a, &moves, &mut SHA3Base::new(true)).unwrap()
+    );
+
+    // either branch may hold the witness, the compressed one is simulated or folded
+    let pp = (pp_a.clone(), pp_b.clone());
+    let left = (instance_a.clone(), false_b.clone());
+    let proof = Either::<C>::prove(
+        &pp,
+        &left,
+        &OrWitness::Left(witness_a.clone()),
+        &mut SHA3Base::new(true),
+        rng,
+    )
+    .unwrap();
+    assert!(Either::<C>::verify(&pp, &left, &proof, &mut SHA3Base::new(true)).unwrap());
+    assert_eq!(
+        proof.response.right.z.len(),
+        CompDLEq::<C>::final_len(l * d0, pp_b.threshold)
+    );
+
+    let right = (false_a.clone(), instance_b.clone());
+    let proof = Either::<C>::prove(
+        &pp,
+        &right,
+        &OrWitness::Right(witness_b.clone()),
+        &mut SHA3Base::new(true),
+        rng,
+    )
+    .unwrap();
+    assert!(Either::<C>::verify(&pp, &right, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    // moving the challenge between the branches breaks both
+    let mut tampered = proof.clone();
+    tampered.response.e1 += C::ScalarField::from(1u64);
+    assert!(!Either::<C>::verify(&pp, &right, &tampered, &mut SHA3Base::new(true)).unwrap());
+
+    // a witness for the false branch does not help
+    let proof = Either::<C>::prove(
+        &pp,
+        &left,
+        &OrWitness::Right(witness_b.clone()),
+        &mut SHA3Base::new(true),
+        rng,
+    )
+    .unwrap();
+    assert!(!Either::<C>::verify(&pp, &left, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    // the response must come from the branch that was committed
+    let mut transcript = SHA3Base::new(true);
+    let context = Either::<C>::append_instance(&pp, &left, &mut transcript).unwrap();
+    let (state, _) =
+        Either::<C>::commit(&pp, &context, &OrWitness::Left(witness_a.clone()), rng).unwrap();
+    assert!(matches!(
+        Either::<C>::respond(
+            &pp,
+            &context,
+            &OrWitness::Right(witness_b.clone()),
+            state,
+            C::ScalarField::rand(rng),
+        ),
+        Err(CompositionError::WitnessMismatch)
+    ));
+
+    // both statements under one challenge
+    let both_pp = (pp_b.clone(), pp_a.clone());
+    let both = (instance_b.clone(), instance_a.clone());
+    let witness = (witness_b.clone(), witness_a.clone());
+    let mut transcript = SHA3Base::new(true);
+    transcript.append(b"context", b"both");
+    let proof = Both::<C>::prove(&both_pp, &both, &witness, &mut transcript.clone(), rng).unwrap();
+    assert!(Both::<C>::verify(&both_pp, &both, &proof, &mut transcript.clone()).unwrap());
+    assert!(!Both::<C>::verify(&both_pp, &both, &proof, &mut SHA3Base::new(true)).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.response.0.omega[0] += C::ScalarField::from(1u64);
+    assert!(!Both::<C>::verify(&both_pp, &both, &tampered, &mut transcript.clone()).unwrap());
+
+    let false_both = (instance_b.clone(), false_a.clone());
+    assert!(!Both::<C>::verify(&both_pp, &false_both, &proof, &mut transcript.clone()).unwrap());
+
+    // nested: the first statement and either of the others, under one challenge
+    type Nested<C> = And<AmComEq<C>, Either<C>>;
+    let (pp_c, instance_c, witness_c) = statement::<C, _>(l, d0, rng);
+    let pp = (pp_c, pp);
+    let instance = (instance_c, right);
+    let witness = (witness_c, OrWitness::Right(witness_b));
+    let proof = Nested::<C>::prove(&pp, &instance, &witness, &mut transcript.clone(), rng).unwrap();
+    assert!(Nested::<C>::verify(&pp, &instance, &proof, &mut transcript.clone()).unwrap());
+
+    let mut tampered = proof.clone();
+    tampered.response.1.right.z[0] += C::ScalarField::from(1u64);
+    assert!(!Nested::<C>::verify(&pp, &instance, &tampered, &mut transcript.clone()).unwrap());
+}
+
+pub mod bn254 {
+    use super::*;
+    use ark_std::{
+        rand::{rngs::StdRng, SeedableRng},
+        test_rng,
+    };
+
+    type C = ark_bn254::G1Projective;
+    type R = StdRng;
+
+    #[test]
+    fn composition_scenario() {
+        let mut rng = R::seed_from_u64(test_rng().next_u64());
+        composition::<C, _>(2, 4, &mut rng);
+        composition::<C, _>(3, 5, &mut rng);
+    }
+}
@@ -0,0 +1,148 @@
+use ark_crypto_primitives::sponge::{poseidon::find_poseidon_ark_and_mds, Absorb};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, uint8::UInt8, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_std::rand::Rng;
+
+use crate::crypto::protocol::transcript::{
+    merlin::MerlinBase,
+    poseidon::{poseidon_config, PoseidonBase, PoseidonBaseVar, FULL_ROUNDS, PARTIAL_ROUNDS, RATE},
+    sha3::SHA3Base,
+    TranscriptProtocol,
+};
+
+/// Challenge after appending `items` under the same label.
+fn challenge<T: TranscriptProtocol, F: PrimeField>(mut transcript: T, items: &[&[u8]]) -> F {
+    items
+        .iter()
+        .for_each(|item| transcript.append(b"item", item));
+    transcript.challenge_scalar(b"challenge")
+}
+
+/// Forked children diverge from each other and leave the parent unchanged.
+fn fork_branches<T: TranscriptProtocol, F: PrimeField>(mut transcript: T) {
+    transcript.append(b"instance", b"shared prefix");
+    let reference = transcript.clone();
+
+    let left: F = transcript.fork(b"left").challenge_scalar(b"challenge");
+    let right: F = transcript.fork(b"right").challenge_scalar(b"challenge");
+    let again: F = transcript.fork(b"left").challenge_scalar(b"challenge");
+    assert_ne!(left, right);
+    assert_eq!(left, again);
+
+    let parent: Vec<F> = transcript.challenge_scalars(b"challenge", 3);
+    let expected: Vec<F> = reference.clone().challenge_scalars(b"challenge", 3);
+    assert_eq!(parent, expected);
+    assert_ne!(parent[0], parent[1]);
+    assert_ne!(parent[0], left);
+}
+
+/// Replay a Poseidon transcript in the circuit and compare both challenges with the native ones.
+/// Returns the number of constraints.
+fn poseidon_replay<CF, F, R>(use_label: bool, rng: &mut R) -> usize
+where
+    CF: PrimeField + Absorb,
+    F: PrimeField,
+    R: Rng,
+{
+    let bytes = (0..48).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+    let elements = (0..3).map(|_| CF::rand(rng)).collect::<Vec<_>>();
+
+    let mut transcript = PoseidonBase::<CF>::new(use_label);
+    transcript.append(b"bytes", &bytes);
+    transcript.append_field_elements(b"elements", &elements);
+    let native: CF = transcript.challenge_scalar(b"native");
+    let nonnative: F = transcript.challenge_scalar(b"nonnative");
+    let forked: Vec<CF> = transcript.fork(b"fork").challenge_scalars(b"forked", 2);
+
+    let cs = ConstraintSystem::<CF>::new_ref();
+    let bytes_var = UInt8::new_witness_vec(cs.clone(), &bytes).unwrap();
+    let elements_var = Vec::<FpVar<CF>>::new_witness(cs.clone(), || Ok(elements)).unwrap();
+
+    let mut transcript_var = PoseidonBaseVar::<CF>::new(cs.clone(), use_label);
+    transcript_var.append(b"bytes", &bytes_var).unwrap();
+    transcript_var
+        .append_field_elements(b"elements", &elements_var)
+        .unwrap();
+    let native_var = transcript_var.challenge_scalar(b"native").unwrap();
+    let nonnative_var = transcript_var
+        .challenge_nonnative::<F>(b"nonnative")
+        .unwrap();
+    let forked_var = transcript_var
+        .fork(b"fork")
+        .unwrap()
+        .challenge_scalars(b"forked", 2)
+        .unwrap();
+
+    assert_eq!(native_var.value().unwrap(), native);
+    assert_eq!(nonnative_var.value().unwrap(), nonnative);
+    assert_eq!(forked_var.value().unwrap(), forked);
+    assert!(cs.is_satisfied().unwrap());
+    cs.num_constraints()
+}
+
+pub mod bn254 {
+    use super::*;
+
+    type F = ark_bn254::Fr;
+
+    #[test]
+    fn framed_appends() {
+        // Merlin frames every append, so ["ab", "c"] and ["a", "bc"] differ; SHA3Base concatenates
+        // the raw bytes and cannot tell them apart
+        let left: F = challenge(MerlinBase::new(false), &[b"ab", b"c"]);
+        let right: F = challenge(MerlinBase::new(false), &[b"a", b"bc"]);
+        assert_ne!(left, right);
+    }
+
+    #[test]
+    fn protocol_domain_separation() {
+        let items: &[&[u8]] = &[b"instance"];
+        let left: F = challenge(MerlinBase::with_protocol(b"AmComEq"), items);
+        let right: F = challenge(MerlinBase::with_protocol(b"CompDLEq"), items);
+        assert_ne!(left, right);
+
+        let again: F = challenge(MerlinBase::with_protocol(b"AmComEq"), items);
+        assert_eq!(left, again);
+    }
+
+    #[test]
+    fn fork_independence() {
+        fork_branches::<_, F>(SHA3Base::new(true));
+        fork_branches::<_, F>(SHA3Base::new(false));
+        fork_branches::<_, F>(MerlinBase::new(true));
+        fork_branches::<_, F>(PoseidonBase::<F>::new(true));
+    }
+
+    #[test]
+    fn poseidon_circuit_replay() {
+        let mut rng = ark_std::test_rng();
+        for use_label in [true, false] {
+            let num_constraints = poseidon_replay::<F, ark_bn254::Fq, _>(use_label, &mut rng);
+            println!(
+                "use_label: {} Number of constraints: {}",
+                use_label, num_constraints
+            );
+        }
+    }
+
+    #[test]
+    fn poseidon_config_cache() {
+        // the cache is keyed by field, and serves the generated constants of each
+        fn cached<F: PrimeField>() {
+            let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+                F::MODULUS_BIT_SIZE as u64,
+                RATE,
+                FULL_ROUNDS as u64,
+                PARTIAL_ROUNDS as u64,
+                0,
+            );
+            for _ in 0..2 {
+                let config = poseidon_config::<F>();
+                assert_eq!((config.ark, config.mds), (ark.clone(), mds.clone()));
+            }
+        }
+        cached::<F>();
+        cached::<ark_bn254::Fq>();
+    }
+}
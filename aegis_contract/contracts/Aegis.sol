@@ -14,6 +14,11 @@ import "hardhat/console.sol";
 
 contract Aegis is AccessControl {
     bytes32 public constant VALIDATOR_ROLE = keccak256("VALIDATOR_ROLE");
+    // only the central bank mints or burns, i.e. submits a non-zero netIssuance
+    bytes32 public constant ISSUER_ROLE = keccak256("ISSUER_ROLE");
+
+    // public inputs of the DBT circuit: [tau, netIssuance]
+    uint256 internal constant NUM_INPUTS = 2;
 
     // set user balance using cm
     mapping(address => Bn128.G1Point) public _CMList;
@@ -23,6 +28,8 @@ contract Aegis is AccessControl {
         address[] userAddress;
         address[] contractAddress;
         Bn128.G1Point[] deltaCm;
+        // amount minted by the batch, p - amount for a burn, 0 for a transfer
+        uint256 netIssuance;
     }
 
     // vrs
@@ -48,9 +55,16 @@ contract Aegis is AccessControl {
         _grantRole(DEFAULT_ADMIN_ROLE, msg.sender);
         // CHECK : change msg.sender to address(this)
         _grantRole(VALIDATOR_ROLE, msg.sender);
+        _grantRole(ISSUER_ROLE, msg.sender);
 
-        // check vk length
-        require(_vk.length == 18, "vk length is failed");
+        // check vk length: alpha, beta, gamma, delta and gamma_abc for [1, tau, netIssuance]
+        require(
+            _vk.length ==
+                ccGroth16VerifyBn128._GENERAL_VK_LENGTH +
+                    ccGroth16VerifyBn128._INPUT_VK_LENGTH *
+                    NUM_INPUTS,
+            "vk length is failed"
+        );
         require(
             _ck.length == 0 || _ck.length == 4,
             "Invalid Committing Key Size"
@@ -66,6 +80,12 @@ contract Aegis is AccessControl {
         grantRole(VALIDATOR_ROLE, contractAddress);
     }
 
+    function grantIssuerRole(
+        address issuer
+    ) public onlyRole(DEFAULT_ADMIN_ROLE) {
+        grantRole(ISSUER_ROLE, issuer);
+    }
+
     function setCM(
         address userAddress,
         Bn128.G1Point calldata cm
@@ -152,6 +172,15 @@ contract Aegis is AccessControl {
         TradeData calldata txs
     ) public onlyRole(VALIDATOR_ROLE) returns (bool) {
         require(proof.length == 10, "proof length is failed");
+        // a netIssuance of r or more would verify as its reduction modulo r
+        require(
+            txs.netIssuance < Bn128.curveOrder,
+            "Aegis: netIssuance out of field"
+        );
+        require(
+            txs.netIssuance == 0 || hasRole(ISSUER_ROLE, msg.sender),
+            "Aegis: issuance requires the issuer role"
+        );
 
         uint256[2] memory d = [proof[8], proof[9]];
 
@@ -193,8 +222,9 @@ contract Aegis is AccessControl {
         proof[8] = d[0];
         proof[9] = d[1];
 
-        uint256[] memory inputs = new uint256[](1);
+        uint256[] memory inputs = new uint256[](2);
         inputs[0] = tau;
+        inputs[1] = txs.netIssuance;
         require(ccGroth16VerifyBn128._verify(vk, inputs, proof), "verify is failed");
 
         return true;
@@ -0,0 +1,11 @@
+const dims = { l: 4 }
+const pp = ["9115673468440927050548030079373132289235174546229003972448322175408463734684", "18077630834239159504765486876874084906465524863230013296342346479722367099186", "8819070586377199452405610259022156664577111920471874805338685023685120038086", "13057021217059547593078804850161809160426109415445836006519987673413010809712", "10200588202982580555918600671221400263901656118153440963871376709070271567774", "17886271492011076589017778289891380028066391110771539383231266397537540608709"]
+const instance = ["4", "20553537678641591987402502470920261721008990233660519565270814029582843403738", "19603920037778596340730576711193303947813558608242973064937325557692475821409", "10937928484010621129418062415621089582373045515641504907746008927119552080182", "20535280557246166432739132628668892813050764262071277002487564403697144699743", "18495941139219124666741885407857320581811094759331019654038320832753702760224", "610252419670473913729486553979563983724898160147027122977652730299063330018", "10216686285440370499443395476091628406525633966026329055136923745749918207846", "11455567287717786067548299860339374291035334219361814317193075177248046298996", "47769334404996351063857508289121051733087416728487088485093924323786098373", "13912143933660580536910714863999210115422695831391130521912863867340692246970", "6195430327457786472714026041446903671950189162054761176830729396784635980443", "4591861116229290317656822452032495928960990172166606532122269727899971075416", "17591669957494213365487477277403812520011963850665213110871387533178371841624", "11126005064608105690025441185767162146997357442869571003857157823096466387848", "21752438578263547410246461756312939985327652731150857793598433236992286915601", "11325928366845025973553320900384263932154988464796218557232790569589810652219", "2741298255360136775556897867501202953516342557853160339672383318126785329530", "4189458231306916096985282674092469568513339333303136865297362362225414873572", "7964022824676135923226761922587959522996997791902952894530430057600258830058", "19289169250002254870121205180073093534615446471964979120212012592829617647552", "7682356105081701072732091798398431109044956369388880967529365626440420075505", "6826782160705902271492947248355667087098963611657385557696513475271306583406", "20908748789925044909758285021127870178816309171167801626996887936221885981917", "16738079813678893500706815251767751931086898867528089166053703591362902138321"]
+const proof = ["17885439507138846025060976595858164917467510588522362096859611097976480720003", "8801456200204956150423740946835431048542352037689814027778487888251324245365", "761620744082208165128050507091447827431318806896284374144457579506356545548", "17940182392532976975134110744969307749952434246612666196769609545324921910728", "14424617814022093910457006672067270570281023852695942812652410060910655951184", "7110185072120675977948943402888219804745991906472785918517469416055226702583", "18967607893685382020827228215332146791109355132537729820317842457718891483882", "1255287685016480964888006454964044277775735535710757028310864805031878574074", "20780701916633661565998057754954362005688957099803686431586921265536780508389"]
+const challenges = ["6477112965559140481168321734331631909391423155154866452739405324942367873537", "19570296997368979679639491033350563806464471600847114066317528798368663352339", "19517796130213395073950962714561743449785741223478919909103650431827804779786", "16953239474677508343195671472963469880307435887962340564925848976842584796195", "15922408888032450557399654976157645728138908751944533723268564126921681216473"]
+const constructorArgs = "0x00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000006142749d0f7ce574d4fc4696226001ba5c7b814f78b77b0eff213e45eda58e79c27f79392cd5aaa9d60561ac05da220dbdca466cc48c6c8b2b1cd0f432b292132137f6ac49f9510fa4a5ecd6eeb9e753787a72d45a88f37638af0f764177714c61cde031a14697852ba0b00b258dd32df8e2b9678dac4fb57351ddd18d8176370168d540d0804f0c534b47246d1b00e9d64929f6baced6159791684b72650579e278b455a4f7180be4dce6dd3bd2c4fab1d63c0fd2dc1594aea55051871c192c5"
+const calldata = "0xb864f5a900000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000380000000000000000000000000000000000000000000000000000000000000001900000000000000000000000000000000000000000000000000000000000000042d70e3dbc2915d34fb9accb7f1b06b275d8705cd8bbc7361bb7ff36e63a25dda2b576cefd12f4588b46d74cf87878afadd8c46036f6bc2ccfabdfc3aa7eecd61182ea5ec1632d9314e066dc627353aa891811288a51422463849c363280ce9362d668e917d83d754e628b86716664e25b73661f5b8c2850d992f48b44fb61b5f28e454ea5d0a764c46e1835b048009d570062df04484a98da7188d9ccd5b6f200159640048feacd4013205510ea42db472bbc808758c11882e54a0f2c9b1e4e2169670841892f64bc52fda6b98a0b8990adb618806095e65e70006dc4126cf6619539f0e6ea25264b20f6231fabf8c1a55bc7afc4ffce66c631acb5ec181b774001b09571703330432a5ed4d65b429db8250b76f65d02570e396b0ac717e06c51ec1fe927ad5d375874111092001993c9449b27a3eee42c15fa9739938e5d1ba0db27d42c4829e1fc4e708c7238e1d54bb5d5e4efb1e4ff4326d23bfa5c22a9b0a26e6baf44682f0b28d116268c51f17967b1ec4f6befffc1c4288f987f1bd5826e48848126f071c729b6fe75166ca7af08db3888ebb0bd255f1b7a7a6841e58189918802ea724127c486fb091464cdb946110b330ec9e38222cf27c3edf3388301771a5ab6166c69ae49790eba9510975909b7cbe98935f279fecd40f09fc11190a3f8f9affe49f121b69846b07fb7409b4d79c1eb25b4030fafdb801a3e43b060f85137f1af6747e3c385cba6498bb1eac8fb7d49901df2c5ed2aa4b61f97a0943263a6567e59378c0e322353aa197647febe6e9217ed89ded0bdffb83a1e4119b7a28747d53dd2590c3518ebd92cee6b9bfac79a16a6b0b3cc651c82a6eea2aa5486c3cd2b93f736c43b7c590893fcb93d78f62fd04d747996c93ace3afc010fc0f392adacc0937267cbe29e8dd2f14ec0276d08af9298ea0635f99bea7f10f17d25fcc87009410526cbdfacce3fb2ddb211696e9a3c76bb4db01ae2d1d6e2e39eeb425ce7560e8e91a5441395befe431ad84e07475a8779d5fe68e0764dd25016addf4b8f4a52e93abb02d50838dcd1472296a4208fe27f63ea421449dd10000000000000000000000000000000000000000000000000000000000000009278accce46b9c4ba7a6b31d8a77a2877b7f48c4770583fe398a1b04315a3e4831375729aba623f5e0610512c7a292d3d6b27ac070ee2f69fa3e5ed9e5d0ca57501af0fe37c023336ddf2315fc014d0d98cea813537fb0a9b2762d4707ee1680c27a9c88cbf958092cdd1c3f55a6fa43595dc7d45afcecdd95ca9b543965c75c81fe40b5b2bbd8b85e083070a0e011e850067582389a256ca30111439801bd1500fb838de05363f07ec12c4b62b6572e99294ab762fc34b47cbeaab0a975b1af729ef491ea17f743d29d6744d08d662cd55e62a56c14d3b68f515e081712412ea02c677b8057df28fe7d0d56f4ec441a9fbccadb767c1ef5ea596b29386afbffa2df175e1a5d5de8bd26c45b3a27d210679ce61f10446530177e052c8c7e49ce5"
+
+const comEncEq4 = { dims, pp, instance, proof, challenges, constructorArgs, calldata }
+
+export default comEncEq4
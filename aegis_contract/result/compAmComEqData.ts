@@ -0,0 +1,11 @@
+const dims = { l: 4, d0: 2, d1: 1, d2: 1, threshold: 5 }
+const pp = ["4", "2", "1", "1", "1", "2", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "1075418944359717948790526162161548480908473464560771674163463050889400539338", "7228569209967957586253754867957593623250096440481964844041562980656175088127", "13396250268295131625409614136356304502860267089766595161854726633364742541906", "5952324336716612535764331013653339490119510823727020684224625261674755657930", "7715046887013928010606070807447224640848851067504954389425592090005319324126", "21741450998220834004659834868091704803964807699491812577005713742835751779379", "7715046887013928010606070807447224640848851067504954389425592090005319324126", "21741450998220834004659834868091704803964807699491812577005713742835751779379", "19600177508735183140612422568156820844918915934377602235568348379319187995868", "6564115917248064516550311863333370820394462016163475876204953468447611597230"]
+const instance = ["10010569378503286696686934577123133653431558866118022491563561152177309299053", "6705839087053384614835727264472993529779042463201518930368712211374907789985", "5405978559726306760776391839416140020952428445239363343943454457497251431744", "10394950767743869411840607199841517458620751371179986298481011981631539607080", "5405978559726306760776391839416140020952428445239363343943454457497251431744", "10394950767743869411840607199841517458620751371179986298481011981631539607080", "5405978559726306760776391839416140020952428445239363343943454457497251431744", "10394950767743869411840607199841517458620751371179986298481011981631539607080", "5405978559726306760776391839416140020952428445239363343943454457497251431744", "10394950767743869411840607199841517458620751371179986298481011981631539607080"]
+const proof = ["5427739665228921694133998060455095063070945147575649843090077183534244563120", "20337447839084285755575116834483686603517801585958329792300229388810016953460", "19080310726175993649480590104760882121753893114283231477815156043429177109604", "13616281526205857728425222154547163243170021331299817910417487102861496660263", "2541546629708121188106348951071065421578109754248945216887686920559939891493", "13685139970703689125188347147975443335539239331638274640203265014137110674375", "21097241510559576638968639262649542227256632987841709126223504152122474765061", "269517553016762168137246645424721567929234231527088294859666853592311386493", "12807185077210793284408619395597297823195779250766277464633672376055116617483", "19324144918435606706225665811680360546631084511057465282608151885489461495941", "11920292023576800628702424399526591148801134374750300243281864045021454641319", "353073450029023344938260761682800471286031683003322177264972768466617858775", "9381981796122907849116370919971974937854387263352792647565446089402131271797", "7903164477751856174533529935445692859505168808043945063331766885052240150968", "14908234200182995150320686097214596039842524247962868813738531481497097567383", "7180687442249851601229933434621076939605553187402981112093383397337929786075", "1657756465528107350948591780169467786354975113851293502903744783720008045819", "11408154130394163118371805399958876358638402928127086538502310908457574196372"]
+const challenges = ["19678674866060139264591321504285737379783866474208037854030286818574636584423", "21809510242167949942896018411322391209364452244208142098135393190081455606208", "12761140172622535048621723683728201900613415351064246333252801417529629423371", "704422546530367959378728654451083645532414904667657240680803215940730781165", "6493524999582180905486640170527354210214127922417382628232468194818552139690", "20351521824194744598036667864689962238747632132387978394694169257166824803726", "9804585885945692287345820743456113389235968670281387814721139472150025213726"]
+const constructorArgs = "0x0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000001e0000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000020260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff0260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff0260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff0260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff0260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff0260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff0260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff0260aa61a13dbe96a3d7f357229a6906be5a92525bd4bace23581d61b891d8ca0ffb39a5be2a04512326702f3257888cb7dd0bc9534cb0c71c0111cc7d3f81ff1d9e024ae9bfd2447b00989f7dcb5084ae5783e33f45a5c046432f1d10a512520d28e56c680700a834bbb10e2abc8168b6047cdf6fac6ea8f3c88106a67a10ca110e8fd1a95a9f8e8f6c0b4da1d2343cf8e200f60aed0a6e1f06f5a4b24211de301139a5acb912018329538bada5e7eb1352dd79f52a7cb91796b33f498afc33110e8fd1a95a9f8e8f6c0b4da1d2343cf8e200f60aed0a6e1f06f5a4b24211de301139a5acb912018329538bada5e7eb1352dd79f52a7cb91796b33f498afc332b554eadb31cc9985c8e35620a919f2da4abc56e3498e35167921fa5a40afcdc0e83286f573fccba2a1285ffc82424ce90b31651405e44471f8d35c0fe9eb1ae"
+const calldata = "0xb864f5a9000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000001a0000000000000000000000000000000000000000000000000000000000000000a1621c80f1b34debb31c593bd95d33d7a5a65f4c26bafb7c6b2c373b10541316d0ed35ed3df478a4f27533a3ecc000d9591846f9c87e2ad1a2315eeafe55f12a10bf3ace93e0191c06e251ddf705814a21a26592ca1f203f4c29f6acb9deb454016fb55693cf32261dd0a4aba91a5fc501620c4f5de2c5fbce2fa0382b6e376280bf3ace93e0191c06e251ddf705814a21a26592ca1f203f4c29f6acb9deb454016fb55693cf32261dd0a4aba91a5fc501620c4f5de2c5fbce2fa0382b6e376280bf3ace93e0191c06e251ddf705814a21a26592ca1f203f4c29f6acb9deb454016fb55693cf32261dd0a4aba91a5fc501620c4f5de2c5fbce2fa0382b6e376280bf3ace93e0191c06e251ddf705814a21a26592ca1f203f4c29f6acb9deb454016fb55693cf32261dd0a4aba91a5fc501620c4f5de2c5fbce2fa0382b6e3762800000000000000000000000000000000000000000000000000000000000000120bfffde5818761d732184b8ece59cad8ec0fc0b269a59e14e69cac47e83008b02cf6966a23e18d3ff75922df8a1d2289d9eb1caeb7577f49f9ef454f89b294742a2f12bb068d2e5cbf5f4b5e27d2a958547530eeb8f1a28f23e94ad1041eac641e1a8acfdd6ce3a4140d70bb763ef1f6ebdac2835751fece91ec80ce5439b127059e76e3e966a53367eea4365236234a6897605bea54fdbf040bf00b7f9d31251e4183c58605ab7760a2ff471c335f4c4e85a1522502f444c547629dad3c3bc72ea49d93bc2c9bae6c3849989f61c8fe547744540c98e1143e0fc21b5cba1b0500988aa1a757921f1b4e03f3bdafd1e0c197ed3f1df19def5a4a09042735d17d1c509c209d23973ce8bd24a67aa4a124dd8acb2f4475f0df02e62f8ffdf8570b2ab91413e1e2a0a96a32780144c183e5f0e4f42a6494eb38ff9aae2fe8a388851a5aa56ceb1d198305915f1e9657234e35506918ccd8658842bb15d16863a8a700c7d51e1ffacf76d8bb62b6c5821f32421641cd2db251df8c0b2a01bb357ad714be0375d691fd12d10a9f3d3de56dbb5d003607f9c6898b52ed2e178e2dd07511790856e8600736c50987588e1b23d00d94b2ea56409dda3a9ea743d300c1b820f5c2f3e97da3ba00915e1b0dfb6dbb35ba33ba0263b99524a74b1913997c970fe020044502971eec3fcbe2e6f53fc9beecdb4bd348516d483b9cf33176a2db03aa41c4c864b9ea41646cf5ad30dd1b43606bf0e4ed2d78a0f6e19ba60ed4fb1938c952af3a2c71ad0cf9e9dd2041088edcc365e33cbcf67b25357f93b7e494"
+
+const compAmComEq4 = { dims, pp, instance, proof, challenges, constructorArgs, calldata }
+
+export default compAmComEq4
@@ -1,4 +1,5 @@
 import { SignerWithAddress } from "@nomicfoundation/hardhat-ethers/signers";
+import { expect } from "chai";
 import { Aegis, Aegis__factory } from "../typechain-types";
 import { ethers } from "hardhat";
 import batch1024 from "../result/dbtData";
@@ -43,9 +44,49 @@ describe("Aegis", () => {
   });
 
   it("verify", async () => {
+    expect(await Aegis.verify.staticCall(batch.dbt.proof, txs)).to.equal(true);
     await Aegis.verify(batch.dbt.proof, txs);
   });
 
+  it("rejects a vk without the netIssuance input", async () => {
+    // alpha, beta, gamma, delta and gamma_abc for [1, tau, netIssuance]
+    expect(batch.vk.length).to.equal(20);
+    await expect(
+      new Aegis__factory(signer).deploy(batch.vk.slice(0, 18), batch.ck, BATCH_SIZE)
+    ).to.be.revertedWith("vk length is failed");
+  });
+
+  it("gates issuance behind the issuer role", async () => {
+    const [, validator] = await ethers.getSigners();
+    await Aegis.grantValidatorRole(validator.address);
+    const mint = { ...txs, netIssuance: 1n };
+
+    // a validator settles transfers but cannot mint
+    expect(
+      await Aegis.connect(validator).verify.staticCall(batch.dbt.proof, txs)
+    ).to.equal(true);
+    await expect(
+      Aegis.connect(validator).verify(batch.dbt.proof, mint)
+    ).to.be.revertedWith("Aegis: issuance requires the issuer role");
+
+    // the issuer passes the gate, and the proof of a transfer does not verify as a mint
+    await Aegis.grantIssuerRole(validator.address);
+    await expect(
+      Aegis.connect(validator).verify(batch.dbt.proof, mint)
+    ).to.be.revertedWith("verify is failed");
+  });
+
+  it("rejects a netIssuance outside the scalar field", async () => {
+    const r =
+      21888242871839275222246405745257275088548364400416034343698204186575808495617n;
+    // the deployer holds the issuer role, so only the field check can reject it
+    for (const netIssuance of [r, r + 1n]) {
+      await expect(
+        Aegis.verify(batch.dbt.proof, { ...txs, netIssuance })
+      ).to.be.revertedWith("Aegis: netIssuance out of field");
+    }
+  });
+
   it("updateCommitment", async () => {
     console.log("Batch size: ", BATCH_SIZE);
     const txs: Aegis.TradeDataStruct = makeTsx(
@@ -181,5 +222,6 @@ function makeTsx(
     contractAddress: contractAddress,
     userAddress: userAddress,
     deltaCm: deltaCm,
+    netIssuance: batch1024.dbt.netIssuance,
   };
 }
\ No newline at end of file
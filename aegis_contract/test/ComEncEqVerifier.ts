@@ -0,0 +1,64 @@
+import { expect } from "chai";
+import { ethers } from "hardhat";
+import comEncEq from "../result/comEncEqData";
+
+describe("ComEncEqVerifier", () => {
+  const fixture = comEncEq;
+
+  // instance = [l, c, (c1, c2) * l]
+  const ciphertext = (i: number) => 1 + 2 * fixture.dims.l + 4 * i;
+
+  async function deploy() {
+    const factory = await ethers.getContractFactory("ComEncEqVerifier");
+    return factory.deploy(fixture.pp);
+  }
+
+  it("replays the prover transcript", async () => {
+    const verifier = await deploy();
+    const challenges = await verifier.challenges(fixture.instance, fixture.proof);
+    expect(challenges.map((c: bigint) => c.toString())).to.deep.equal(fixture.challenges);
+    expect(challenges.length).to.equal(fixture.dims.l + 1);
+  });
+
+  it("verify", async () => {
+    const verifier = await deploy();
+    expect(await verifier.verify(fixture.instance, fixture.proof)).to.equal(true);
+  });
+
+  it("verify with ABI-encoded fixtures", async () => {
+    const [signer] = await ethers.getSigners();
+    const factory = await ethers.getContractFactory("ComEncEqVerifier");
+    const tx = await signer.sendTransaction({
+      data: factory.bytecode + fixture.constructorArgs.slice(2),
+    });
+    const receipt = await tx.wait();
+    const result = await ethers.provider.call({
+      to: receipt!.contractAddress!,
+      data: fixture.calldata,
+    });
+    expect(BigInt(result)).to.equal(1n);
+  });
+
+  it("rejects an instance with a missing ciphertext", async () => {
+    const verifier = await deploy();
+    const instance = fixture.instance.slice(0, fixture.instance.length - 4);
+    expect(await verifier.verify(instance, fixture.proof)).to.equal(false);
+  });
+
+  it("rejects the proof for swapped ciphertexts", async () => {
+    const verifier = await deploy();
+    const instance = [...fixture.instance];
+    const [a, b] = [ciphertext(0), ciphertext(1)];
+    for (let k = 0; k < 4; k++) {
+      [instance[a + k], instance[b + k]] = [instance[b + k], instance[a + k]];
+    }
+    expect(await verifier.verify(instance, fixture.proof)).to.equal(false);
+  });
+
+  it("rejects a tampered proof", async () => {
+    const verifier = await deploy();
+    const proof = [...fixture.proof];
+    proof[2] = (BigInt(proof[2]) + 1n).toString();
+    expect(await verifier.verify(fixture.instance, proof)).to.equal(false);
+  });
+});
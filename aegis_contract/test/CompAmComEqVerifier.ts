@@ -0,0 +1,77 @@
+import { expect } from "chai";
+import { ethers } from "hardhat";
+import compAmComEq from "../result/compAmComEqData";
+
+describe("CompAmComEqVerifier", () => {
+  const fixture = compAmComEq;
+
+  // CompDLEq halves the generators until at most `threshold` remain
+  function folding(n: number, threshold: number) {
+    let rounds = 0;
+    while (n > threshold) {
+      n = Math.ceil(n / 2);
+      rounds++;
+    }
+    return { rounds, finalLen: n };
+  }
+
+  async function deploy() {
+    const factory = await ethers.getContractFactory("CompAmComEqVerifier");
+    return factory.deploy(fixture.pp);
+  }
+
+  it("replays the prover transcript", async () => {
+    const verifier = await deploy();
+    const challenges = await verifier.challenges(fixture.instance, fixture.proof);
+    expect(challenges.map((c: bigint) => c.toString())).to.deep.equal(fixture.challenges);
+  });
+
+  it("verify", async () => {
+    const verifier = await deploy();
+    expect(await verifier.verify(fixture.instance, fixture.proof)).to.equal(true);
+  });
+
+  it("verify with ABI-encoded fixtures", async () => {
+    const [signer] = await ethers.getSigners();
+    const factory = await ethers.getContractFactory("CompAmComEqVerifier");
+    const tx = await signer.sendTransaction({
+      data: factory.bytecode + fixture.constructorArgs.slice(2),
+    });
+    const receipt = await tx.wait();
+    const result = await ethers.provider.call({
+      to: receipt!.contractAddress!,
+      data: fixture.calldata,
+    });
+    expect(BigInt(result)).to.equal(1n);
+  });
+
+  it("folds down to the threshold of the Rust parameters", async () => {
+    const { l, d0, d1, d2, threshold } = fixture.dims;
+    const { rounds, finalLen } = folding(l * d0, threshold);
+    expect(fixture.proof.length).to.equal(finalLen + d1 + d2 + 4 + 8 * rounds);
+    const verifier = await deploy();
+    const challenges = await verifier.challenges(fixture.instance, fixture.proof);
+    expect(challenges.length).to.equal(l + rounds + 2);
+  });
+
+  it("rejects a proof folded to another threshold", async () => {
+    const verifier = await deploy();
+    const proof = fixture.proof.slice(0, fixture.proof.length - 8);
+    expect(await verifier.verify(fixture.instance, proof)).to.equal(false);
+  });
+
+  it("rejects the proof for another instance", async () => {
+    const verifier = await deploy();
+    const instance = [...fixture.instance];
+    [instance[0], instance[2]] = [instance[2], instance[0]];
+    [instance[1], instance[3]] = [instance[3], instance[1]];
+    expect(await verifier.verify(instance, fixture.proof)).to.equal(false);
+  });
+
+  it("rejects a tampered proof", async () => {
+    const verifier = await deploy();
+    const proof = [...fixture.proof];
+    proof[0] = (BigInt(proof[0]) + 1n).toString();
+    expect(await verifier.verify(fixture.instance, proof)).to.equal(false);
+  });
+});